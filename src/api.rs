@@ -1,28 +1,132 @@
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Using
 
-use std::error::Error;
+use std::ops::Deref;
 
-use crate::dist_corr::{dist_corr, dist_cov, dist_var};
-use crate::dist_corr_binary::{
-    dist_corr_both_binary, dist_corr_one_binary, dist_cov_both_binary, dist_cov_one_binary,
+use rayon::prelude::*;
+
+use crate::auto_detection::{
+    dist_corr_auto, dist_corr_detected, dist_cov_auto, dist_cov_detected, Algorithm, AutoResult,
+    BinaryDetection, BinaryVector,
+};
+use crate::config::{Config, DistCorrelationBuilder, DistCovarianceBuilder, Estimator};
+use crate::dist_corr::dist_var;
+use crate::dist_corr_accurate::{centered, CompensatedSums};
+use crate::dist_corr_bias_corrected::{
+    dist_corr_bias_corrected, dist_cov_sq_bias_corrected, dist_var_sq_bias_corrected,
+    BiasCorrectedDistCorr,
+};
+use crate::dist_corr_binary::ContingencyTable;
+use crate::dist_corr_categorical::{
+    dist_corr_both_categorical, dist_corr_categorical, dist_cov_both_categorical,
+    dist_cov_categorical,
+};
+use crate::dist_corr_details::{dist_corr_details, general_details, DistCorrDetails};
+use crate::dist_corr_exponent::ExponentSums;
+use crate::dist_corr_multi::{dist_corr_multi, dist_corr_multi_bias_corrected, dist_cov_multi};
+use crate::dist_cov_exact::{dist_cov_exact, ExactDistCov};
+use crate::error::DistCorrError;
+use crate::exact_test::BinaryIndependenceTest;
+use crate::matrix::{
+    cross_dist_corr_matrix, dist_corr_matrix, pairwise_cross_matrix, pairwise_matrix,
+    SymmetricMatrix,
 };
+use crate::nan_policy::{check_finite, CompleteLabeled, CompletePairs, CompleteRows, NanPolicy};
+use crate::numeric::{check_exact, to_f64_checked, Column, Numeric};
+use crate::partial_dist_corr::{partial_dist_corr_multi, PartialDistCorr};
+use crate::permutation_test::{
+    dist_corr_both_binary_permutation_test, dist_corr_one_binary_permutation_test,
+    dist_corr_permutation_test, PermutationTest,
+};
+use crate::prepared_vector::{
+    dist_corr_one_vs_many, dist_corr_prepared, dist_cov_prepared, FeatureResult, PreparedVector,
+};
+use crate::random_projection::{
+    dist_corr_random_projection, dist_cov_random_projection, RandomProjectionEstimate,
+};
+use crate::screening::{dist_corr_screening, ScreenedFeature, ScreeningCutoff};
+use crate::t_test::DistCorrTTest;
 
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // API Calls
 
 /// Instance for distance correlation computation.
+///
+/// `DistCorrelation` computes with the default settings, i.e. the standard estimator, rejection of
+/// non-finite values and parallel computation. `compute` and `compute_binary` take `f64` slices,
+/// all other methods are the ones of [`ConfiguredDistCorrelation`] with the default settings,
+/// which `DistCorrelation` dereferences to. Other settings and inputs of other [`Numeric`] types
+/// are handled by the [`ConfiguredDistCorrelation`] returned by [`DistCorrelation::builder`].
 #[derive(Clone, Debug)]
 pub struct DistCorrelation;
 
 /// Instance for distance covariance computation.
+///
+/// `DistCovariance` computes with the default settings, i.e. the standard estimator, rejection of
+/// non-finite values and parallel computation. `compute`, `compute_binary` and `compute_var` take
+/// `f64` slices, all other methods are the ones of [`ConfiguredDistCovariance`] with the default
+/// settings, which `DistCovariance` dereferences to. Other settings and inputs of other
+/// [`Numeric`] types are handled by the [`ConfiguredDistCovariance`] returned by
+/// [`DistCovariance::builder`].
 #[derive(Clone, Debug)]
 pub struct DistCovariance;
 
+/// Instance for distance correlation computation with the settings of a
+/// [`DistCorrelationBuilder`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfiguredDistCorrelation {
+    config: Config,
+}
+
+/// Instance for distance covariance computation with the settings of a
+/// [`DistCovarianceBuilder`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfiguredDistCovariance {
+    config: Config,
+}
+
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Implementations
 
 impl DistCorrelation {
+    /// Returns a [`DistCorrelationBuilder`] starting from the default settings of
+    /// `DistCorrelation`.
+    ///
+    /// The settings are honored by `compute`, `compute_with_nan_policy`, `compute_binary`,
+    /// `compute_auto` and the batch computations `compute_one_vs_many`, `correlation_matrix`,
+    /// `cross_correlation_matrix`, `screen` and `screen_with_p_values`. With
+    /// [`Estimator::BiasCorrected`] the distance correlation is computed as in
+    /// [`ConfiguredDistCorrelation::compute_bias_corrected`], binary flags only validate the
+    /// inputs and the batch computations evaluate every pair separately instead of using prepared
+    /// vectors. The same holds for an exponent other than `1.0`, whose computations take up to
+    /// `O(n^2)` time, and for the accuracy mode.
+    ///
+    /// The other methods apply the configured [`NanPolicy`] and run on the configured thread pool.
+    /// Settings a method does not support make it fail with
+    /// [`DistCorrError::UnsupportedSetting`] instead of being ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{ConfiguredDistCorrelation, DistCorrelation, Estimator, NanPolicy};
+    ///
+    /// let dist_corr = DistCorrelation::builder()
+    ///     .estimator(Estimator::BiasCorrected)
+    ///     .nan_policy(NanPolicy::PairwiseDeletion)
+    ///     .parallel(false)
+    ///     .build();
+    ///
+    /// let v1 = vec![1.0, 2.0, 3.0, f64::NAN, 4.0, 5.0];
+    /// let v2 = vec![1.0, 4.0, 9.0, 12.0, 16.0, 25.0];
+    /// let result = dist_corr.compute(&v1, &v2).unwrap();
+    ///
+    /// assert!(result > 0.9 && result <= 1.0);
+    /// assert_eq!(DistCorrelation::builder().build(), ConfiguredDistCorrelation::default());
+    /// ```
+    pub fn builder() -> DistCorrelationBuilder {
+        DistCorrelationBuilder::new()
+    }
+
     /// Computes the distance correlation between two vectors.
     ///
     /// # Arguments
@@ -42,6 +146,7 @@ impl DistCorrelation {
     /// The function will return an error if:
     /// - The lengths of `v1` and `v2` do not match.
     /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
     ///
     /// # Examples
     ///
@@ -56,8 +161,8 @@ impl DistCorrelation {
     ///
     /// assert_eq!(result, 1.0);
     /// ```
-    pub fn compute(&self, v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
-        self.compute_binary(v1, v2, false, false)
+    pub fn compute(&self, v1: &[f64], v2: &[f64]) -> Result<f64, DistCorrError> {
+        ConfiguredDistCorrelation::DEFAULT.compute(v1, v2)
     }
 
     /// Computes the distance correlation between two vectors where at least one is binary, i.e. 0-1-valued.
@@ -82,6 +187,7 @@ impl DistCorrelation {
     /// - `v1` or `v2` is not 0-1-valued as indicated by v1_binary and v2_binary
     /// - The lengths of `v1` and `v2` do not match.
     /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
     ///
     /// ```
     /// use dist_corr::DistCorrelation;
@@ -100,170 +206,3154 @@ impl DistCorrelation {
         v2: &[f64],
         v1_binary: bool,
         v2_binary: bool,
-    ) -> Result<f64, Box<dyn Error>> {
-        if v1.len() != v2.len() {
-            return Err("Length of v1 must and v2 must be identical".into());
-        }
+    ) -> Result<f64, DistCorrError> {
+        ConfiguredDistCorrelation::DEFAULT.compute_binary(v1, v2, v1_binary, v2_binary)
+    }
+}
 
-        if v1.is_empty() {
-            return Err("v1 and v2 must not be empty".into());
-        }
+impl Deref for DistCorrelation {
+    type Target = ConfiguredDistCorrelation;
 
-        let result = match (v1_binary, v2_binary) {
-            (true, true) => dist_corr_both_binary(v1, v2),
-            (true, false) => {
-                if !v1.iter().all(|&x| x == 0.0 || x == 1.0) {
-                    return Err("v1 must be binary (only 0.0 or 1.0)".into());
-                } else {
-                    dist_corr_one_binary(v1, v2)
-                }
-            }
-            (false, true) => {
-                if !v2.iter().all(|&x| x == 0.0 || x == 1.0) {
-                    return Err("v2 must be binary (only 0.0 or 1.0)".into());
-                } else {
-                    dist_corr_one_binary(v2, v1)
-                }
-            }
-            (false, false) => dist_corr(v1, v2),
-        };
-        result.map(|dist_corr| dist_corr.clamp(0.0, 1.0))
+    fn deref(&self) -> &ConfiguredDistCorrelation {
+        &ConfiguredDistCorrelation::DEFAULT
     }
 }
 
-impl DistCovariance {
-    /// Computes the distance covariance between two vectors.
+impl Default for ConfiguredDistCorrelation {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ConfiguredDistCorrelation {
+    /// the default settings, i.e. the ones of `DistCorrelation`
+    pub(crate) const DEFAULT: Self = ConfiguredDistCorrelation {
+        config: Config::DEFAULT,
+    };
+
+    pub(crate) fn from_config(config: Config) -> Self {
+        ConfiguredDistCorrelation { config }
+    }
+
+    /// Returns `true` if sums are accumulated with compensated summation of the centered inputs.
+    pub fn accurate(&self) -> bool {
+        self.config.accurate
+    }
+
+    /// Returns `true` if results are bit-identical regardless of the number of threads.
+    pub fn deterministic(&self) -> bool {
+        self.config.deterministic
+    }
+
+    /// Returns the configured [`Estimator`].
+    pub fn estimator(&self) -> Estimator {
+        self.config.estimator
+    }
+
+    /// Returns the configured exponent of the distances.
+    pub fn exponent(&self) -> f64 {
+        self.config.exponent
+    }
+
+    /// Returns the configured [`NanPolicy`].
+    pub fn nan_policy(&self) -> NanPolicy {
+        self.config.nan_policy
+    }
+
+    /// Returns `true` if computations use the global rayon thread pool.
+    pub fn parallel(&self) -> bool {
+        self.config.parallel
+    }
+
+    /// Computes the distance correlation between two vectors.
     ///
     /// # Arguments
     ///
-    /// * `v1` - A slice of `f64` values representing the first data vector.
-    /// * `v2` - A slice of `f64` values representing the second data vector.
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
     ///
     /// # Returns
     ///
-    /// Returns a `f64` representing the distance covariance between the two input vectors.
+    /// Returns a `f64` representing the distance correlation between the two input vectors. The value will
+    /// be in the range `[0.0, 1.0]`, where:
+    /// - `0.0` indicates no dependence.
+    /// - `1.0` indicates perfect linear dependence.
     ///
     /// # Errors
     ///
     /// The function will return an error if:
     /// - The lengths of `v1` and `v2` do not match.
     /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
+    /// - Either of the vectors contains integers beyond `2^53` in magnitude, see [`Numeric`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use dist_corr::DistCovariance;
+    /// use dist_corr::DistCorrelation;
     ///
-    /// let v1 = vec![0.0, 1.0, 0.0, 1.0];
-    /// let v2 = vec![0.0, 1.0, 1.0, 0.0];
+    /// let v1 = vec![1.0, 2.0, 3.0];
+    /// let v2 = vec![2.0, 4.0, 6.0];
     ///
-    /// let dist_cov = DistCovariance;
-    /// let result = dist_cov.compute(&v1, &v2).unwrap();
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute(&v1, &v2).unwrap();
     ///
-    /// assert_eq!(result, 0.0);
+    /// assert_eq!(result, 1.0);
     /// ```
-    pub fn compute(&self, v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
+    pub fn compute<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+    ) -> Result<f64, DistCorrError> {
         self.compute_binary(v1, v2, false, false)
     }
 
-    /// Computes the distance covariance between two vectors where at least one is binary, i.e. 0-1-valued.
+    /// Computes the distance correlation between two vectors with an explicit treatment of `NaN` and
+    /// infinite values.
+    ///
+    /// [`ConfiguredDistCorrelation::compute`] applies the configured policy, [`NanPolicy::Reject`]
+    /// by default, which is overridden by `nan_policy`. With [`NanPolicy::PairwiseDeletion`] every
+    /// pair `(v1[i], v2[i])` containing a non-finite value is dropped before the computation, with
+    /// [`NanPolicy::Propagate`] the result is `NaN` if any value is not finite.
     ///
     /// # Arguments
     ///
-    /// * `v1` - A slice of `f64` values representing the first data vector.
-    /// * `v2` - A slice of `f64` values representing the second data vector.
-    /// * `v1_binary` - A flag indicating if v1 is a binary vector, i.e. with values either 0.0 or 1.0.
-    /// * `v2_binary` - A flag indicating if v2 is a binary vector, i.e. with values either 0.0 or 1.0.
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `nan_policy` - The [`NanPolicy`] applied to non-finite values.
     ///
     /// # Returns
     ///
-    /// Returns a `f64` representing the distance covariance between the two binary input vectors.
+    /// Returns a `f64` representing the distance correlation between the two input vectors in the range `[0.0, 1.0]`, or `NaN`.
     ///
     /// # Errors
     ///
     /// The function will return an error if:
-    /// - `v1` or `v2` is not 0-1-valued as indicated by v1_binary and v2_binary
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty, respectively no complete pair is left after pairwise deletion.
+    /// - Either of the vectors contains `NaN` or infinite values and `nan_policy` is [`NanPolicy::Reject`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{DistCorrelation, NanPolicy};
+    ///
+    /// let v1 = vec![1.0, 2.0, f64::NAN, 3.0, 4.0];
+    /// let v2 = vec![2.0, 4.0, 5.0, 6.0, f64::INFINITY];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr
+    ///     .compute_with_nan_policy(&v1, &v2, NanPolicy::PairwiseDeletion)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result, DistCorrelation.compute(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]).unwrap());
+    /// assert!(dist_corr.compute_with_nan_policy(&v1, &v2, NanPolicy::Propagate).unwrap().is_nan());
+    /// assert!(dist_corr.compute_with_nan_policy(&v1, &v2, NanPolicy::Reject).is_err());
+    /// ```
+    pub fn compute_with_nan_policy<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+        nan_policy: NanPolicy,
+    ) -> Result<f64, DistCorrError> {
+        Self::from_config(Config {
+            nan_policy,
+            ..self.config
+        })
+        .compute(v1, v2)
+    }
+
+    /// Computes the distance correlation between two vectors where at least one is binary.
+    ///
+    /// A binary vector may take any two distinct values, e.g. `{0.0, 1.0}`, `{-1.0, 1.0}` or
+    /// `{1.0, 2.0}`. Since the distance correlation is invariant under affine maps of each vector,
+    /// binary vectors are mapped to `{0.0, 1.0}` before the specialized algorithms are applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `v1_binary` - A flag indicating if v1 is a binary vector, i.e. with at most two distinct values.
+    /// * `v2_binary` - A flag indicating if v2 is a binary vector, i.e. with at most two distinct values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance correlation between the two binary input vectors. The value will
+    /// be in the range `[0.0, 1.0]`, where:
+    /// - `0.0` indicates no dependence.
+    /// - `1.0` indicates perfect linear dependence.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `v1` or `v2` has more than two distinct values although indicated binary by v1_binary and v2_binary
     /// - The lengths of `v1` and `v2` do not match.
     /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
     ///
     /// ```
-    /// use dist_corr::DistCovariance;
+    /// use dist_corr::DistCorrelation;
     ///
     /// let v1 = vec![0.0, 1.0, 0.0, 1.0];
-    /// let v2 = vec![0.0, 1.0, 1.0, 0.0];
+    /// let v2 = vec![-1.0, 1.0, 1.0, -1.0];
     ///
-    /// let dist_cov = DistCovariance;
-    /// let result = dist_cov.compute_binary(&v1, &v2, true, true).unwrap();
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_binary(&v1, &v2, true, true).unwrap();
     ///
     /// assert_eq!(result, 0.0);
     /// ```
-    pub fn compute_binary(
+    pub fn compute_binary<T: Numeric, U: Numeric>(
         &self,
-        v1: &[f64],
-        v2: &[f64],
+        v1: &[T],
+        v2: &[U],
         v1_binary: bool,
         v2_binary: bool,
-    ) -> Result<f64, Box<dyn Error>> {
-        if v1.len() != v2.len() {
-            return Err("Length of v1 must and v2 must be identical".into());
-        }
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
 
-        if v1.is_empty() {
-            return Err("v1 and v2 must not be empty".into());
-        }
+            let CompletePairs::Pairs(v1, v2) = complete_pairs(v1, v2, self.config.nan_policy)?
+            else {
+                return Ok(f64::NAN);
+            };
 
-        match (v1_binary, v2_binary) {
-            (true, true) => dist_cov_both_binary(v1, v2),
-            (true, false) => {
-                if v1_binary && !v1.iter().all(|&x| x == 0.0 || x == 1.0) {
-                    return Err("v1 must be binary (only 0.0 or 1.0)".into());
-                };
-                dist_cov_one_binary(v1, v2)
-            }
-            (false, true) => {
-                if v2_binary && !v2.iter().all(|&x| x == 0.0 || x == 1.0) {
-                    return Err("v2 must be binary (only 0.0 or 1.0)".into());
-                };
-                dist_cov_one_binary(v2, v1)
+            let b1 = v1_binary.then(|| BinaryVector::require(&v1)).transpose()?;
+            let b2 = v2_binary.then(|| BinaryVector::require(&v2)).transpose()?;
+
+            match self.config.estimator {
+                Estimator::Standard if self.config.default_algorithms() => {
+                    dist_corr_detected(&v1, b1, &v2, b2).map(|result| result.value.clamp(0.0, 1.0))
+                }
+                Estimator::Standard => Ok(standard_details(&v1, &v2, &self.config).dist_corr),
+                Estimator::BiasCorrected => {
+                    bias_corrected(&v1, &v2, &self.config).map(|result| result.dist_corr)
+                }
             }
-            (false, false) => dist_cov(v1, v2),
-        }
+        })
     }
 
-    /// Computes the distance variance of a single vector.
+    /// Computes the distance correlation between a boolean vector and a data vector.
+    ///
+    /// `false` and `true` are treated as `0.0` and `1.0` and the algorithm for one binary vector is
+    /// used, see [`ConfiguredDistCorrelation::compute_binary`].
     ///
     /// # Arguments
     ///
-    /// * `v` - A slice of `f64` values representing the input data vector.
+    /// * `v1` - A slice of `bool` values representing the binary data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
     ///
     /// # Returns
     ///
-    /// A `f64` value representing the distance variance of the input vector.
-    /// The result is always non-negative:
-    /// - `0.0` indicates that all points in the vector are identical.
+    /// Returns a `f64` in the range `[0.0, 1.0]` representing the distance correlation between
+    /// the two input vectors.
     ///
-    /// This method is faster than calling `DistCovariance::compute(v,v)` if `v` is non-binary.
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - `v2` contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let v1 = vec![false, true, false, true];
+    /// let v2 = vec![0.0, 1.0, 0.0, 1.0];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_bool(&v1, &v2).unwrap();
+    ///
+    /// assert!((result - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn compute_bool<U: Numeric>(&self, v1: &[bool], v2: &[U]) -> Result<f64, DistCorrError> {
+        self.compute_binary(&BinaryVector::from_bool(v1).values, v2, true, false)
+    }
+
+    /// Computes the distance correlation between two boolean vectors.
+    ///
+    /// `false` and `true` are treated as `0.0` and `1.0` and the `O(n)` algorithm for two binary
+    /// vectors is used, see [`ConfiguredDistCorrelation::compute_binary`].
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of `bool` values representing the first data vector.
+    /// * `v2` - A slice of `bool` values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` in the range `[0.0, 1.0]` representing the distance correlation between
+    /// the two input vectors.
     ///
     /// # Errors
     ///
     /// The function will return an error if:
-    /// - The input vector `v` is empty.
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use dist_corr::DistCovariance;
+    /// use dist_corr::DistCorrelation;
     ///
-    /// let v = vec![1.0, 1.0, 1.0];
+    /// let v1 = vec![false, true, false, true];
+    /// let v2 = vec![false, true, true, false];
     ///
-    /// let dist_cov = DistCovariance;
-    /// let result = dist_cov.compute_var(&v).unwrap();
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_both_bool(&v1, &v2).unwrap();
     ///
     /// assert_eq!(result, 0.0);
     /// ```
-    pub fn compute_var(&self, v: &[f64]) -> Result<f64, Box<dyn Error>> {
-        if v.is_empty() {
-            return Err("v must not be empty".into());
-        }
+    pub fn compute_both_bool(&self, v1: &[bool], v2: &[bool]) -> Result<f64, DistCorrError> {
+        let (v1, v2) = (BinaryVector::from_bool(v1), BinaryVector::from_bool(v2));
+
+        self.compute_binary(&v1.values, &v2.values, true, true)
+    }
+
+    /// Computes the distance correlation between a categorical vector and a data vector.
+    ///
+    /// The labels are compared with the discrete metric, i.e. two observations have distance
+    /// `1.0` if their labels differ and `0.0` otherwise. This generalizes the algorithm for one
+    /// binary vector to `k` levels. Instead of one weighted pass over the sorted values per level,
+    /// which takes `O(n log n + k n)`, the sums of all levels are accumulated in a single pass,
+    /// such that the computation runs in `O(n log n + k)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - A slice of `usize` values representing the levels of the categorical vector.
+    /// * `values` - A slice of [`Numeric`] values representing the data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance correlation between the two input vectors in the range `[0.0, 1.0]`.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `labels` and `values` do not match.
+    /// - Either of the vectors is empty.
+    /// - `values` contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The bias-corrected estimator, the accuracy mode or an exponent other than `1.0` is
+    ///   configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let labels = vec![0, 1, 2, 0, 1, 2];
+    /// let values = vec![0.1, 1.2, 2.1, 0.2, 0.9, 2.3];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_categorical(&labels, &values).unwrap();
+    ///
+    /// assert!(result > 0.9);
+    /// ```
+    pub fn compute_categorical<T: Numeric>(
+        &self,
+        labels: &[usize],
+        values: &[T],
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_unit_exponent(self.config.exponent)?;
+
+            let values = to_f64_checked(values)?;
+            let CompleteLabeled::Pairs(labels, values) =
+                complete_categorical(labels, &values, self.config.nan_policy)?
+            else {
+                return Ok(f64::NAN);
+            };
+
+            Ok(dist_corr_categorical(&labels, &values))
+        })
+    }
+
+    /// Computes the distance correlation between two categorical vectors.
+    ///
+    /// Both vectors are compared with the discrete metric and reduced to their `k x m` contingency
+    /// table, which generalizes the algorithm for two binary vectors and runs in `O(n + k m)`.
+    /// All distances are `0.0` or `1.0`, which are invariant under every exponent, hence the
+    /// configured exponent does not change the result and is not rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels_1` - A slice of `usize` values representing the levels of the first vector.
+    /// * `labels_2` - A slice of `usize` values representing the levels of the second vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance correlation between the two input vectors in the range `[0.0, 1.0]`.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `labels_1` and `labels_2` do not match.
+    /// - Either of the vectors is empty.
+    /// - The bias-corrected estimator or the accuracy mode is configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let labels_1 = vec![0, 1, 2, 0, 1, 2];
+    /// let labels_2 = vec![5, 7, 9, 5, 7, 9];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_both_categorical(&labels_1, &labels_2).unwrap();
+    ///
+    /// assert!((result - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn compute_both_categorical(
+        &self,
+        labels_1: &[usize],
+        labels_2: &[usize],
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_exponent(self.config.exponent)?;
+
+            if labels_1.len() != labels_2.len() {
+                return Err(DistCorrError::LengthMismatch {
+                    expected: labels_1.len(),
+                    found: labels_2.len(),
+                });
+            }
+
+            if labels_1.is_empty() {
+                return Err(DistCorrError::EmptyInput);
+            }
 
-        Ok(dist_var(v))
+            Ok(dist_corr_both_categorical(labels_1, labels_2))
+        })
     }
+
+    /// Computes the distance correlation between two vectors with automatic selection of the algorithm.
+    ///
+    /// Each vector is scanned once. If both vectors are binary, the `O(n)` algorithm of
+    /// [`ConfiguredDistCorrelation::compute_binary`] for two binary vectors is used, if one vector
+    /// is binary the faster algorithm for one binary vector and otherwise the general algorithm of
+    /// [`ConfiguredDistCorrelation::compute`]. Since the distance correlation is invariant under
+    /// affine maps of each vector, [`BinaryDetection::TwoValued`] also treats every vector with two
+    /// distinct values as binary.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `detection` - The [`BinaryDetection`] deciding which vectors are treated as binary.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`AutoResult`] containing the distance correlation in `[0.0, 1.0]` and the
+    /// [`Algorithm`] chosen.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{Algorithm, BinaryDetection, DistCorrelation};
+    ///
+    /// let v1 = vec![0.0, 1.0, 0.0, 1.0];
+    /// let v2 = vec![0.5, 2.0, 1.0, -0.3];
+    /// let v3 = vec![-1.0, 1.0, 1.0, -1.0];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_auto(&v1, &v2, BinaryDetection::ZeroOne).unwrap();
+    /// assert_eq!(result.algorithm, Algorithm::OneBinary);
+    ///
+    /// let result = dist_corr.compute_auto(&v1, &v3, BinaryDetection::TwoValued).unwrap();
+    /// assert_eq!(result.algorithm, Algorithm::BothBinary);
+    /// ```
+    pub fn compute_auto<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+        detection: BinaryDetection,
+    ) -> Result<AutoResult, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            let CompletePairs::Pairs(v1, v2) = complete_pairs(v1, v2, self.config.nan_policy)?
+            else {
+                return Ok(AutoResult {
+                    value: f64::NAN,
+                    algorithm: Algorithm::General,
+                });
+            };
+
+            match self.config.estimator {
+                Estimator::Standard if self.config.default_algorithms() => {
+                    dist_corr_auto(&v1, &v2, detection).map(|result| AutoResult {
+                        value: result.value.clamp(0.0, 1.0),
+                        ..result
+                    })
+                }
+                _ => Ok(AutoResult {
+                    value: self.compute(&v1, &v2)?,
+                    algorithm: Algorithm::General,
+                }),
+            }
+        })
+    }
+
+    /// Computes the distance correlation between two vectors together with the intermediate
+    /// quantities and diagnostics of the computation.
+    ///
+    /// The algorithm is chosen as in [`ConfiguredDistCorrelation::compute_binary`] and the
+    /// configured settings are applied as in [`ConfiguredDistCorrelation::compute`]. Besides the
+    /// distance correlation, the [`DistCorrDetails`] contain the unclamped dCov² and dVar² of both
+    /// vectors, the number of observations, the algorithm used and flags telling whether a vector
+    /// is degenerate, i.e. has zero distance variance, or a value had to be clamped because of
+    /// rounding errors. With [`NanPolicy::Propagate`] and non-finite values all quantities are
+    /// `NaN`.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `v1_binary` - A flag indicating if v1 is a binary vector, i.e. with at most two distinct values.
+    /// * `v2_binary` - A flag indicating if v2 is a binary vector, i.e. with at most two distinct values.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`DistCorrDetails`], whose `dist_corr` equals the result of
+    /// [`ConfiguredDistCorrelation::compute_binary`].
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `v1` or `v2` has more than two distinct values although indicated binary by v1_binary and v2_binary
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{Algorithm, DistCorrelation};
+    ///
+    /// let v1 = vec![1.0, 2.0, 3.0, 4.0];
+    /// let v2 = vec![5.0, 5.0, 5.0, 5.0];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let details = dist_corr.compute_detailed(&v1, &v2, false, false).unwrap();
+    ///
+    /// assert_eq!(details.dist_corr, 0.0);
+    /// assert!(details.degenerate);
+    /// assert_eq!(details.len, 4);
+    /// assert_eq!(details.algorithm, Algorithm::General);
+    /// ```
+    pub fn compute_detailed<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+        v1_binary: bool,
+        v2_binary: bool,
+    ) -> Result<DistCorrDetails, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            let CompletePairs::Pairs(v1, v2) = complete_pairs(v1, v2, self.config.nan_policy)?
+            else {
+                return Ok(DistCorrDetails::propagated(v1.len()));
+            };
+            let (v1, v2) = (T::slice_to_f64(&v1), U::slice_to_f64(&v2));
+
+            let b1 = v1_binary.then(|| BinaryVector::require(&v1)).transpose()?;
+            let b2 = v2_binary.then(|| BinaryVector::require(&v2)).transpose()?;
+
+            match self.config.estimator {
+                Estimator::Standard if self.config.default_algorithms() => {
+                    dist_corr_details(&v1, b1, &v2, b2)
+                }
+                Estimator::Standard => Ok(standard_details(&v1, &v2, &self.config)),
+                Estimator::BiasCorrected => {
+                    bias_corrected(&v1, &v2, &self.config).map(DistCorrDetails::from_bias_corrected)
+                }
+            }
+        })
+    }
+
+    /// Computes the distance correlation between two prepared vectors.
+    ///
+    /// The sorting, grand means and distance variances are taken from the [`PreparedVector`]s,
+    /// such that only a single `O(n log n)` frobenius inner product remains, respectively an `O(n)`
+    /// computation if one of the vectors is binary. Preparing a vector that is compared with many
+    /// others, e.g. a common target, avoids repeating its preprocessing in every call.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - The first prepared data vector.
+    /// * `v2` - The second prepared data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance correlation between the two vectors. The value
+    /// will be in the range `[0.0, 1.0]` and coincides with [`ConfiguredDistCorrelation::compute`]
+    /// up to rounding.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - The bias-corrected estimator, the accuracy mode or an exponent other than `1.0` is
+    ///   configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{DistCorrelation, PreparedVector};
+    ///
+    /// let target = PreparedVector::new(&[1.0, 4.0, 9.0, 16.0]).unwrap();
+    /// let features = [vec![1.0, 2.0, 3.0, 4.0], vec![0.0, 1.0, 1.0, 0.0]];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let results: Vec<f64> = features
+    ///     .iter()
+    ///     .map(|v| dist_corr.compute_prepared(&PreparedVector::new(v).unwrap(), &target).unwrap())
+    ///     .collect();
+    ///
+    /// assert!(results[0] > results[1]);
+    /// ```
+    pub fn compute_prepared(
+        &self,
+        v1: &PreparedVector,
+        v2: &PreparedVector,
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_unit_exponent(self.config.exponent)?;
+
+            if v1.len() != v2.len() {
+                return Err(DistCorrError::LengthMismatch {
+                    expected: v1.len(),
+                    found: v2.len(),
+                });
+            }
+
+            Ok(dist_corr_prepared(v1, v2))
+        })
+    }
+
+    /// Computes the distance correlation between a target vector and each of many feature vectors.
+    ///
+    /// The target is sorted and its grand means and distance variance are computed only once, see
+    /// [`PreparedVector`]. The features are processed in parallel with rayon.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A slice of [`Numeric`] values representing the target vector.
+    /// * `features` - A slice of feature vectors, e.g. `Vec<f64>` or `&[u8]`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector with one [`FeatureResult`] per feature in the order of `features`. Each entry is either
+    /// the distance correlation between the feature and the target in `[0.0, 1.0]` or an error if the
+    /// length of the feature differs from the length of the target.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if `target` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let target = vec![1.0, 4.0, 9.0, 16.0];
+    /// let features = vec![vec![1.0, 2.0, 3.0, 4.0], vec![0.0, 1.0, 1.0, 0.0], vec![1.0]];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let results = dist_corr.compute_one_vs_many(&target, &features).unwrap();
+    ///
+    /// assert!(results[0].as_ref().unwrap() > results[1].as_ref().unwrap());
+    /// assert!(results[2].is_err());
+    /// ```
+    pub fn compute_one_vs_many<T: Numeric, U: Numeric, V: AsRef<[U]> + Sync>(
+        &self,
+        target: &[T],
+        features: &[V],
+    ) -> Result<Vec<FeatureResult>, DistCorrError> {
+        self.one_vs_many_helper(target, features)
+    }
+
+    /// Computes the distance correlation matrix of a list of vectors.
+    ///
+    /// Every vector is sorted and its grand means and distance variance are computed only once, see
+    /// [`PreparedVector`]. Since the matrix is symmetric, only the upper triangle is evaluated. The
+    /// rows are processed in parallel with rayon.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - A slice of data vectors, e.g. `Vec<f64>` or `&[u8]`, of identical length.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`SymmetricMatrix`] whose entry `(i, j)` is the distance correlation between
+    /// `columns[i]` and `columns[j]`. The diagonal is `1.0`, respectively `0.0` for constant vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The vectors do not have identical length.
+    /// - Any of the vectors is empty.
+    /// - Any of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let columns = [[0.1, 1.0, 2.0, 1.0], [0.0, -1.0, 1.0, 2.0], [-0.1, 1.0, -2.0, 1.0]];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let matrix = dist_corr.correlation_matrix(&columns).unwrap();
+    ///
+    /// assert_eq!(matrix.dim(), 3);
+    /// assert_eq!(matrix.get(0, 2), matrix.get(2, 0));
+    /// assert_eq!(matrix.row(1)[1], 1.0);
+    /// ```
+    pub fn correlation_matrix<T: Numeric, V: AsRef<[T]> + Sync>(
+        &self,
+        columns: &[V],
+    ) -> Result<SymmetricMatrix, DistCorrError> {
+        self.correlation_matrix_helper(columns)
+    }
+
+    /// Computes the cross distance correlation matrix of two lists of vectors.
+    ///
+    /// Every vector is sorted and its grand means and distance variance are computed only once, see
+    /// [`PreparedVector`]. The rows are processed in parallel with rayon.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_1` - A slice of data vectors, e.g. `Vec<f64>` or `&[u8]`, corresponding to the rows.
+    /// * `list_2` - A slice of data vectors, e.g. `Vec<f64>` or `&[u8]`, corresponding to the columns.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of rows, whose entry `[i][j]` is the distance correlation between
+    /// `list_1[i]` and `list_2[j]`.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The vectors of both lists do not have identical length.
+    /// - Any of the vectors is empty.
+    /// - Any of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let list_1 = [[0.1, 1.0, 2.0, 1.0], [0.0, -1.0, 1.0, 2.0]];
+    /// let list_2 = [[-0.1, 1.0, -2.0, 1.0], [0.0, 1.0, 1.0, 2.0], [3.0, 2.0, 1.0, 0.0]];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let matrix = dist_corr.cross_correlation_matrix(&list_1, &list_2).unwrap();
+    ///
+    /// assert_eq!(matrix.len(), 2);
+    /// assert_eq!(matrix[0].len(), 3);
+    /// ```
+    pub fn cross_correlation_matrix<T, U, V, W>(
+        &self,
+        list_1: &[V],
+        list_2: &[W],
+    ) -> Result<Vec<Vec<f64>>, DistCorrError>
+    where
+        T: Numeric,
+        U: Numeric,
+        V: AsRef<[T]> + Sync,
+        W: AsRef<[U]> + Sync,
+    {
+        self.cross_correlation_matrix_helper(list_1, list_2)
+    }
+
+    /// Ranks features by their distance correlation with a target (distance correlation based sure
+    /// independence screening, DC-SIS).
+    ///
+    /// Following Li, Zhong and Zhu (2012), the marginal distance correlation between every feature
+    /// and the target is computed and the features are ranked in decreasing order. The target is
+    /// prepared only once, see [`PreparedVector`], the features are processed in parallel with rayon
+    /// and binary features are handled by the faster binary formulas.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A slice of [`Numeric`] values representing the target vector.
+    /// * `features` - A slice of feature vectors, e.g. `Vec<f64>` or `&[u8]`.
+    /// * `cutoff` - The [`ScreeningCutoff`] deciding how many of the ranked features are retained.
+    ///
+    /// # Returns
+    ///
+    /// Returns the retained [`ScreenedFeature`]s, i.e. the index of the feature in `features` and its
+    /// distance correlation with the target, ranked by decreasing distance correlation. Ties are ranked
+    /// by index.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of the features and the target do not match.
+    /// - The target is empty.
+    /// - The target or a feature contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{DistCorrelation, ScreeningCutoff};
+    ///
+    /// let target = vec![1.0, 4.0, 9.0, 16.0, 25.0];
+    /// let features = vec![
+    ///     vec![0.0, 1.0, 1.0, 0.0, 1.0],
+    ///     vec![1.0, 2.0, 3.0, 4.0, 5.0],
+    ///     vec![2.0, -1.0, 0.5, 3.0, 0.0],
+    /// ];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let screened = dist_corr.screen(&target, &features, ScreeningCutoff::TopK(2)).unwrap();
+    ///
+    /// assert_eq!(screened.len(), 2);
+    /// assert_eq!(screened[0].index, 1);
+    /// ```
+    pub fn screen<T: Numeric, U: Numeric, V: AsRef<[U]> + Sync>(
+        &self,
+        target: &[T],
+        features: &[V],
+        cutoff: ScreeningCutoff,
+    ) -> Result<Vec<ScreenedFeature>, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            check_vectors(&[target], features, self.config.nan_policy)?;
+
+            self.screen_helper(&T::slice_to_f64(target), features, cutoff, None)
+        })
+    }
+
+    /// Ranks features by their distance correlation with a target as
+    /// [`ConfiguredDistCorrelation::screen`] and attaches permutation p-values to the retained
+    /// features.
+    ///
+    /// The p-values are computed as in [`ConfiguredDistCorrelation::permutation_test`],
+    /// respectively [`ConfiguredDistCorrelation::permutation_test_binary`] for binary features or
+    /// targets, only for the retained features.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A slice of [`Numeric`] values representing the target vector.
+    /// * `features` - A slice of feature vectors, e.g. `Vec<f64>` or `&[u8]`.
+    /// * `cutoff` - The [`ScreeningCutoff`] deciding how many of the ranked features are retained.
+    /// * `n_perm` - The number of permutations per retained feature.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// Returns the retained [`ScreenedFeature`]s ranked by decreasing distance correlation, with
+    /// `p_value` set.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of the features and the target do not match.
+    /// - The target is empty.
+    /// - The target or a feature contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - `n_perm` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{DistCorrelation, ScreeningCutoff};
+    ///
+    /// let target: Vec<f64> = (0..60).map(|i| (i as f64 * 0.7).sin()).collect();
+    /// let features = vec![
+    ///     target.iter().map(|x| x * x).collect::<Vec<f64>>(),
+    ///     (0..60).map(|i| ((i * 7919) % 61) as f64).collect(),
+    /// ];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let screened = dist_corr
+    ///     .screen_with_p_values(&target, &features, ScreeningCutoff::All, 199, 1)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(screened[0].index, 0);
+    /// assert!(screened[0].p_value.unwrap() < 0.05);
+    /// ```
+    pub fn screen_with_p_values<T: Numeric, U: Numeric, V: AsRef<[U]> + Sync>(
+        &self,
+        target: &[T],
+        features: &[V],
+        cutoff: ScreeningCutoff,
+        n_perm: usize,
+        seed: u64,
+    ) -> Result<Vec<ScreenedFeature>, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            check_vectors(&[target], features, self.config.nan_policy)?;
+
+            if n_perm == 0 {
+                return Err(DistCorrError::NonPositiveParameter { name: "n_perm" });
+            }
+
+            self.screen_helper(
+                &T::slice_to_f64(target),
+                features,
+                cutoff,
+                Some((n_perm, seed)),
+            )
+        })
+    }
+
+    /// Computes the distance correlation between two multivariate samples.
+    ///
+    /// The samples are given as row-major matrices: row `i` of `x` is `x[i * dim_x..(i + 1) * dim_x]`
+    /// and row `i` of `y` is `y[i * dim_y..(i + 1) * dim_y]`. Distances between rows are Euclidean,
+    /// raised to the configured exponent.
+    /// The algorithm has complexity `O(n^2 (dim_x + dim_y))` and memory `O(n)` where `n` denotes the
+    /// number of rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first sample as row-major matrix.
+    /// * `dim_x` - The dimension of the first sample, i.e. the number of columns of `x`.
+    /// * `y` - A slice of [`Numeric`] values representing the second sample as row-major matrix.
+    /// * `dim_y` - The dimension of the second sample, i.e. the number of columns of `y`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance correlation between the two samples. The value will
+    /// be in the range `[0.0, 1.0]`. With the bias-corrected estimator, the bias-corrected distance
+    /// correlation of the samples is returned.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `dim_x` or `dim_y` is zero.
+    /// - The length of `x` (`y`) is not a multiple of `dim_x` (`dim_y`).
+    /// - The number of rows of `x` and `y` do not match.
+    /// - Either of the samples is empty.
+    /// - Either of the samples contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The bias-corrected estimator is configured and the samples have less than 4 rows.
+    /// - The accuracy mode is configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// // 4 points in the plane and their distance to the origin
+    /// let x = vec![1.0, 0.0, 0.0, 2.0, -3.0, 0.0, 0.0, -4.0];
+    /// let y = vec![1.0, 2.0, 3.0, 4.0];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_multi(&x, 2, &y, 1).unwrap();
+    ///
+    /// assert!(result > 0.0 && result <= 1.0);
+    /// ```
+    pub fn compute_multi<T: Numeric, U: Numeric>(
+        &self,
+        x: &[T],
+        dim_x: usize,
+        y: &[U],
+        dim_y: usize,
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_not_accurate(&self.config)?;
+            check_exponent(self.config.exponent)?;
+            let (x, y): (&[f64], &[f64]) = (&to_f64_checked(x)?, &to_f64_checked(y)?);
+            check_multi(x, dim_x, y, dim_y)?;
+
+            let CompleteRows::Rows([x, y]) =
+                self.config.nan_policy.apply_rows([x, y], [dim_x, dim_y])?
+            else {
+                return Ok(f64::NAN);
+            };
+
+            match self.config.estimator {
+                Estimator::Standard => dist_corr_multi(&x, dim_x, &y, dim_y, self.config.exponent)
+                    .map(|dist_corr| dist_corr.clamp(0.0, 1.0)),
+                Estimator::BiasCorrected => {
+                    check_bias_corrected(x.len() / dim_x)?;
+                    Ok(
+                        dist_corr_multi_bias_corrected(&x, dim_x, &y, dim_y, self.config.exponent)
+                            .dist_corr,
+                    )
+                }
+            }
+        })
+    }
+
+    /// Approximates the distance correlation between two multivariate samples by random projections.
+    ///
+    /// Following Huang and Huo (2017), the samples are projected onto `n_proj` pairs of random
+    /// directions drawn uniformly from the unit spheres. The distance covariance and the distance
+    /// variances are estimated by the averages of the rescaled univariate distance covariances of
+    /// the projections, each of which is computed with the `O(n log n)` algorithm of
+    /// [`ConfiguredDistCorrelation::compute`]. The total complexity is `O(n_proj n (log n + dim_x +
+    /// dim_y))`, which makes very large samples feasible.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first sample as row-major matrix.
+    /// * `dim_x` - The dimension of the first sample, i.e. the number of columns of `x`.
+    /// * `y` - A slice of [`Numeric`] values representing the second sample as row-major matrix.
+    /// * `dim_y` - The dimension of the second sample, i.e. the number of columns of `y`.
+    /// * `n_proj` - The number of random projections.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`RandomProjectionEstimate`] containing the estimated distance correlation in
+    /// `[0.0, 1.0]` and its jackknife standard error over the projections.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `dim_x` or `dim_y` is zero.
+    /// - The length of `x` (`y`) is not a multiple of `dim_x` (`dim_y`).
+    /// - The number of rows of `x` and `y` do not match.
+    /// - Either of the samples is empty.
+    /// - Either of the samples contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The bias-corrected estimator, the accuracy mode or an exponent other than `1.0` is
+    ///   configured, which is not supported.
+    /// - `n_proj` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// // points in the plane and their distance to the origin
+    /// let x: Vec<f64> = (0..200).map(|i| (i as f64 * 0.37).sin()).collect();
+    /// let y: Vec<f64> = x.chunks_exact(2).map(|row| row[0].hypot(row[1])).collect();
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_multi_projection(&x, 2, &y, 1, 50, 7).unwrap();
+    ///
+    /// assert!(result.estimate > 0.0 && result.std_error < 0.1);
+    /// ```
+    pub fn compute_multi_projection<T: Numeric, U: Numeric>(
+        &self,
+        x: &[T],
+        dim_x: usize,
+        y: &[U],
+        dim_y: usize,
+        n_proj: usize,
+        seed: u64,
+    ) -> Result<RandomProjectionEstimate, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_unit_exponent(self.config.exponent)?;
+            let (x, y): (&[f64], &[f64]) = (&to_f64_checked(x)?, &to_f64_checked(y)?);
+            check_multi(x, dim_x, y, dim_y)?;
+
+            let rows = self.config.nan_policy.apply_rows([x, y], [dim_x, dim_y])?;
+
+            if n_proj == 0 {
+                return Err(DistCorrError::NonPositiveParameter { name: "n_proj" });
+            }
+
+            let CompleteRows::Rows([x, y]) = rows else {
+                return Ok(RandomProjectionEstimate::propagated(n_proj));
+            };
+
+            dist_corr_random_projection(&x, dim_x, &y, dim_y, n_proj, seed)
+        })
+    }
+
+    /// Computes the bias-corrected distance correlation between two vectors.
+    ///
+    /// The distance covariance and variances are replaced by their unbiased estimators based on
+    /// U-centered distance matrices (Székely and Rizzo, 2014). The computation uses the same
+    /// `O(n log n)` algorithm as [`ConfiguredDistCorrelation::compute`].
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`BiasCorrectedDistCorr`] containing the unclamped bias-corrected squared distance
+    /// correlation `dist_corr_sq`, which is close to `0.0` or negative for independent vectors, the clamped
+    /// distance correlation `dist_corr` in `[0.0, 1.0]` as well as the unbiased estimators it is built from.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The vectors have less than 4 elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let v1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let v2 = vec![2.0, 4.0, 6.0, 8.0, 10.0];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_bias_corrected(&v1, &v2).unwrap();
+    ///
+    /// assert!((result.dist_corr_sq - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn compute_bias_corrected<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+    ) -> Result<BiasCorrectedDistCorr, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            let CompletePairs::Pairs(v1, v2) = complete_pairs(v1, v2, self.config.nan_policy)?
+            else {
+                return Ok(BiasCorrectedDistCorr::propagated(v1.len()));
+            };
+
+            bias_corrected(&v1, &v2, &self.config)
+        })
+    }
+
+    /// Computes the partial distance correlation of `x` and `y` given `z`.
+    ///
+    /// The partial distance correlation (Székely and Rizzo, 2014) measures the dependence between
+    /// `x` and `y` after removing the effect of the confounder `z`. It is built from the U-centered
+    /// distance matrices and hence from the three pairwise bias-corrected distance correlations
+    /// `R*`, each computed with the `O(n log n)` algorithm of
+    /// [`ConfiguredDistCorrelation::compute_bias_corrected`]:
+    ///
+    /// `pdCor(x, y; z) = (R*_xy - R*_xz R*_yz) / sqrt((1 - R*_xz^2) (1 - R*_yz^2))`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `y` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `z` - A slice of [`Numeric`] values representing the confounding data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`PartialDistCorr`] containing the partial distance correlation in `[-1.0, 1.0]`,
+    /// the partial distance covariance and the three pairwise bias-corrected distance correlations.
+    /// If `x` or `y` is fully explained by `z`, i.e. `R*_xz^2 = 1` or `R*_yz^2 = 1`, the partial
+    /// distance correlation is `0.0`.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `x`, `y` and `z` do not match.
+    /// - Any of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The vectors have less than 4 elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// // x and y both depend on z only
+    /// let z: Vec<f64> = (0..100).map(|i| (i as f64 * 0.37).sin()).collect();
+    /// let x: Vec<f64> = z.iter().map(|v| v * v).collect();
+    /// let y: Vec<f64> = z.iter().map(|v| v.abs()).collect();
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_partial(&x, &y, &z).unwrap();
+    ///
+    /// assert!(result.partial_dist_corr < result.dist_corr_xy.dist_corr_sq);
+    /// ```
+    pub fn compute_partial<X: Numeric, Y: Numeric, Z: Numeric>(
+        &self,
+        x: &[X],
+        y: &[Y],
+        z: &[Z],
+    ) -> Result<PartialDistCorr, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+            let (x, y, z): (&[f64], &[f64], &[f64]) = (
+                &to_f64_checked(x)?,
+                &to_f64_checked(y)?,
+                &to_f64_checked(z)?,
+            );
+            check_partial(x, y, z)?;
+
+            let CompleteRows::Rows([x, y, z]) =
+                self.config.nan_policy.apply_rows([x, y, z], [1, 1, 1])?
+            else {
+                return Ok(PartialDistCorr::propagated(x.len()));
+            };
+
+            partial(&x, &y, &z, &self.config)
+        })
+    }
+
+    /// Computes the partial distance correlation of the multivariate samples `x` and `y` given `z`.
+    ///
+    /// Multivariate version of [`ConfiguredDistCorrelation::compute_partial`] with Euclidean
+    /// distances raised to the configured exponent. The samples are passed as row-major matrices as
+    /// in [`ConfiguredDistCorrelation::compute_multi`]. The algorithm has complexity `O(n^2 (dim_x
+    /// + dim_y + dim_z))` and memory `O(n)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first sample as row-major matrix.
+    /// * `dim_x` - The dimension of the first sample, i.e. the number of columns of `x`.
+    /// * `y` - A slice of [`Numeric`] values representing the second sample as row-major matrix.
+    /// * `dim_y` - The dimension of the second sample, i.e. the number of columns of `y`.
+    /// * `z` - A slice of [`Numeric`] values representing the confounding sample as row-major matrix.
+    /// * `dim_z` - The dimension of the confounding sample, i.e. the number of columns of `z`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`PartialDistCorr`] as [`ConfiguredDistCorrelation::compute_partial`].
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `dim_x`, `dim_y` or `dim_z` is zero.
+    /// - The length of a sample is not a multiple of its dimension.
+    /// - The numbers of rows of `x`, `y` and `z` do not match.
+    /// - The samples have less than 4 rows.
+    /// - Any of the samples contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The accuracy mode is configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let z: Vec<f64> = (0..100).map(|i| (i as f64 * 0.37).sin()).collect();
+    /// let x: Vec<f64> = z.chunks_exact(2).map(|row| row[0] + row[1]).collect();
+    /// let y: Vec<f64> = z.chunks_exact(2).map(|row| row[0] * row[1]).collect();
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let result = dist_corr.compute_partial_multi(&x, 1, &y, 1, &z, 2).unwrap();
+    ///
+    /// assert!(result.partial_dist_corr.abs() <= 1.0);
+    /// ```
+    pub fn compute_partial_multi<X: Numeric, Y: Numeric, Z: Numeric>(
+        &self,
+        x: &[X],
+        dim_x: usize,
+        y: &[Y],
+        dim_y: usize,
+        z: &[Z],
+        dim_z: usize,
+    ) -> Result<PartialDistCorr, DistCorrError> {
+        self.config.run(|| {
+            check_not_accurate(&self.config)?;
+            check_exponent(self.config.exponent)?;
+            let (x, y, z): (&[f64], &[f64], &[f64]) = (
+                &to_f64_checked(x)?,
+                &to_f64_checked(y)?,
+                &to_f64_checked(z)?,
+            );
+            check_partial_multi(x, dim_x, y, dim_y, z, dim_z)?;
+
+            let CompleteRows::Rows([x, y, z]) = self
+                .config
+                .nan_policy
+                .apply_rows([x, y, z], [dim_x, dim_y, dim_z])?
+            else {
+                return Ok(PartialDistCorr::propagated(x.len() / dim_x));
+            };
+
+            check_bias_corrected(x.len() / dim_x)?;
+
+            partial_dist_corr_multi(&x, dim_x, &y, dim_y, &z, dim_z, self.config.exponent)
+        })
+    }
+
+    /// Performs the distance correlation t-test of independence.
+    ///
+    /// Under independence, the bias-corrected squared distance correlation `R` (see
+    /// [`ConfiguredDistCorrelation::compute_bias_corrected`]) transformed to
+    ///
+    /// ```text
+    /// T = sqrt(df) * R / sqrt(1 - R^2),    df = n(n-3)/2 - 1,
+    /// ```
+    ///
+    /// is approximately Student t distributed with `df` degrees of freedom (Székely and Rizzo, 2013).
+    /// The p-value is obtained at the cost of a single `O(n log n)` evaluation without permutations.
+    ///
+    /// The t approximation is derived for samples whose dimension grows. For univariate vectors the
+    /// test is anti-conservative, i.e. it rejects independent vectors more often than the nominal
+    /// level, e.g. at a rate of about 7% at the level 5%, regardless of `n`. Use
+    /// [`ConfiguredDistCorrelation::t_test_multi`] for high-dimensional samples and
+    /// [`ConfiguredDistCorrelation::permutation_test`] for a test that holds its level in low
+    /// dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`DistCorrTTest`] containing the t statistic, the degrees of freedom, the one-sided
+    /// p-value `P(T >= t)` and the bias-corrected squared distance correlation.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The vectors have less than 4 elements.
+    /// - One of the vectors is constant, such that the test statistic is undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let v1: Vec<f64> = (0..100).map(|i| (i as f64 * 0.7).sin()).collect();
+    /// let v2: Vec<f64> = v1.iter().map(|x| x * x).collect();
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let test = dist_corr.t_test(&v1, &v2).unwrap();
+    ///
+    /// assert_eq!(test.df, 4849.0);
+    /// assert!(test.p_value < 1e-6);
+    /// ```
+    pub fn t_test<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+    ) -> Result<DistCorrTTest, DistCorrError> {
+        DistCorrTTest::from_bias_corrected(&self.compute_bias_corrected(v1, v2)?)
+    }
+
+    /// Performs the distance correlation t-test of independence of two multivariate samples.
+    ///
+    /// The test of [`ConfiguredDistCorrelation::t_test`] based on the bias-corrected distance
+    /// correlation of the samples with Euclidean distances raised to the configured exponent. The
+    /// samples are passed as row-major matrices as in [`ConfiguredDistCorrelation::compute_multi`].
+    /// The t approximation holds as the dimensions grow, which is the setting the test is designed
+    /// for (Székely and Rizzo, 2013). The algorithm has complexity `O(n^2 (dim_x + dim_y))` and
+    /// memory `O(n)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first sample as row-major matrix.
+    /// * `dim_x` - The dimension of the first sample, i.e. the number of columns of `x`.
+    /// * `y` - A slice of [`Numeric`] values representing the second sample as row-major matrix.
+    /// * `dim_y` - The dimension of the second sample, i.e. the number of columns of `y`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`DistCorrTTest`] as [`ConfiguredDistCorrelation::t_test`].
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `dim_x` or `dim_y` is zero.
+    /// - The length of `x` (`y`) is not a multiple of `dim_x` (`dim_y`).
+    /// - The number of rows of `x` and `y` do not match.
+    /// - The samples have less than 4 rows.
+    /// - Either of the samples contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - One of the samples is constant, such that the test statistic is undefined.
+    /// - The accuracy mode is configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// // 50 observations of a 20-dimensional sample and of its exponentials
+    /// let x: Vec<f64> = (0..1_000).map(|i| (i as f64 * 0.7).sin()).collect();
+    /// let y: Vec<f64> = x.iter().map(|x| x.exp()).collect();
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let test = dist_corr.t_test_multi(&x, 20, &y, 20).unwrap();
+    ///
+    /// assert_eq!(test.df, 1174.0);
+    /// assert!(test.p_value < 1e-6);
+    /// ```
+    pub fn t_test_multi<T: Numeric, U: Numeric>(
+        &self,
+        x: &[T],
+        dim_x: usize,
+        y: &[U],
+        dim_y: usize,
+    ) -> Result<DistCorrTTest, DistCorrError> {
+        self.config.run(|| {
+            check_not_accurate(&self.config)?;
+            check_exponent(self.config.exponent)?;
+            let (x, y): (&[f64], &[f64]) = (&to_f64_checked(x)?, &to_f64_checked(y)?);
+            check_multi(x, dim_x, y, dim_y)?;
+
+            let CompleteRows::Rows([x, y]) =
+                self.config.nan_policy.apply_rows([x, y], [dim_x, dim_y])?
+            else {
+                return Ok(DistCorrTTest::propagated(x.len() / dim_x));
+            };
+
+            check_bias_corrected(x.len() / dim_x)?;
+
+            DistCorrTTest::from_bias_corrected(&dist_corr_multi_bias_corrected(
+                &x,
+                dim_x,
+                &y,
+                dim_y,
+                self.config.exponent,
+            ))
+        })
+    }
+
+    /// Performs a permutation test of independence based on the distance correlation.
+    ///
+    /// The distance correlation of `v1` and `v2` is compared with the distance correlations obtained
+    /// after randomly permuting `v1` `n_perm` times. The permutations are evaluated in parallel and are
+    /// reproducible for a given `seed`, independently of the number of threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `n_perm` - The number of permutations.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`PermutationTest`] containing the observed distance correlation, the p-value
+    /// `(1 + #{b : T_b >= T}) / (1 + n_perm)` and a summary of the permutation distribution.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - `n_perm` is zero.
+    /// - The bias-corrected estimator, the accuracy mode or an exponent other than `1.0` is
+    ///   configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let v1: Vec<f64> = (0..100).map(|i| (i as f64 * 0.7).sin()).collect();
+    /// let v2: Vec<f64> = v1.iter().map(|x| x * x).collect();
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let test = dist_corr.permutation_test(&v1, &v2, 199, 42).unwrap();
+    ///
+    /// assert!(test.p_value < 0.05);
+    /// ```
+    pub fn permutation_test<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+        n_perm: usize,
+        seed: u64,
+    ) -> Result<PermutationTest, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_unit_exponent(self.config.exponent)?;
+
+            let pairs = complete_pairs(v1, v2, self.config.nan_policy)?;
+
+            if n_perm == 0 {
+                return Err(DistCorrError::NonPositiveParameter { name: "n_perm" });
+            }
+
+            let CompletePairs::Pairs(v1, v2) = pairs else {
+                return Ok(PermutationTest::propagated(n_perm));
+            };
+            let (v1, v2) = (T::slice_to_f64(&v1), U::slice_to_f64(&v2));
+
+            dist_corr_permutation_test(&v1, &v2, n_perm, seed)
+        })
+    }
+
+    /// Performs a permutation test of independence based on the distance correlation where at least
+    /// one vector is binary, i.e. has at most two distinct values.
+    ///
+    /// The non-binary vector is sorted only once. Afterwards every permutation of the binary labels
+    /// costs `O(n)`, such that thousands of permutations are available for the cost of a few
+    /// distance correlation computations.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `v1_binary` - A flag indicating if v1 is a binary vector, i.e. with at most two distinct values.
+    /// * `v2_binary` - A flag indicating if v2 is a binary vector, i.e. with at most two distinct values.
+    /// * `n_perm` - The number of permutations.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`PermutationTest`] as in [`ConfiguredDistCorrelation::permutation_test`]. For the
+    /// same seed, the binary vector is permuted in the same way as `v1` in
+    /// [`ConfiguredDistCorrelation::permutation_test`] if it is passed as first argument there.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `v1` or `v2` has more than two distinct values although indicated binary by v1_binary and v2_binary
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - `n_perm` is zero.
+    /// - The bias-corrected estimator, the accuracy mode or an exponent other than `1.0` is
+    ///   configured, which is not supported.
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let v1: Vec<f64> = (0..200).map(|i| (i % 2) as f64).collect();
+    /// let v2: Vec<f64> = v1.iter().enumerate().map(|(i, x)| x + (i as f64).sin()).collect();
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let test = dist_corr.permutation_test_binary(&v1, &v2, true, false, 999, 42).unwrap();
+    ///
+    /// assert!(test.p_value < 0.01);
+    /// ```
+    pub fn permutation_test_binary<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+        v1_binary: bool,
+        v2_binary: bool,
+        n_perm: usize,
+        seed: u64,
+    ) -> Result<PermutationTest, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_unit_exponent(self.config.exponent)?;
+
+            let pairs = complete_pairs(v1, v2, self.config.nan_policy)?;
+
+            if n_perm == 0 {
+                return Err(DistCorrError::NonPositiveParameter { name: "n_perm" });
+            }
+
+            let CompletePairs::Pairs(v1, v2) = pairs else {
+                return Ok(PermutationTest::propagated(n_perm));
+            };
+            let (v1, v2) = (T::slice_to_f64(&v1), U::slice_to_f64(&v2));
+
+            let b1 = v1_binary.then(|| BinaryVector::require(&v1)).transpose()?;
+            let b2 = v2_binary.then(|| BinaryVector::require(&v2)).transpose()?;
+
+            match (b1, b2) {
+                (Some(b1), Some(b2)) => {
+                    dist_corr_both_binary_permutation_test(&b1.values, &b2.values, n_perm, seed)
+                }
+                (Some(b1), None) => {
+                    dist_corr_one_binary_permutation_test(&b1.values, &v2, n_perm, seed)
+                }
+                (None, Some(b2)) => {
+                    dist_corr_one_binary_permutation_test(&b2.values, &v1, n_perm, seed)
+                }
+                (None, None) => dist_corr_permutation_test(&v1, &v2, n_perm, seed),
+            }
+        })
+    }
+
+    /// Tests the independence of two binary vectors, i.e. with at most two distinct values each,
+    /// without resampling.
+    ///
+    /// As in [`ConfiguredDistCorrelation::compute_binary`], the smaller and larger value of each
+    /// vector are mapped to `0.0` and `1.0`. The vectors are reduced to their 2x2 contingency table
+    /// in `O(n)`. Given the margins of the table, the distance correlation is a monotone function
+    /// of the count `n11`, whose null distribution is hypergeometric. This yields an exact p-value,
+    /// which for symmetric tables coincides with the two-sided Fisher exact test, and the
+    /// asymptotic chi-square p-value of `n * dCor^2` with one degree of freedom.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first binary data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second binary data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`BinaryIndependenceTest`] containing the contingency table, the distance
+    /// correlation, the chi-square statistic and both p-values.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `v1` or `v2` has more than two distinct values
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    ///   Under `Propagate` the statistics and p-values are `NaN` and the table is empty.
+    /// - The bias-corrected estimator or the accuracy mode is configured, which is not supported.
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    ///
+    /// let v1 = vec![1.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0];
+    /// let v2 = vec![1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+    ///
+    /// let dist_corr = DistCorrelation;
+    /// let test = dist_corr.exact_test_both_binary(&v1, &v2).unwrap();
+    ///
+    /// assert_eq!(test.table.n11, 3);
+    /// assert!((test.p_value_exact - 34.0 / 70.0).abs() < 1e-12);
+    /// ```
+    pub fn exact_test_both_binary<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+    ) -> Result<BinaryIndependenceTest, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_exponent(self.config.exponent)?;
+
+            let CompletePairs::Pairs(v1, v2) = complete_pairs(v1, v2, self.config.nan_policy)?
+            else {
+                return Ok(BinaryIndependenceTest::propagated());
+            };
+
+            let (b1, b2) = (BinaryVector::require(&v1)?, BinaryVector::require(&v2)?);
+            let table = ContingencyTable::from_binary(&b1.values, &b2.values)?;
+
+            Ok(BinaryIndependenceTest::from_table(table))
+        })
+    }
+}
+
+impl ConfiguredDistCorrelation {
+    /// checks if the batch computations can use prepared vectors, i.e. for the standard
+    /// estimator, the default algorithms and finite vectors
+    fn uses_prepared<T: Numeric, C: Column<T>>(&self, vectors: &[C]) -> bool {
+        self.config.estimator == Estimator::Standard
+            && self.config.default_algorithms()
+            && vectors
+                .iter()
+                .all(|v| v.values().iter().all(|x| x.to_f64().is_finite()))
+    }
+
+    /// computes the distance correlation of the target with every feature, see
+    /// `compute_one_vs_many`
+    pub(crate) fn one_vs_many_helper<T: Numeric, U: Numeric, C: Column<U>>(
+        &self,
+        target: &[T],
+        features: &[C],
+    ) -> Result<Vec<FeatureResult>, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            if target.is_empty() {
+                return Err(DistCorrError::EmptyInput);
+            }
+
+            let target = to_f64_checked(target)?;
+            let target: &[f64] = &target;
+
+            if self.config.nan_policy == NanPolicy::Reject {
+                check_finite(target)?;
+            }
+
+            if self.uses_prepared(&[target]) && self.uses_prepared(features) {
+                Ok(dist_corr_one_vs_many(target, features))
+            } else {
+                Ok(features
+                    .par_iter()
+                    .map(|feature| self.compute(target, &feature.values()))
+                    .collect())
+            }
+        })
+    }
+
+    /// computes the distance correlation matrix of the columns, see `correlation_matrix`
+    pub(crate) fn correlation_matrix_helper<T: Numeric, C: Column<T>>(
+        &self,
+        columns: &[C],
+    ) -> Result<SymmetricMatrix, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            check_vectors(columns, columns, self.config.nan_policy)?;
+
+            if self.uses_prepared(columns) {
+                Ok(dist_corr_matrix(columns))
+            } else {
+                pairwise_matrix(columns, |v1, v2| self.compute(v1, v2))
+            }
+        })
+    }
+
+    /// computes the cross distance correlation matrix of list_1 and list_2, see
+    /// `cross_correlation_matrix`
+    pub(crate) fn cross_correlation_matrix_helper<T, U, C, D>(
+        &self,
+        list_1: &[C],
+        list_2: &[D],
+    ) -> Result<Vec<Vec<f64>>, DistCorrError>
+    where
+        T: Numeric,
+        U: Numeric,
+        C: Column<T>,
+        D: Column<U>,
+    {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            check_vectors(list_1, list_2, self.config.nan_policy)?;
+
+            if self.uses_prepared(list_1) && self.uses_prepared(list_2) {
+                Ok(cross_dist_corr_matrix(list_1, list_2))
+            } else {
+                pairwise_cross_matrix(list_1, list_2, |v1, v2| self.compute(v1, v2))
+            }
+        })
+    }
+
+    /// ranks the features with prepared vectors if possible and with compute otherwise
+    fn screen_helper<T: Numeric, V: AsRef<[T]> + Sync>(
+        &self,
+        target: &[f64],
+        features: &[V],
+        cutoff: ScreeningCutoff,
+        permutation: Option<(usize, u64)>,
+    ) -> Result<Vec<ScreenedFeature>, DistCorrError> {
+        let nan_policy = self.config.nan_policy;
+
+        if self.uses_prepared(&[target]) && self.uses_prepared(features) {
+            let prepared_target = PreparedVector::prepare(target);
+            dist_corr_screening(
+                target,
+                features,
+                cutoff,
+                permutation,
+                nan_policy,
+                |feature| {
+                    Ok(dist_corr_prepared(
+                        &PreparedVector::prepare(feature),
+                        &prepared_target,
+                    ))
+                },
+            )
+        } else {
+            dist_corr_screening(
+                target,
+                features,
+                cutoff,
+                permutation,
+                nan_policy,
+                |feature| self.compute(feature, target),
+            )
+        }
+    }
+}
+
+impl DistCovariance {
+    /// Returns a [`DistCovarianceBuilder`] starting from the default settings of `DistCovariance`.
+    ///
+    /// The settings are honored by `compute`, `compute_with_nan_policy`, `compute_binary`,
+    /// `compute_auto` and `compute_var`. With [`Estimator::BiasCorrected`] the distance covariance
+    /// (variance) is the square root of the unbiased estimator of dCov² (dVar²), clamped to
+    /// non-negative values, and binary flags only validate the inputs. The same holds for an
+    /// exponent other than `1.0`, whose computations take up to `O(n^2)` time, and for the
+    /// accuracy mode.
+    ///
+    /// The other methods apply the configured [`NanPolicy`] and run on the configured thread pool.
+    /// Settings a method does not support make it fail with
+    /// [`DistCorrError::UnsupportedSetting`] instead of being ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{DistCovariance, Estimator};
+    ///
+    /// let dist_cov = DistCovariance::builder()
+    ///     .estimator(Estimator::BiasCorrected)
+    ///     .parallel(false)
+    ///     .build();
+    ///
+    /// let v = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    /// let result = dist_cov.compute_var(&v).unwrap();
+    ///
+    /// assert!(result > 0.0);
+    /// ```
+    pub fn builder() -> DistCovarianceBuilder {
+        DistCovarianceBuilder::new()
+    }
+
+    /// Computes the distance covariance between two vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of `f64` values representing the first data vector.
+    /// * `v2` - A slice of `f64` values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v1 = vec![0.0, 1.0, 0.0, 1.0];
+    /// let v2 = vec![0.0, 1.0, 1.0, 0.0];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute(&v1, &v2).unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// ```
+    pub fn compute(&self, v1: &[f64], v2: &[f64]) -> Result<f64, DistCorrError> {
+        ConfiguredDistCovariance::DEFAULT.compute(v1, v2)
+    }
+
+    /// Computes the distance covariance between two vectors where at least one is binary, i.e. 0-1-valued.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of `f64` values representing the first data vector.
+    /// * `v2` - A slice of `f64` values representing the second data vector.
+    /// * `v1_binary` - A flag indicating if v1 is a binary vector, i.e. with values either 0.0 or 1.0.
+    /// * `v2_binary` - A flag indicating if v2 is a binary vector, i.e. with values either 0.0 or 1.0.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two binary input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `v1` or `v2` is not 0-1-valued as indicated by v1_binary and v2_binary
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v1 = vec![0.0, 1.0, 0.0, 1.0];
+    /// let v2 = vec![0.0, 1.0, 1.0, 0.0];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_binary(&v1, &v2, true, true).unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// ```
+    pub fn compute_binary(
+        &self,
+        v1: &[f64],
+        v2: &[f64],
+        v1_binary: bool,
+        v2_binary: bool,
+    ) -> Result<f64, DistCorrError> {
+        ConfiguredDistCovariance::DEFAULT.compute_binary(v1, v2, v1_binary, v2_binary)
+    }
+
+    /// Computes the distance variance of a single vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A slice of `f64` values representing the input data vector.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value representing the distance variance of the input vector.
+    /// The result is always non-negative:
+    /// - `0.0` indicates that all points in the vector are identical.
+    ///
+    /// This method is faster than calling `DistCovariance::compute(v,v)` if `v` is non-binary.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The input vector `v` is empty.
+    /// - The input vector `v` contains `NaN` or infinite values.
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v = vec![1.0, 1.0, 1.0];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_var(&v).unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// ```
+    pub fn compute_var(&self, v: &[f64]) -> Result<f64, DistCorrError> {
+        ConfiguredDistCovariance::DEFAULT.compute_var(v)
+    }
+}
+
+impl Deref for DistCovariance {
+    type Target = ConfiguredDistCovariance;
+
+    fn deref(&self) -> &ConfiguredDistCovariance {
+        &ConfiguredDistCovariance::DEFAULT
+    }
+}
+
+impl Default for ConfiguredDistCovariance {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ConfiguredDistCovariance {
+    /// the default settings, i.e. the ones of `DistCovariance`
+    pub(crate) const DEFAULT: Self = ConfiguredDistCovariance {
+        config: Config::DEFAULT,
+    };
+
+    pub(crate) fn from_config(config: Config) -> Self {
+        ConfiguredDistCovariance { config }
+    }
+
+    /// Returns `true` if sums are accumulated with compensated summation of the centered inputs.
+    pub fn accurate(&self) -> bool {
+        self.config.accurate
+    }
+
+    /// Returns `true` if results are bit-identical regardless of the number of threads.
+    pub fn deterministic(&self) -> bool {
+        self.config.deterministic
+    }
+
+    /// Returns the configured [`Estimator`].
+    pub fn estimator(&self) -> Estimator {
+        self.config.estimator
+    }
+
+    /// Returns the configured exponent of the distances.
+    pub fn exponent(&self) -> f64 {
+        self.config.exponent
+    }
+
+    /// Returns the configured [`NanPolicy`].
+    pub fn nan_policy(&self) -> NanPolicy {
+        self.config.nan_policy
+    }
+
+    /// Returns `true` if computations use the global rayon thread pool.
+    pub fn parallel(&self) -> bool {
+        self.config.parallel
+    }
+
+    /// Computes the distance covariance between two vectors.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
+    /// - Either of the vectors contains integers beyond `2^53` in magnitude, see [`Numeric`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v1 = vec![0.0, 1.0, 0.0, 1.0];
+    /// let v2 = vec![0.0, 1.0, 1.0, 0.0];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute(&v1, &v2).unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// ```
+    pub fn compute<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+    ) -> Result<f64, DistCorrError> {
+        self.compute_binary(v1, v2, false, false)
+    }
+
+    /// Computes the distance covariance between two vectors with an explicit treatment of `NaN` and
+    /// infinite values.
+    ///
+    /// [`ConfiguredDistCovariance::compute`] applies the configured policy, [`NanPolicy::Reject`]
+    /// by default, which is overridden by `nan_policy`. With [`NanPolicy::PairwiseDeletion`] every
+    /// pair `(v1[i], v2[i])` containing a non-finite value is dropped before the computation, with
+    /// [`NanPolicy::Propagate`] the result is `NaN` if any value is not finite.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `nan_policy` - The [`NanPolicy`] applied to non-finite values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two input vectors, or `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty, respectively no complete pair is left after pairwise deletion.
+    /// - Either of the vectors contains `NaN` or infinite values and `nan_policy` is [`NanPolicy::Reject`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{DistCovariance, NanPolicy};
+    ///
+    /// let v1 = vec![1.0, 2.0, f64::NAN, 3.0, 4.0];
+    /// let v2 = vec![2.0, 4.0, 5.0, 6.0, f64::INFINITY];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov
+    ///     .compute_with_nan_policy(&v1, &v2, NanPolicy::PairwiseDeletion)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result, DistCovariance.compute(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]).unwrap());
+    /// assert!(dist_cov.compute_with_nan_policy(&v1, &v2, NanPolicy::Propagate).unwrap().is_nan());
+    /// assert!(dist_cov.compute_with_nan_policy(&v1, &v2, NanPolicy::Reject).is_err());
+    /// ```
+    pub fn compute_with_nan_policy<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+        nan_policy: NanPolicy,
+    ) -> Result<f64, DistCorrError> {
+        Self::from_config(Config {
+            nan_policy,
+            ..self.config
+        })
+        .compute(v1, v2)
+    }
+
+    /// Computes the distance covariance between two vectors where at least one is binary.
+    ///
+    /// A binary vector may take any two distinct values `low < high`. It is mapped to
+    /// `{0.0, 1.0}` before the specialized algorithms are applied and the result is rescaled by
+    /// `sqrt(high - low)`, since `dCov(a v1 + b, v2) = sqrt(|a|) dCov(v1, v2)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `v1_binary` - A flag indicating if v1 is a binary vector, i.e. with at most two distinct values.
+    /// * `v2_binary` - A flag indicating if v2 is a binary vector, i.e. with at most two distinct values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two binary input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `v1` or `v2` has more than two distinct values although indicated binary by v1_binary and v2_binary
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v1 = vec![0.0, 1.0, 0.0, 1.0];
+    /// let v2 = vec![0.0, 1.0, 1.0, 0.0];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_binary(&v1, &v2, true, true).unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// ```
+    pub fn compute_binary<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+        v1_binary: bool,
+        v2_binary: bool,
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            let CompletePairs::Pairs(v1, v2) = complete_pairs(v1, v2, self.config.nan_policy)?
+            else {
+                return Ok(f64::NAN);
+            };
+
+            let b1 = v1_binary.then(|| BinaryVector::require(&v1)).transpose()?;
+            let b2 = v2_binary.then(|| BinaryVector::require(&v2)).transpose()?;
+
+            let dist_cov_sq = match self.config.estimator {
+                Estimator::Standard if self.config.default_algorithms() => {
+                    return dist_cov_detected(&v1, b1, &v2, b2).map(|result| result.value);
+                }
+                Estimator::Standard => standard_details(&v1, &v2, &self.config).dist_cov_sq,
+                Estimator::BiasCorrected if self.config.default_algorithms() => {
+                    check_bias_corrected(v1.len())?;
+                    dist_cov_sq_bias_corrected(&T::slice_to_f64(&v1), &U::slice_to_f64(&v2))?
+                }
+                Estimator::BiasCorrected => bias_corrected(&v1, &v2, &self.config)?.dist_cov_sq,
+            };
+
+            Ok(dist_cov_sq.max(0.0).sqrt())
+        })
+    }
+
+    /// Computes the distance covariance between a boolean vector and a data vector.
+    ///
+    /// `false` and `true` are treated as `0.0` and `1.0` and the algorithm for one binary vector is
+    /// used, see [`ConfiguredDistCovariance::compute_binary`].
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of `bool` values representing the binary data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - `v2` contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v1 = vec![false, true, false, true];
+    /// let v2 = vec![0.5, 2.0, 1.0, -0.3];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_bool(&v1, &v2).unwrap();
+    ///
+    /// assert!(result > 0.0);
+    /// ```
+    pub fn compute_bool<U: Numeric>(&self, v1: &[bool], v2: &[U]) -> Result<f64, DistCorrError> {
+        self.compute_binary(&BinaryVector::from_bool(v1).values, v2, true, false)
+    }
+
+    /// Computes the distance covariance between two boolean vectors.
+    ///
+    /// `false` and `true` are treated as `0.0` and `1.0` and the `O(n)` algorithm for two binary
+    /// vectors is used, see [`ConfiguredDistCovariance::compute_binary`].
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of `bool` values representing the first data vector.
+    /// * `v2` - A slice of `bool` values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v1 = vec![false, true, false, true];
+    /// let v2 = vec![false, true, true, false];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_both_bool(&v1, &v2).unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// ```
+    pub fn compute_both_bool(&self, v1: &[bool], v2: &[bool]) -> Result<f64, DistCorrError> {
+        let (v1, v2) = (BinaryVector::from_bool(v1), BinaryVector::from_bool(v2));
+
+        self.compute_binary(&v1.values, &v2.values, true, true)
+    }
+
+    /// Computes the distance covariance between a categorical vector and a data vector.
+    ///
+    /// The labels are compared with the discrete metric, i.e. two observations have distance
+    /// `1.0` if their labels differ and `0.0` otherwise. This generalizes the algorithm for one
+    /// binary vector to `k` levels and runs in `O(n log n + k)`, see
+    /// [`ConfiguredDistCorrelation::compute_categorical`].
+    ///
+    /// # Arguments
+    ///
+    /// * `labels` - A slice of `usize` values representing the levels of the categorical vector.
+    /// * `values` - A slice of [`Numeric`] values representing the data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `labels` and `values` do not match.
+    /// - Either of the vectors is empty.
+    /// - `values` contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The bias-corrected estimator, the accuracy mode or an exponent other than `1.0` is
+    ///   configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let labels = vec![0, 1, 2, 0, 1, 2];
+    /// let values = vec![0.1, 1.2, 2.1, 0.2, 0.9, 2.3];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_categorical(&labels, &values).unwrap();
+    ///
+    /// assert!(result > 0.0);
+    /// ```
+    pub fn compute_categorical<T: Numeric>(
+        &self,
+        labels: &[usize],
+        values: &[T],
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_unit_exponent(self.config.exponent)?;
+
+            let values = to_f64_checked(values)?;
+            let CompleteLabeled::Pairs(labels, values) =
+                complete_categorical(labels, &values, self.config.nan_policy)?
+            else {
+                return Ok(f64::NAN);
+            };
+
+            Ok(dist_cov_categorical(&labels, &values))
+        })
+    }
+
+    /// Computes the distance covariance between two categorical vectors.
+    ///
+    /// Both vectors are compared with the discrete metric and reduced to their `k x m` contingency
+    /// table, which generalizes the algorithm for two binary vectors and runs in `O(n + k m)`.
+    /// All distances are `0.0` or `1.0`, which are invariant under every exponent, hence the
+    /// configured exponent does not change the result and is not rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels_1` - A slice of `usize` values representing the levels of the first vector.
+    /// * `labels_2` - A slice of `usize` values representing the levels of the second vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `labels_1` and `labels_2` do not match.
+    /// - Either of the vectors is empty.
+    /// - The bias-corrected estimator or the accuracy mode is configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let labels_1 = vec![0, 1, 2, 0, 1, 2];
+    /// let labels_2 = vec![5, 7, 9, 5, 7, 9];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_both_categorical(&labels_1, &labels_2).unwrap();
+    ///
+    /// assert!(result > 0.0);
+    /// ```
+    pub fn compute_both_categorical(
+        &self,
+        labels_1: &[usize],
+        labels_2: &[usize],
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_exponent(self.config.exponent)?;
+
+            if labels_1.len() != labels_2.len() {
+                return Err(DistCorrError::LengthMismatch {
+                    expected: labels_1.len(),
+                    found: labels_2.len(),
+                });
+            }
+
+            if labels_1.is_empty() {
+                return Err(DistCorrError::EmptyInput);
+            }
+
+            Ok(dist_cov_both_categorical(labels_1, labels_2))
+        })
+    }
+
+    /// Computes the distance covariance between two vectors with automatic selection of the algorithm.
+    ///
+    /// Each vector is scanned once and the fastest applicable algorithm is chosen as in
+    /// [`ConfiguredDistCorrelation::compute_auto`]. Vectors with two distinct values `low < high`
+    /// that are rescaled to `{0.0, 1.0}` by [`BinaryDetection::TwoValued`] are accounted for by the
+    /// factor `sqrt(high - low)`, since `dCov(a v1 + b, v2) = sqrt(|a|) dCov(v1, v2)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `detection` - The [`BinaryDetection`] deciding which vectors are treated as binary.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`AutoResult`] containing the distance covariance and the
+    /// [`Algorithm`] chosen.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{Algorithm, BinaryDetection, DistCovariance};
+    ///
+    /// let v1 = vec![1.0, 2.0, 1.0, 2.0];
+    /// let v2 = vec![0.5, 2.0, 1.0, -0.3];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_auto(&v1, &v2, BinaryDetection::TwoValued).unwrap();
+    ///
+    /// assert_eq!(result.algorithm, Algorithm::OneBinary);
+    /// assert!((result.value - dist_cov.compute(&v1, &v2).unwrap()).abs() < 1e-12);
+    /// ```
+    pub fn compute_auto<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+        detection: BinaryDetection,
+    ) -> Result<AutoResult, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            let CompletePairs::Pairs(v1, v2) = complete_pairs(v1, v2, self.config.nan_policy)?
+            else {
+                return Ok(AutoResult {
+                    value: f64::NAN,
+                    algorithm: Algorithm::General,
+                });
+            };
+
+            match self.config.estimator {
+                Estimator::Standard if self.config.default_algorithms() => {
+                    dist_cov_auto(&v1, &v2, detection)
+                }
+                _ => Ok(AutoResult {
+                    value: self.compute(&v1, &v2)?,
+                    algorithm: Algorithm::General,
+                }),
+            }
+        })
+    }
+
+    /// Computes the distance covariance between two prepared vectors.
+    ///
+    /// The sorting and grand means are taken from the [`PreparedVector`]s, such that only a single
+    /// `O(n log n)` frobenius inner product remains, respectively an `O(n)` computation if one of the
+    /// vectors is binary.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - The first prepared data vector.
+    /// * `v2` - The second prepared data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two vectors, which coincides
+    /// with [`ConfiguredDistCovariance::compute`] up to rounding.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - The bias-corrected estimator, the accuracy mode or an exponent other than `1.0` is
+    ///   configured, which is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::{DistCovariance, PreparedVector};
+    ///
+    /// let v1 = PreparedVector::new(&[0.0, 1.0, 0.0, 1.0]).unwrap();
+    /// let v2 = PreparedVector::new(&[0.0, 1.0, 1.0, 0.0]).unwrap();
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_prepared(&v1, &v2).unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// assert_eq!(v1.dist_var(), dist_cov.compute_var(&[0.0, 1.0, 0.0, 1.0]).unwrap());
+    /// ```
+    pub fn compute_prepared(
+        &self,
+        v1: &PreparedVector,
+        v2: &PreparedVector,
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_unit_exponent(self.config.exponent)?;
+
+            if v1.len() != v2.len() {
+                return Err(DistCorrError::LengthMismatch {
+                    expected: v1.len(),
+                    found: v2.len(),
+                });
+            }
+
+            Ok(dist_cov_prepared(v1, v2))
+        })
+    }
+
+    /// Computes the distance covariance between two multivariate samples.
+    ///
+    /// The samples are given as row-major matrices: row `i` of `x` is `x[i * dim_x..(i + 1) * dim_x]`
+    /// and row `i` of `y` is `y[i * dim_y..(i + 1) * dim_y]`. Distances between rows are Euclidean,
+    /// raised to the configured exponent.
+    /// The algorithm has complexity `O(n^2 (dim_x + dim_y))` and memory `O(n)` where `n` denotes the
+    /// number of rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first sample as row-major matrix.
+    /// * `dim_x` - The dimension of the first sample, i.e. the number of columns of `x`.
+    /// * `y` - A slice of [`Numeric`] values representing the second sample as row-major matrix.
+    /// * `dim_y` - The dimension of the second sample, i.e. the number of columns of `y`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two samples. With the
+    /// bias-corrected estimator, the square root of the clamped bias-corrected squared distance
+    /// covariance is returned.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `dim_x` or `dim_y` is zero.
+    /// - The length of `x` (`y`) is not a multiple of `dim_x` (`dim_y`).
+    /// - The number of rows of `x` and `y` do not match.
+    /// - Either of the samples is empty.
+    /// - Either of the samples contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The bias-corrected estimator is configured and the samples have less than 4 rows.
+    /// - The accuracy mode is configured, which is not supported.
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let x = vec![0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0];
+    /// let y = vec![0.0, 1.0, 1.0, 0.0];
+    ///
+    /// // y is the XOR of the two columns of x
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_multi(&x, 2, &y, 1).unwrap();
+    ///
+    /// assert!(result > 0.0);
+    /// ```
+    pub fn compute_multi<T: Numeric, U: Numeric>(
+        &self,
+        x: &[T],
+        dim_x: usize,
+        y: &[U],
+        dim_y: usize,
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_not_accurate(&self.config)?;
+            check_exponent(self.config.exponent)?;
+            let (x, y): (&[f64], &[f64]) = (&to_f64_checked(x)?, &to_f64_checked(y)?);
+            check_multi(x, dim_x, y, dim_y)?;
+
+            let CompleteRows::Rows([x, y]) =
+                self.config.nan_policy.apply_rows([x, y], [dim_x, dim_y])?
+            else {
+                return Ok(f64::NAN);
+            };
+
+            match self.config.estimator {
+                Estimator::Standard => dist_cov_multi(&x, dim_x, &y, dim_y, self.config.exponent),
+                Estimator::BiasCorrected => {
+                    check_bias_corrected(x.len() / dim_x)?;
+                    let dist_cov_sq =
+                        dist_corr_multi_bias_corrected(&x, dim_x, &y, dim_y, self.config.exponent)
+                            .dist_cov_sq;
+                    Ok(dist_cov_sq.max(0.0).sqrt())
+                }
+            }
+        })
+    }
+
+    /// Approximates the distance covariance between two multivariate samples by random projections.
+    ///
+    /// The estimator of Huang and Huo (2017) averages the rescaled univariate distance covariances
+    /// of the projections of `x` and `y` onto `n_proj` pairs of random directions. Its expectation
+    /// over the directions is the squared distance covariance computed by
+    /// [`ConfiguredDistCovariance::compute_multi`]. The total complexity is
+    /// `O(n_proj n (log n + dim_x + dim_y))`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first sample as row-major matrix.
+    /// * `dim_x` - The dimension of the first sample, i.e. the number of columns of `x`.
+    /// * `y` - A slice of [`Numeric`] values representing the second sample as row-major matrix.
+    /// * `dim_y` - The dimension of the second sample, i.e. the number of columns of `y`.
+    /// * `n_proj` - The number of random projections.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`RandomProjectionEstimate`] containing the estimated distance covariance and its
+    /// jackknife standard error over the projections.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `dim_x` or `dim_y` is zero.
+    /// - The length of `x` (`y`) is not a multiple of `dim_x` (`dim_y`).
+    /// - The number of rows of `x` and `y` do not match.
+    /// - Either of the samples is empty.
+    /// - Either of the samples contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The bias-corrected estimator, the accuracy mode or an exponent other than `1.0` is
+    ///   configured, which is not supported.
+    /// - `n_proj` is zero.
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let x = vec![0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0];
+    /// let y = vec![0.0, 1.0, 1.0, 0.0];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_multi_projection(&x, 2, &y, 1, 100, 1).unwrap();
+    ///
+    /// assert_eq!(result.n_proj, 100);
+    /// ```
+    pub fn compute_multi_projection<T: Numeric, U: Numeric>(
+        &self,
+        x: &[T],
+        dim_x: usize,
+        y: &[U],
+        dim_y: usize,
+        n_proj: usize,
+        seed: u64,
+    ) -> Result<RandomProjectionEstimate, DistCorrError> {
+        self.config.run(|| {
+            check_standard(&self.config)?;
+            check_unit_exponent(self.config.exponent)?;
+            let (x, y): (&[f64], &[f64]) = (&to_f64_checked(x)?, &to_f64_checked(y)?);
+            check_multi(x, dim_x, y, dim_y)?;
+
+            let rows = self.config.nan_policy.apply_rows([x, y], [dim_x, dim_y])?;
+
+            if n_proj == 0 {
+                return Err(DistCorrError::NonPositiveParameter { name: "n_proj" });
+            }
+
+            let CompleteRows::Rows([x, y]) = rows else {
+                return Ok(RandomProjectionEstimate::propagated(n_proj));
+            };
+
+            dist_cov_random_projection(&x, dim_x, &y, dim_y, n_proj, seed)
+        })
+    }
+
+    /// Computes the unbiased estimator of the squared distance covariance between two vectors.
+    ///
+    /// The estimator is the inner product of the U-centered distance matrices (Székely and Rizzo,
+    /// 2014) and is computed with the same `O(n log n)` algorithm as
+    /// [`ConfiguredDistCovariance::compute`]. Note that the squared distance covariance is
+    /// returned, since the estimator may be negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `v2` - A slice of [`Numeric`] values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the unbiased, unclamped squared distance covariance.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - Either of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The vectors have less than 4 elements.
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v1 = vec![0.0, 1.0, 0.0, 1.0, 0.5];
+    /// let v2 = vec![0.0, 1.0, 1.0, 0.0, 0.5];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_bias_corrected(&v1, &v2).unwrap();
+    ///
+    /// assert!(result < 0.0);
+    /// ```
+    pub fn compute_bias_corrected<T: Numeric, U: Numeric>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            let CompletePairs::Pairs(v1, v2) = complete_pairs(v1, v2, self.config.nan_policy)?
+            else {
+                return Ok(f64::NAN);
+            };
+
+            if self.config.default_algorithms() {
+                check_bias_corrected(v1.len())?;
+                dist_cov_sq_bias_corrected(&T::slice_to_f64(&v1), &U::slice_to_f64(&v2))
+            } else {
+                bias_corrected(&v1, &v2, &self.config).map(|result| result.dist_cov_sq)
+            }
+        })
+    }
+
+    /// Computes the partial distance covariance of `x` and `y` given `z`.
+    ///
+    /// The partial distance covariance (Székely and Rizzo, 2014) is the inner product of the
+    /// projections of the U-centered distance matrices of `x` and `y` onto the orthogonal complement
+    /// of the U-centered distance matrix of `z`, i.e.
+    /// `pdCov(x, y; z) = dCov²_U(x, y) - dCov²_U(x, z) dCov²_U(y, z) / dVar²_U(z)`.
+    /// It is an unbiased quantity and may be negative. If `z` is constant it coincides with
+    /// [`ConfiguredDistCovariance::compute_bias_corrected`].
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first data vector.
+    /// * `y` - A slice of [`Numeric`] values representing the second data vector.
+    /// * `z` - A slice of [`Numeric`] values representing the confounding data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the partial distance covariance.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `x`, `y` and `z` do not match.
+    /// - Any of the vectors contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The vectors have less than 4 elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let y = vec![2.0, 1.0, 4.0, 3.0, 6.0];
+    /// let z = vec![1.0; 5];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_partial(&x, &y, &z).unwrap();
+    ///
+    /// assert_eq!(result, dist_cov.compute_bias_corrected(&x, &y).unwrap());
+    /// ```
+    pub fn compute_partial<X: Numeric, Y: Numeric, Z: Numeric>(
+        &self,
+        x: &[X],
+        y: &[Y],
+        z: &[Z],
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+            let (x, y, z): (&[f64], &[f64], &[f64]) = (
+                &to_f64_checked(x)?,
+                &to_f64_checked(y)?,
+                &to_f64_checked(z)?,
+            );
+            check_partial(x, y, z)?;
+
+            let CompleteRows::Rows([x, y, z]) =
+                self.config.nan_policy.apply_rows([x, y, z], [1, 1, 1])?
+            else {
+                return Ok(f64::NAN);
+            };
+
+            partial(&x, &y, &z, &self.config).map(|partial| partial.partial_dist_cov)
+        })
+    }
+
+    /// Computes the partial distance covariance of the multivariate samples `x` and `y` given `z`.
+    ///
+    /// Multivariate version of [`ConfiguredDistCovariance::compute_partial`] with Euclidean
+    /// distances raised to the configured exponent. The samples are passed as row-major matrices as
+    /// in [`ConfiguredDistCovariance::compute_multi`].
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A slice of [`Numeric`] values representing the first sample as row-major matrix.
+    /// * `dim_x` - The dimension of the first sample, i.e. the number of columns of `x`.
+    /// * `y` - A slice of [`Numeric`] values representing the second sample as row-major matrix.
+    /// * `dim_y` - The dimension of the second sample, i.e. the number of columns of `y`.
+    /// * `z` - A slice of [`Numeric`] values representing the confounding sample as row-major matrix.
+    /// * `dim_z` - The dimension of the confounding sample, i.e. the number of columns of `z`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the partial distance covariance.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - `dim_x`, `dim_y` or `dim_z` is zero.
+    /// - The length of a sample is not a multiple of its dimension.
+    /// - The numbers of rows of `x`, `y` and `z` do not match.
+    /// - The samples have less than 4 rows.
+    /// - Any of the samples contains `NaN` or infinite values and the [`NanPolicy`] is `Reject`.
+    /// - The accuracy mode is configured, which is not supported.
+    pub fn compute_partial_multi<X: Numeric, Y: Numeric, Z: Numeric>(
+        &self,
+        x: &[X],
+        dim_x: usize,
+        y: &[Y],
+        dim_y: usize,
+        z: &[Z],
+        dim_z: usize,
+    ) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_not_accurate(&self.config)?;
+            check_exponent(self.config.exponent)?;
+            let (x, y, z): (&[f64], &[f64], &[f64]) = (
+                &to_f64_checked(x)?,
+                &to_f64_checked(y)?,
+                &to_f64_checked(z)?,
+            );
+            check_partial_multi(x, dim_x, y, dim_y, z, dim_z)?;
+
+            let CompleteRows::Rows([x, y, z]) = self
+                .config
+                .nan_policy
+                .apply_rows([x, y, z], [dim_x, dim_y, dim_z])?
+            else {
+                return Ok(f64::NAN);
+            };
+
+            check_bias_corrected(x.len() / dim_x)?;
+
+            partial_dist_corr_multi(&x, dim_x, &y, dim_y, &z, dim_z, self.config.exponent)
+                .map(|partial| partial.partial_dist_cov)
+        })
+    }
+
+    /// Computes the distance variance of a single vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A slice of [`Numeric`] values representing the input data vector.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value representing the distance variance of the input vector.
+    /// The result is always non-negative:
+    /// - `0.0` indicates that all points in the vector are identical.
+    ///
+    /// This method is faster than calling `DistCovariance::compute(v,v)` if `v` is non-binary.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The input vector `v` is empty.
+    /// - The input vector `v` contains `NaN` or infinite values.
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v = vec![1.0, 1.0, 1.0];
+    ///
+    /// let dist_cov = DistCovariance;
+    /// let result = dist_cov.compute_var(&v).unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// ```
+    pub fn compute_var<T: Numeric>(&self, v: &[T]) -> Result<f64, DistCorrError> {
+        self.config.run(|| {
+            check_exponent(self.config.exponent)?;
+
+            let CompletePairs::Pairs(v, _) = complete_pairs(v, v, self.config.nan_policy)? else {
+                return Ok(f64::NAN);
+            };
+
+            let dist_var_sq = match self.config.estimator {
+                Estimator::Standard if self.config.default_algorithms() => {
+                    return Ok(dist_var(&v));
+                }
+                Estimator::Standard => standard_details(&v, &v, &self.config).dist_var_sq_v1,
+                Estimator::BiasCorrected if self.config.default_algorithms() => {
+                    check_bias_corrected(v.len())?;
+                    dist_var_sq_bias_corrected(&T::slice_to_f64(&v))
+                }
+                Estimator::BiasCorrected => bias_corrected(&v, &v, &self.config)?.dist_var_sq_v1,
+            };
+
+            Ok(dist_var_sq.max(0.0).sqrt())
+        })
+    }
+
+    /// Computes the squared distance covariance and variances of integer vectors exactly.
+    ///
+    /// All sums are accumulated in 128-bit integers with the same `O(n log n)` algorithm as the
+    /// floating-point computations, and the resulting rational statistics are correctly rounded
+    /// to the nearest `f64`. The results are therefore bit-exact references, independent of the
+    /// platform, the number of threads and the order of the observations. The configured
+    /// [`Estimator`] selects the V- or U-statistics, whereas the accuracy mode has no effect. The
+    /// distances have the exponent `1.0`, other configured exponents are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A slice of integer values, such as `i64` or `u32`, representing the first data vector.
+    /// * `v2` - A slice of integer values representing the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`ExactDistCov`] with the correctly rounded dCov²(v1, v2), dVar²(v1) and dVar²(v2).
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if:
+    /// - The lengths of `v1` and `v2` do not match.
+    /// - Either of the vectors is empty.
+    /// - The vectors have less than 4 elements and the estimator is [`Estimator::BiasCorrected`].
+    /// - The product of the length and the range of a vector exceeds `2^61`.
+    /// - An exponent other than `1.0` is configured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    ///
+    /// let v1: Vec<i64> = vec![0, 1, 2, 3];
+    /// let v2: Vec<u32> = vec![0, 1, 2, 3];
+    ///
+    /// let result = DistCovariance.compute_exact(&v1, &v2).unwrap();
+    ///
+    /// // 4^4 dCov² = 4^2 * 40 - 2 * 4 * 104 + 20 * 20 = 208
+    /// assert_eq!(result.dist_cov_sq, 208.0 / 256.0);
+    /// assert_eq!(result.dist_var_sq_v1, result.dist_cov_sq);
+    /// ```
+    pub fn compute_exact<T: Copy + Into<i128>, U: Copy + Into<i128>>(
+        &self,
+        v1: &[T],
+        v2: &[U],
+    ) -> Result<ExactDistCov, DistCorrError> {
+        check_unit_exponent(self.config.exponent)?;
+
+        if v1.len() != v2.len() {
+            return Err(DistCorrError::LengthMismatch {
+                expected: v1.len(),
+                found: v2.len(),
+            });
+        }
+
+        if v1.is_empty() {
+            return Err(DistCorrError::EmptyInput);
+        }
+
+        if self.config.estimator == Estimator::BiasCorrected {
+            check_bias_corrected(v1.len())?;
+        }
+
+        let v1: Vec<i128> = v1.iter().map(|&x| x.into()).collect();
+        let v2: Vec<i128> = v2.iter().map(|&x| x.into()).collect();
+
+        self.config
+            .run(|| dist_cov_exact(&v1, &v2, self.config.estimator))
+    }
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Validation
+
+/// checks that x and y are non-empty row-major matrices with the same number of rows
+fn check_multi(x: &[f64], dim_x: usize, y: &[f64], dim_y: usize) -> Result<(), DistCorrError> {
+    check_dimension(x, dim_x, "dim_x")?;
+    check_dimension(y, dim_y, "dim_y")?;
+
+    if x.len() / dim_x != y.len() / dim_y {
+        return Err(DistCorrError::LengthMismatch {
+            expected: x.len() / dim_x,
+            found: y.len() / dim_y,
+        });
+    }
+
+    if x.is_empty() {
+        return Err(DistCorrError::EmptyInput);
+    }
+
+    Ok(())
+}
+
+/// checks that dim is positive and divides the length of the row-major matrix x
+fn check_dimension(x: &[f64], dim: usize, name: &'static str) -> Result<(), DistCorrError> {
+    if dim == 0 {
+        return Err(DistCorrError::NonPositiveParameter { name });
+    }
+
+    if !x.len().is_multiple_of(dim) {
+        return Err(DistCorrError::InvalidDimension { len: x.len(), dim });
+    }
+
+    Ok(())
+}
+
+/// checks that x, y and z have the same length
+fn check_partial(x: &[f64], y: &[f64], z: &[f64]) -> Result<(), DistCorrError> {
+    for other in [y, z] {
+        if x.len() != other.len() {
+            return Err(DistCorrError::LengthMismatch {
+                expected: x.len(),
+                found: other.len(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// checks that x, y and z are non-empty row-major matrices with the same number of rows
+fn check_partial_multi(
+    x: &[f64],
+    dim_x: usize,
+    y: &[f64],
+    dim_y: usize,
+    z: &[f64],
+    dim_z: usize,
+) -> Result<(), DistCorrError> {
+    check_multi(x, dim_x, y, dim_y)?;
+    check_dimension(z, dim_z, "dim_z")?;
+
+    if x.len() / dim_x != z.len() / dim_z {
+        return Err(DistCorrError::LengthMismatch {
+            expected: x.len() / dim_x,
+            found: z.len() / dim_z,
+        });
+    }
+
+    Ok(())
+}
+
+/// checks v1 and v2 and applies nan_policy, such that the remaining pairs are non-empty
+fn complete_pairs<'a, T: Numeric, U: Numeric>(
+    v1: &'a [T],
+    v2: &'a [U],
+    nan_policy: NanPolicy,
+) -> Result<CompletePairs<'a, T, U>, DistCorrError> {
+    if v1.len() != v2.len() {
+        return Err(DistCorrError::LengthMismatch {
+            expected: v1.len(),
+            found: v2.len(),
+        });
+    }
+
+    match nan_policy.apply(v1, v2)? {
+        CompletePairs::Pairs(v1, _) if v1.is_empty() => Err(DistCorrError::EmptyInput),
+        complete_pairs => Ok(complete_pairs),
+    }
+}
+
+/// checks labels and values and applies nan_policy to the values, such that the remaining
+/// observations are non-empty
+fn complete_categorical<'a>(
+    labels: &'a [usize],
+    values: &'a [f64],
+    nan_policy: NanPolicy,
+) -> Result<CompleteLabeled<'a>, DistCorrError> {
+    if labels.len() != values.len() {
+        return Err(DistCorrError::LengthMismatch {
+            expected: labels.len(),
+            found: values.len(),
+        });
+    }
+
+    if labels.is_empty() {
+        return Err(DistCorrError::EmptyInput);
+    }
+
+    nan_policy.apply_labeled(labels, values)
+}
+
+/// checks that the computation is not configured with the bias-corrected estimator or the
+/// accuracy mode, which it does not support
+fn check_standard(config: &Config) -> Result<(), DistCorrError> {
+    if config.estimator != Estimator::Standard {
+        return Err(DistCorrError::UnsupportedSetting {
+            setting: "estimator",
+        });
+    }
+
+    check_not_accurate(config)
+}
+
+/// checks that the computation is not configured with the accuracy mode, which it does not support
+fn check_not_accurate(config: &Config) -> Result<(), DistCorrError> {
+    if config.accurate {
+        return Err(DistCorrError::UnsupportedSetting {
+            setting: "accurate",
+        });
+    }
+
+    Ok(())
+}
+
+/// checks that the exponent of the distances is 1, the only one the computation supports
+fn check_unit_exponent(exponent: f64) -> Result<(), DistCorrError> {
+    check_exponent(exponent)?;
+
+    if exponent != 1.0 {
+        return Err(DistCorrError::UnsupportedSetting {
+            setting: "exponent",
+        });
+    }
+
+    Ok(())
+}
+
+/// checks that the exponent of the distances lies in (0, 2)
+fn check_exponent(exponent: f64) -> Result<(), DistCorrError> {
+    if exponent > 0.0 && exponent < 2.0 {
+        Ok(())
+    } else {
+        Err(DistCorrError::InvalidExponent { exponent })
+    }
+}
+
+/// computes the details of the standard estimator of v1 and v2 with the general algorithm for
+/// the configured exponent and accuracy mode
+fn standard_details<T: Numeric, U: Numeric>(
+    v1: &[T],
+    v2: &[U],
+    config: &Config,
+) -> DistCorrDetails {
+    let (v1, v2): (&[f64], &[f64]) = (&T::slice_to_f64(v1), &U::slice_to_f64(v2));
+
+    match (config.exponent, config.accurate) {
+        (1.0, false) => general_details(v1, v2),
+        (1.0, true) => CompensatedSums::new(v1, v2).details(),
+        (exponent, false) => ExponentSums::new(v1, v2, exponent).details(),
+        (exponent, true) => ExponentSums::new(&centered(v1), &centered(v2), exponent).details(),
+    }
+}
+
+/// computes the bias-corrected distance correlation of v1 and v2 for the configured exponent and
+/// accuracy mode
+fn bias_corrected<T: Numeric, U: Numeric>(
+    v1: &[T],
+    v2: &[U],
+    config: &Config,
+) -> Result<BiasCorrectedDistCorr, DistCorrError> {
+    check_bias_corrected(v1.len())?;
+
+    let (v1, v2): (&[f64], &[f64]) = (&T::slice_to_f64(v1), &U::slice_to_f64(v2));
+
+    match (config.exponent, config.accurate) {
+        (1.0, false) => dist_corr_bias_corrected(v1, v2),
+        (1.0, true) => Ok(CompensatedSums::new(v1, v2).bias_corrected()),
+        (exponent, false) => Ok(ExponentSums::new(v1, v2, exponent).bias_corrected()),
+        (exponent, true) => {
+            Ok(ExponentSums::new(&centered(v1), &centered(v2), exponent).bias_corrected())
+        }
+    }
+}
+
+/// computes the partial distance correlation of x, y and z for the configured exponent and
+/// accuracy mode
+fn partial<X: Numeric, Y: Numeric, Z: Numeric>(
+    x: &[X],
+    y: &[Y],
+    z: &[Z],
+    config: &Config,
+) -> Result<PartialDistCorr, DistCorrError> {
+    Ok(PartialDistCorr::new(
+        bias_corrected(x, y, config)?,
+        bias_corrected(x, z, config)?,
+        bias_corrected(y, z, config)?,
+    ))
+}
+
+/// checks that len suffices for the bias-corrected estimators
+fn check_bias_corrected(len: usize) -> Result<(), DistCorrError> {
+    if len < 4 {
+        return Err(DistCorrError::TooFewObservations {
+            required: 4,
+            found: len,
+        });
+    }
+
+    Ok(())
+}
+
+/// checks that all vectors of list_1 and list_2 are non-empty, of identical length and exact in
+/// f64, and finite if nan_policy is `Reject`
+fn check_vectors<T: Numeric, U: Numeric, C: Column<T>, D: Column<U>>(
+    list_1: &[C],
+    list_2: &[D],
+    nan_policy: NanPolicy,
+) -> Result<(), DistCorrError> {
+    let mut lengths = list_1
+        .iter()
+        .map(|v| v.len())
+        .chain(list_2.iter().map(|w| w.len()));
+
+    if let Some(len) = lengths.next() {
+        if len == 0 {
+            return Err(DistCorrError::EmptyInput);
+        }
+
+        if let Some(other_len) = lengths.find(|&other_len| other_len != len) {
+            return Err(DistCorrError::LengthMismatch {
+                expected: len,
+                found: other_len,
+            });
+        }
+    }
+
+    fn check_values<T: Numeric>(v: &[T], nan_policy: NanPolicy) -> Result<(), DistCorrError> {
+        check_exact(v)?;
+
+        match nan_policy {
+            NanPolicy::Reject => check_finite(v),
+            _ => Ok(()),
+        }
+    }
+
+    // one vector is materialized at a time
+    list_1
+        .iter()
+        .try_for_each(|v| check_values(&v.values(), nan_policy))?;
+    list_2
+        .iter()
+        .try_for_each(|w| check_values(&w.values(), nan_policy))
 }
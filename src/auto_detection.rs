@@ -0,0 +1,225 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use std::borrow::Cow;
+
+use crate::dist_corr::{dist_corr, dist_cov};
+use crate::dist_corr_binary::{
+    dist_corr_both_binary, dist_corr_one_binary, dist_cov_both_binary, dist_cov_one_binary,
+};
+use crate::error::DistCorrError;
+use crate::numeric::Numeric;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Algorithm used to compute a distance covariance or correlation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// The general `O(n log n)` algorithm.
+    General,
+    /// The `O(n log n)` algorithm for one binary vector.
+    OneBinary,
+    /// The `O(n)` algorithm for two binary vectors based on the contingency table.
+    BothBinary,
+}
+
+/// Which vectors are detected as binary by the automatic algorithm selection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryDetection {
+    /// Only vectors with values in `{0.0, 1.0}`.
+    ZeroOne,
+    /// Every vector with at most two distinct values, which is affinely rescaled to `{0.0, 1.0}`.
+    TwoValued,
+}
+
+/// Result of a computation with automatic algorithm selection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AutoResult {
+    /// The distance covariance or correlation.
+    pub value: f64,
+    /// The algorithm chosen.
+    pub algorithm: Algorithm,
+}
+
+/// a vector mapped to `{0.0, 1.0}` by `(v - low) / scale`
+pub(crate) struct BinaryVector<'a> {
+    pub values: Cow<'a, [f64]>,
+    pub scale: f64,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl<'a> BinaryVector<'a> {
+    /// maps false to 0.0 and true to 1.0
+    pub(crate) fn from_bool(v: &[bool]) -> Self {
+        BinaryVector {
+            values: Cow::Owned(v.iter().map(|&x| if x { 1.0 } else { 0.0 }).collect()),
+            scale: 1.0,
+        }
+    }
+
+    /// returns v as 0-1-valued vector or an error if it has more than two distinct values
+    pub(crate) fn require<T: Numeric>(v: &'a [T]) -> Result<Self, DistCorrError> {
+        let (low, high) = binary_levels(v)?;
+
+        Ok(Self::from_levels(v, low, high, BinaryDetection::TwoValued)
+            .expect("two-valued vectors are always binary"))
+    }
+
+    /// scans v once and returns it as 0-1-valued vector if it is binary according to detection
+    ///
+    /// Vectors with non-finite values are never binary.
+    pub(crate) fn detect<T: Numeric>(v: &'a [T], detection: BinaryDetection) -> Option<Self> {
+        let (low, high) = binary_levels(v).ok()?;
+
+        Self::from_levels(v, low, high, detection)
+    }
+
+    /// maps v with values in {low, high} to 0-1-values, slices of f64 are borrowed if already
+    /// 0-1-valued
+    fn from_levels<T: Numeric>(
+        v: &'a [T],
+        low: f64,
+        high: f64,
+        detection: BinaryDetection,
+    ) -> Option<Self> {
+        if (low == 0.0 || low == 1.0) && (high == 0.0 || high == 1.0) {
+            return Some(BinaryVector {
+                values: T::slice_to_f64(v),
+                scale: 1.0,
+            });
+        }
+
+        match detection {
+            BinaryDetection::ZeroOne => None,
+            BinaryDetection::TwoValued if high > low => Some(BinaryVector {
+                values: Cow::Owned(
+                    v.iter()
+                        .map(|&x| if x.to_f64() == high { 1.0 } else { 0.0 })
+                        .collect(),
+                ),
+                scale: high - low,
+            }),
+            // a constant vector is mapped to zeros
+            BinaryDetection::TwoValued => Some(BinaryVector {
+                values: Cow::Owned(vec![0.0; v.len()]),
+                scale: 1.0,
+            }),
+        }
+    }
+}
+
+/// returns the smallest and largest value of v if v has at most two distinct finite values
+///
+/// Otherwise the error points to the first non-finite value or the first third distinct value.
+fn binary_levels<T: Numeric>(v: &[T]) -> Result<(f64, f64), DistCorrError> {
+    let mut levels: [Option<f64>; 2] = [None, None];
+
+    for (index, value) in v.iter().map(|x| x.to_f64()).enumerate() {
+        if !value.is_finite() {
+            return Err(DistCorrError::NonFinite { index, value });
+        }
+
+        match levels {
+            [Some(a), _] if value == a => {}
+            [_, Some(b)] if value == b => {}
+            [None, _] => levels[0] = Some(value),
+            [Some(_), None] => levels[1] = Some(value),
+            [Some(_), Some(_)] => return Err(DistCorrError::NonBinary { index, value }),
+        }
+    }
+
+    match levels {
+        [Some(a), Some(b)] => Ok((a.min(b), a.max(b))),
+        [Some(a), None] => Ok((a, a)),
+        [None, _] => Err(DistCorrError::EmptyInput),
+    }
+}
+
+/// computes distance correlation of vectors v1 and v2 with the fastest applicable algorithm
+pub(crate) fn dist_corr_auto<T: Numeric, U: Numeric>(
+    v1: &[T],
+    v2: &[U],
+    detection: BinaryDetection,
+) -> Result<AutoResult, DistCorrError> {
+    dist_corr_detected(
+        v1,
+        BinaryVector::detect(v1, detection),
+        v2,
+        BinaryVector::detect(v2, detection),
+    )
+}
+
+/// computes distance covariance of vectors v1 and v2 with the fastest applicable algorithm
+pub(crate) fn dist_cov_auto<T: Numeric, U: Numeric>(
+    v1: &[T],
+    v2: &[U],
+    detection: BinaryDetection,
+) -> Result<AutoResult, DistCorrError> {
+    dist_cov_detected(
+        v1,
+        BinaryVector::detect(v1, detection),
+        v2,
+        BinaryVector::detect(v2, detection),
+    )
+}
+
+/// computes distance correlation of vectors v1 and v2, where b1 and b2 are the
+/// 0-1-valued versions of v1 and v2 if they are binary
+pub(crate) fn dist_corr_detected<T: Numeric, U: Numeric>(
+    v1: &[T],
+    b1: Option<BinaryVector>,
+    v2: &[U],
+    b2: Option<BinaryVector>,
+) -> Result<AutoResult, DistCorrError> {
+    match (b1, b2) {
+        (Some(b1), Some(b2)) => Ok(AutoResult {
+            value: dist_corr_both_binary(&b1.values, &b2.values)?,
+            algorithm: Algorithm::BothBinary,
+        }),
+        (Some(b1), None) => Ok(AutoResult {
+            value: dist_corr_one_binary(&b1.values, v2)?,
+            algorithm: Algorithm::OneBinary,
+        }),
+        (None, Some(b2)) => Ok(AutoResult {
+            value: dist_corr_one_binary(&b2.values, v1)?,
+            algorithm: Algorithm::OneBinary,
+        }),
+        (None, None) => Ok(AutoResult {
+            value: dist_corr(v1, v2)?,
+            algorithm: Algorithm::General,
+        }),
+    }
+}
+
+/// computes distance covariance of vectors v1 and v2, where b1 and b2 are the
+/// 0-1-valued versions of v1 and v2 if they are binary
+///
+/// dCov(a v1 + b, v2) = sqrt(|a|) dCov(v1, v2), hence rescaled vectors are scaled back.
+pub(crate) fn dist_cov_detected<T: Numeric, U: Numeric>(
+    v1: &[T],
+    b1: Option<BinaryVector>,
+    v2: &[U],
+    b2: Option<BinaryVector>,
+) -> Result<AutoResult, DistCorrError> {
+    match (b1, b2) {
+        (Some(b1), Some(b2)) => Ok(AutoResult {
+            value: dist_cov_both_binary(&b1.values, &b2.values)? * (b1.scale * b2.scale).sqrt(),
+            algorithm: Algorithm::BothBinary,
+        }),
+        (Some(b1), None) => Ok(AutoResult {
+            value: dist_cov_one_binary(&b1.values, v2)? * b1.scale.sqrt(),
+            algorithm: Algorithm::OneBinary,
+        }),
+        (None, Some(b2)) => Ok(AutoResult {
+            value: dist_cov_one_binary(&b2.values, v1)? * b2.scale.sqrt(),
+            algorithm: Algorithm::OneBinary,
+        }),
+        (None, None) => Ok(AutoResult {
+            value: dist_cov(v1, v2)?,
+            algorithm: Algorithm::General,
+        }),
+    }
+}
@@ -0,0 +1,211 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::cell::Cell;
+use std::sync::OnceLock;
+
+use crate::api::{ConfiguredDistCorrelation, ConfiguredDistCovariance};
+use crate::error::DistCorrError;
+use crate::nan_policy::NanPolicy;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Estimator of the squared distance covariance.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Estimator {
+    /// The standard estimator of Székely, Rizzo and Bakirov (2007), a V-statistic which is biased
+    /// upwards for finite samples.
+    #[default]
+    Standard,
+    /// The bias-corrected estimator based on U-centered distance matrices, which is unbiased for
+    /// dCov² and requires at least four observations.
+    BiasCorrected,
+}
+
+/// settings shared by DistCorrelation and DistCovariance
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Config {
+    pub(crate) accurate: bool,
+    pub(crate) deterministic: bool,
+    pub(crate) estimator: Estimator,
+    pub(crate) exponent: f64,
+    pub(crate) nan_policy: NanPolicy,
+    pub(crate) parallel: bool,
+}
+
+/// Builder for a [`ConfiguredDistCorrelation`], created by
+/// [`DistCorrelation::builder`](crate::DistCorrelation::builder).
+#[derive(Clone, Debug)]
+pub struct DistCorrelationBuilder {
+    config: Config,
+}
+
+/// Builder for a [`ConfiguredDistCovariance`], created by
+/// [`DistCovariance::builder`](crate::DistCovariance::builder).
+#[derive(Clone, Debug)]
+pub struct DistCovarianceBuilder {
+    config: Config,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl Config {
+    /// the configuration of `DistCorrelation` and `DistCovariance`
+    pub(crate) const DEFAULT: Config = Config {
+        accurate: false,
+        deterministic: false,
+        estimator: Estimator::Standard,
+        exponent: 1.0,
+        nan_policy: NanPolicy::Reject,
+        parallel: true,
+    };
+
+    /// checks if the default algorithms apply, i.e. the exponent one without the accuracy mode,
+    /// which includes the algorithms for binary vectors and prepared vectors
+    pub(crate) fn default_algorithms(&self) -> bool {
+        self.exponent == 1.0 && !self.accurate
+    }
+
+    /// runs f on the global rayon pool, respectively on a single thread if parallel is disabled
+    /// and on a pool of deterministic workers if deterministic is enabled
+    ///
+    /// Fails if the pool of a sequential or deterministic computation cannot be built, since the
+    /// global pool would silently give up the configured guarantees.
+    pub(crate) fn run<T: Send>(
+        &self,
+        f: impl FnOnce() -> Result<T, DistCorrError> + Send,
+    ) -> Result<T, DistCorrError> {
+        match thread_pool(self.parallel, self.deterministic)? {
+            Some(pool) => pool.install(f),
+            None => f(),
+        }
+    }
+}
+
+/// the constructor, setters and build method shared by the builders
+macro_rules! impl_builder {
+    ($($builder:ident => $configured:ident),*) => {
+        $(
+            impl $builder {
+                pub(crate) fn new() -> Self {
+                    $builder {
+                        config: Config::DEFAULT,
+                    }
+                }
+
+                /// Enables or disables the accuracy mode, disabled by default.
+                ///
+                /// dCov² and dVar² are differences of large, nearly equal sums, such that rounding
+                /// errors dominate for large samples or data with a large offset, e.g. timestamps.
+                /// In the accuracy mode the inputs are centered and all sums are accumulated with
+                /// compensated summation, at roughly twice the cost. Binary flags only validate the
+                /// inputs and the general algorithm is used throughout.
+                pub fn accurate(mut self, accurate: bool) -> Self {
+                    self.config.accurate = accurate;
+                    self
+                }
+
+                /// Enables or disables bit-identical results regardless of the number of threads,
+                /// disabled by default.
+                ///
+                /// If enabled, the work is split into a fixed number of chunks instead of one chunk
+                /// per thread, such that the order of the floating-point summations only depends on
+                /// the input. The computations run on a dedicated rayon pool, respectively on a
+                /// single thread if parallel computation is disabled, and may be slower than with
+                /// the default splitting. If the pool cannot be built, the computations fail with
+                /// [`DistCorrError::ThreadPoolUnavailable`] instead of running without the
+                /// guarantee.
+                pub fn deterministic(mut self, deterministic: bool) -> Self {
+                    self.config.deterministic = deterministic;
+                    self
+                }
+
+                /// Sets the [`Estimator`] of the squared distance covariance,
+                /// [`Estimator::Standard`] by default.
+                pub fn estimator(mut self, estimator: Estimator) -> Self {
+                    self.config.estimator = estimator;
+                    self
+                }
+
+                /// Sets the exponent `α` in `(0, 2)` of the distances `|x - y|^α`, `1.0` by
+                /// default, see [Exponent of the distances](crate#exponent-of-the-distances).
+                pub fn exponent(mut self, exponent: f64) -> Self {
+                    self.config.exponent = exponent;
+                    self
+                }
+
+                /// Sets the [`NanPolicy`] applied to non-finite values, [`NanPolicy::Reject`] by
+                /// default.
+                pub fn nan_policy(mut self, nan_policy: NanPolicy) -> Self {
+                    self.config.nan_policy = nan_policy;
+                    self
+                }
+
+                /// Enables or disables the use of the global rayon thread pool, enabled by default.
+                ///
+                /// If disabled, every computation runs on a single thread of a dedicated pool and
+                /// fails with [`DistCorrError::ThreadPoolUnavailable`] if that pool cannot be
+                /// built.
+                pub fn parallel(mut self, parallel: bool) -> Self {
+                    self.config.parallel = parallel;
+                    self
+                }
+
+                #[doc = concat!("Builds the [`", stringify!($configured), "`].")]
+                pub fn build(self) -> $configured {
+                    $configured::from_config(self.config)
+                }
+            }
+        )*
+    };
+}
+
+impl_builder!(
+    DistCorrelationBuilder => ConfiguredDistCorrelation,
+    DistCovarianceBuilder => ConfiguredDistCovariance
+);
+
+thread_local! {
+    /// marks the worker threads of the deterministic pools
+    static DETERMINISTIC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// checks if the current thread is a worker of a deterministic pool, in which case the work
+/// must not be split according to the number of threads
+pub(crate) fn is_deterministic() -> bool {
+    DETERMINISTIC.with(Cell::get)
+}
+
+/// builder of a rayon pool whose workers are marked as deterministic
+pub(crate) fn deterministic_pool_builder() -> ThreadPoolBuilder {
+    ThreadPoolBuilder::new().start_handler(|_| DETERMINISTIC.with(|flag| flag.set(true)))
+}
+
+/// the pool for the given settings, built on first use, or None for the global pool
+///
+/// A pool that cannot be built is not retried and makes every computation with its settings fail.
+fn thread_pool(
+    parallel: bool,
+    deterministic: bool,
+) -> Result<Option<&'static ThreadPool>, DistCorrError> {
+    static SEQUENTIAL: OnceLock<Option<ThreadPool>> = OnceLock::new();
+    static DETERMINISTIC_SEQUENTIAL: OnceLock<Option<ThreadPool>> = OnceLock::new();
+    static DETERMINISTIC_PARALLEL: OnceLock<Option<ThreadPool>> = OnceLock::new();
+
+    let (pool, builder): (_, fn() -> ThreadPoolBuilder) = match (parallel, deterministic) {
+        (true, false) => return Ok(None),
+        (false, false) => (&SEQUENTIAL, || ThreadPoolBuilder::new().num_threads(1)),
+        (false, true) => (&DETERMINISTIC_SEQUENTIAL, || {
+            deterministic_pool_builder().num_threads(1)
+        }),
+        (true, true) => (&DETERMINISTIC_PARALLEL, deterministic_pool_builder),
+    };
+
+    match pool.get_or_init(|| builder().build().ok()) {
+        Some(pool) => Ok(Some(pool)),
+        None => Err(DistCorrError::ThreadPoolUnavailable),
+    }
+}
@@ -4,17 +4,18 @@
 use itertools::izip;
 use log::debug;
 use rayon::prelude::*;
-use std::error::Error;
 
+use crate::error::DistCorrError;
 use crate::frob_inner_product::compute_frobenius_inner_product;
 use crate::grand_mean::GrandMeans;
+use crate::numeric::Numeric;
 use crate::ordering::Ordering;
 
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Implementation
 
 /// computes distance correlation of vectors v1 and v2
-pub(crate) fn dist_corr(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
+pub(crate) fn dist_corr<T: Numeric, U: Numeric>(v1: &[T], v2: &[U]) -> Result<f64, DistCorrError> {
     let len = v1.len();
 
     // sort v1,v2 with respect to ordering of v2
@@ -44,7 +45,7 @@ pub(crate) fn dist_corr(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
 }
 
 /// computes distance covariance of vectors v1 and v2
-pub(crate) fn dist_cov(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
+pub(crate) fn dist_cov<T: Numeric, U: Numeric>(v1: &[T], v2: &[U]) -> Result<f64, DistCorrError> {
     let len = v1.len();
 
     // sort v1,v2 with respect to ordering of v2
@@ -62,21 +63,21 @@ pub(crate) fn dist_cov(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
 }
 
 /// computes dVar(v)
-pub(crate) fn dist_var(v: &[f64]) -> f64 {
+pub(crate) fn dist_var<T: Numeric>(v: &[T]) -> f64 {
     let len = v.len();
 
     // sort v
-    let mut v_ord = v.to_vec();
-    v_ord.par_sort_unstable_by(|v_i, v_j| v_i.partial_cmp(v_j).unwrap());
+    let mut v_ord: Vec<f64> = v.iter().map(|x| x.to_f64()).collect();
+    v_ord.par_sort_unstable_by(|v_i, v_j| v_i.total_cmp(v_j));
 
     // compute grand means
     let grand_means_v = GrandMeans::new(&v_ord).compute_ordered();
 
-    dist_var_sq_helper(v, &grand_means_v, len as f64).sqrt()
+    dist_var_sq_helper(&v_ord, &grand_means_v, len as f64).sqrt()
 }
 
 /// computes dCov^2 from intermediate input
-fn dist_cov_sq_helper(
+pub(crate) fn dist_cov_sq_helper(
     v1: &[f64],
     v2: &[f64],
     grand_mean_v1: &[f64],
@@ -86,6 +87,30 @@ fn dist_cov_sq_helper(
     // frobenius inner product of distance matrices corresponding to v1 and v2
     let frob_prod_dist_mat = compute_frobenius_inner_product(v1, v2, len);
 
+    let dist_cov_sq =
+        v_centered_inner_product(frob_prod_dist_mat, grand_mean_v1, grand_mean_v2, len);
+
+    // dist_cov_sq must be >= 0.0
+    // if not there must be a numerical error
+    if dist_cov_sq < 0.0 {
+        debug!(
+            "dist_cov_sq_helper method gives negative: {:?} - use 0.0",
+            dist_cov_sq
+        );
+        0.0
+    } else {
+        dist_cov_sq
+    }
+}
+
+/// computes the inner product of double centered distance matrices divided by n^2
+/// from their frobenius inner product and grand means
+pub(crate) fn v_centered_inner_product(
+    frob_prod_dist_mat: f64,
+    grand_mean_v1: &[f64],
+    grand_mean_v2: &[f64],
+    len: usize,
+) -> f64 {
     // dot product of the grand means of the distance matrices corresponding to v1 and v2
     let dot_prod_grand_means = izip!(grand_mean_v1, grand_mean_v2)
         .map(|(a, b)| a * b)
@@ -99,24 +124,27 @@ fn dist_cov_sq_helper(
 
     let len_sq = (len * len) as f64;
 
-    let dist_cov_sq = frob_prod_dist_mat / len_sq - 2.0 * dot_prod_grand_means / len as f64
-        + dist_mat_v1_one_norm * dist_mat_v2_one_norm / len_sq;
+    frob_prod_dist_mat / len_sq - 2.0 * dot_prod_grand_means / len as f64
+        + dist_mat_v1_one_norm * dist_mat_v2_one_norm / len_sq
+}
 
-    // dist_cov_sq must be >= 0.0
-    // if not there must be a numerical error
-    if dist_cov_sq < 0.0 {
+/// computes dVar^2 from intermediate input
+pub(crate) fn dist_var_sq_helper(v: &[f64], grand_means: &[f64], len: f64) -> f64 {
+    let dist_var_sq = dist_var_sq_unclamped(v, grand_means, len);
+
+    if dist_var_sq < 0.0 {
         debug!(
-            "dist_cov_sq_helper method gives negative: {:?} - use 0.0",
-            dist_cov_sq
+            "dist_var_sq_helper method gives something negative: {:?} - use 0.0",
+            dist_var_sq
         );
         0.0
     } else {
-        dist_cov_sq
+        dist_var_sq
     }
 }
 
-/// computes dVar^2 from intermediate input
-pub(crate) fn dist_var_sq_helper(v: &[f64], grand_means: &[f64], len: f64) -> f64 {
+/// computes dVar^2 from intermediate input without clamping negative values caused by rounding
+pub(crate) fn dist_var_sq_unclamped(v: &[f64], grand_means: &[f64], len: f64) -> f64 {
     let (sum, sum_of_sq) = v.iter().fold((0.0, 0.0), |(sum, sum_of_sq), &x| {
         (sum + x, sum_of_sq + x * x)
     });
@@ -124,17 +152,6 @@ pub(crate) fn dist_var_sq_helper(v: &[f64], grand_means: &[f64], len: f64) -> f6
     let dist_scalar_prod = 2.0 * len * sum_of_sq - 2.0 * sum.powi(2);
     let len_sq = len * len;
 
-    let dist_var_sq = dist_scalar_prod / len_sq
-        - 2.0 * grand_means.iter().map(|a| a * a).sum::<f64>() / len
-        + grand_means.iter().sum::<f64>().powi(2) / len_sq;
-
-    if dist_var_sq < 0.0 {
-        debug!(
-            "dist_var_sq_helper method gives something negative: {:?} - use 0.0",
-            dist_var_sq
-        );
-        0.0
-    } else {
-        dist_var_sq
-    }
+    dist_scalar_prod / len_sq - 2.0 * grand_means.iter().map(|a| a * a).sum::<f64>() / len
+        + grand_means.iter().sum::<f64>().powi(2) / len_sq
 }
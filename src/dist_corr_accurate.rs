@@ -0,0 +1,140 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use crate::dist_corr_bias_corrected::BiasCorrectedDistCorr;
+use crate::dist_corr_details::DistCorrDetails;
+use crate::frob_inner_product::frobenius_inner_product;
+use crate::grand_mean::GrandMeans;
+use crate::ordering::Ordering;
+use crate::summation::{compensated_dot, compensated_sum, Compensated, Summation};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// compensated sums over the distance matrices `a_ij = |v1_i - v1_j|` and
+/// `b_ij = |v2_i - v2_j|` of the centered vectors, from which the V- and U-statistics follow
+///
+/// The distances do not change under centering, which removes a large common offset before any
+/// sum is formed. All sums, including the Frobenius inner product and the grand means, are
+/// accumulated with compensated summation, and the centered inner products are combined before
+/// the single final rounding.
+pub(crate) struct CompensatedSums {
+    len: usize,
+    /// sum_ij a_ij b_ij, sum_ij a_ij^2 and sum_ij b_ij^2
+    frob_ab: Compensated,
+    frob_aa: Compensated,
+    frob_bb: Compensated,
+    /// grand means a_i / n and b_i / n
+    grand_means_v1: Vec<f64>,
+    grand_means_v2: Vec<f64>,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl CompensatedSums {
+    /// accumulates all sums in O(n log n) time
+    ///
+    /// v1 and v2 must be non-empty, finite and of the same length.
+    pub(crate) fn new(v1: &[f64], v2: &[f64]) -> Self {
+        let len = v1.len();
+        let (v1, v2) = (centered(v1), centered(v2));
+
+        // sort v1,v2 with respect to ordering of v2
+        let Ordering {
+            v1_per,
+            v2_ord,
+            order_v1_per,
+        } = Ordering::order_wrt_v2(&v1, &v2, true);
+
+        // compute grand means of v1 and v2
+        let grand_means_v1 = GrandMeans::new(&v1_per)
+            .compute_unordered_with::<Compensated>(order_v1_per.as_ref().unwrap());
+        let grand_means_v2 = GrandMeans::new(&v2_ord).compute_ordered_with::<Compensated>();
+
+        CompensatedSums {
+            len,
+            frob_ab: frobenius_inner_product(&v1_per, &v2_ord, len),
+            frob_aa: squared_frobenius_norm(&v1),
+            frob_bb: squared_frobenius_norm(&v2),
+            grand_means_v1,
+            grand_means_v2,
+        }
+    }
+
+    /// details of the V-statistics dCov², dVar²(v1) and dVar²(v2)
+    pub(crate) fn details(&self) -> DistCorrDetails {
+        let (gm_1, gm_2) = (&self.grand_means_v1, &self.grand_means_v2);
+
+        DistCorrDetails::from_general(
+            self.v_centered(self.frob_ab, gm_1, gm_2),
+            self.v_centered(self.frob_aa, gm_1, gm_1),
+            self.v_centered(self.frob_bb, gm_2, gm_2),
+            self.len,
+        )
+    }
+
+    /// the bias-corrected distance correlation based on the U-statistics, needs len > 3
+    pub(crate) fn bias_corrected(&self) -> BiasCorrectedDistCorr {
+        let (gm_1, gm_2) = (&self.grand_means_v1, &self.grand_means_v2);
+
+        BiasCorrectedDistCorr::new(
+            self.u_centered(self.frob_ab, gm_1, gm_2),
+            self.u_centered(self.frob_aa, gm_1, gm_1),
+            self.u_centered(self.frob_bb, gm_2, gm_2),
+            self.len,
+        )
+    }
+
+    /// inner product of the double-centered matrices divided by n^2, see `v_centered_inner_product`
+    ///
+    /// ```text
+    /// n^2 dCov² = sum a_ij b_ij - 2 n sum_i gm_a_i gm_b_i + sum_i gm_a_i sum_i gm_b_i
+    /// ```
+    fn v_centered(&self, frob: Compensated, gm_1: &[f64], gm_2: &[f64]) -> f64 {
+        let len = self.len as f64;
+
+        frob.add_scaled(compensated_dot(gm_1, gm_2), -2.0 * len)
+            .add_product(compensated_sum(gm_1).value(), compensated_sum(gm_2).value())
+            .value()
+            / (len * len)
+    }
+
+    /// inner product of the U-centered matrices divided by n(n-3), see `u_centered_inner_product`
+    ///
+    /// ```text
+    /// n(n-3) dCov²_U = sum a_ij b_ij - 2 n^2/(n-2) sum_i gm_a_i gm_b_i
+    ///                  + n^2/((n-1)(n-2)) sum_i gm_a_i sum_i gm_b_i
+    /// ```
+    fn u_centered(&self, frob: Compensated, gm_1: &[f64], gm_2: &[f64]) -> f64 {
+        let len = self.len as f64;
+
+        frob.add_scaled(compensated_dot(gm_1, gm_2), -2.0 * len * len / (len - 2.0))
+            .add_product(
+                compensated_sum(gm_1).value(),
+                compensated_sum(gm_2).value() * len * len / ((len - 1.0) * (len - 2.0)),
+            )
+            .value()
+            / (len * (len - 3.0))
+    }
+}
+
+/// subtracts the mean, computed with compensated summation, from every element of v
+pub(crate) fn centered(v: &[f64]) -> Vec<f64> {
+    let mean = compensated_sum(v).value() / v.len() as f64;
+
+    v.iter().map(|x| x - mean).collect()
+}
+
+/// the squared frobenius norm of the distance matrix of v
+///
+/// ```text
+/// sum_ij (v_i - v_j)^2 = 2 n sum_i v_i^2 - 2 (sum_i v_i)^2
+/// ```
+fn squared_frobenius_norm(v: &[f64]) -> Compensated {
+    let sum = compensated_sum(v).value();
+
+    compensated_dot(v, v)
+        .scale(2.0 * v.len() as f64)
+        .add_product(-2.0 * sum, sum)
+}
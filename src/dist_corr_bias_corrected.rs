@@ -0,0 +1,197 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use itertools::izip;
+use rayon::prelude::*;
+
+use crate::error::DistCorrError;
+use crate::frob_inner_product::compute_frobenius_inner_product;
+use crate::grand_mean::GrandMeans;
+use crate::ordering::Ordering;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Bias-corrected distance correlation based on U-centered distance matrices.
+///
+/// The unbiased estimators are not clamped and may be negative, in particular for
+/// independent data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BiasCorrectedDistCorr {
+    /// Bias-corrected squared distance correlation `dCov²_U(v1, v2) / sqrt(dVar²_U(v1) dVar²_U(v2))`, unclamped.
+    pub dist_corr_sq: f64,
+    /// `sqrt(dist_corr_sq)` after clamping `dist_corr_sq` to `[0.0, 1.0]`.
+    pub dist_corr: f64,
+    /// Unbiased estimator of the squared distance covariance, unclamped.
+    pub dist_cov_sq: f64,
+    /// Unbiased estimator of the squared distance variance of `v1`.
+    pub dist_var_sq_v1: f64,
+    /// Unbiased estimator of the squared distance variance of `v2`.
+    pub dist_var_sq_v2: f64,
+    /// Common length of `v1` and `v2`.
+    pub len: usize,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl BiasCorrectedDistCorr {
+    /// result propagated as `NaN` from len observations
+    pub(crate) fn propagated(len: usize) -> Self {
+        BiasCorrectedDistCorr {
+            dist_corr_sq: f64::NAN,
+            dist_corr: f64::NAN,
+            dist_cov_sq: f64::NAN,
+            dist_var_sq_v1: f64::NAN,
+            dist_var_sq_v2: f64::NAN,
+            len,
+        }
+    }
+
+    /// combines the unbiased estimators to the bias-corrected distance correlation
+    pub(crate) fn new(
+        dist_cov_sq: f64,
+        dist_var_sq_v1: f64,
+        dist_var_sq_v2: f64,
+        len: usize,
+    ) -> Self {
+        let denominator = (dist_var_sq_v1 * dist_var_sq_v2).sqrt();
+
+        let dist_corr_sq = if denominator > 0.0 {
+            dist_cov_sq / denominator
+        } else {
+            0.0
+        };
+
+        BiasCorrectedDistCorr {
+            dist_corr_sq,
+            dist_corr: dist_corr_sq.clamp(0.0, 1.0).sqrt(),
+            dist_cov_sq,
+            dist_var_sq_v1,
+            dist_var_sq_v2,
+            len,
+        }
+    }
+}
+
+/// computes the bias-corrected distance correlation of vectors v1 and v2
+pub(crate) fn dist_corr_bias_corrected(
+    v1: &[f64],
+    v2: &[f64],
+) -> Result<BiasCorrectedDistCorr, DistCorrError> {
+    let len = v1.len();
+
+    // sort v1,v2 with respect to ordering of v2
+    let Ordering {
+        v1_per,
+        v2_ord,
+        order_v1_per,
+    } = Ordering::order_wrt_v2(v1, v2, true);
+
+    // compute grand means of v1 and v2
+    let grand_means_v1 = GrandMeans::new(&v1_per).compute_unordered(order_v1_per.as_ref().unwrap());
+    let grand_means_v2 = GrandMeans::new(&v2_ord).compute_ordered();
+
+    let dist_var_sq_v1 = dist_var_sq_u_helper(&v1_per, &grand_means_v1, len);
+    let dist_var_sq_v2 = dist_var_sq_u_helper(&v2_ord, &grand_means_v2, len);
+    let dist_cov_sq = dist_cov_sq_u_helper(&v1_per, &v2_ord, &grand_means_v1, &grand_means_v2, len);
+
+    Ok(BiasCorrectedDistCorr::new(
+        dist_cov_sq,
+        dist_var_sq_v1,
+        dist_var_sq_v2,
+        len,
+    ))
+}
+
+/// computes the unbiased estimator of dCov^2 of vectors v1 and v2
+pub(crate) fn dist_cov_sq_bias_corrected(v1: &[f64], v2: &[f64]) -> Result<f64, DistCorrError> {
+    let len = v1.len();
+
+    // sort v1,v2 with respect to ordering of v2
+    let Ordering {
+        v1_per,
+        v2_ord,
+        order_v1_per,
+    } = Ordering::order_wrt_v2(v1, v2, true);
+
+    // compute grand means of v1 and v2
+    let grand_means_v1 = GrandMeans::new(&v1_per).compute_unordered(order_v1_per.as_ref().unwrap());
+    let grand_means_v2 = GrandMeans::new(&v2_ord).compute_ordered();
+
+    Ok(dist_cov_sq_u_helper(
+        &v1_per,
+        &v2_ord,
+        &grand_means_v1,
+        &grand_means_v2,
+        len,
+    ))
+}
+
+/// computes the unbiased estimator of dVar^2 of vector v
+pub(crate) fn dist_var_sq_bias_corrected(v: &[f64]) -> f64 {
+    let mut v_ord = v.to_vec();
+    v_ord.par_sort_unstable_by(|v_i, v_j| v_i.total_cmp(v_j));
+
+    let grand_means = GrandMeans::new(&v_ord).compute_ordered();
+
+    dist_var_sq_u_helper(&v_ord, &grand_means, v.len())
+}
+
+/// computes the unbiased estimator of dCov^2 from intermediate input
+///
+/// With row sums `a_i = len * grand_mean_v1[i]` of the distance matrix `a` of v1 (and `b` of v2)
+///
+/// ```text
+/// dCov²_U = ( sum_{i != j} a_ij b_ij - 2/(n-2) sum_i a_i b_i + a b / ((n-1)(n-2)) ) / (n(n-3))
+/// ```
+///
+/// which is the inner product of the U-centered distance matrices. Needs len > 3.
+pub(crate) fn dist_cov_sq_u_helper(
+    v1: &[f64],
+    v2: &[f64],
+    grand_mean_v1: &[f64],
+    grand_mean_v2: &[f64],
+    len: usize,
+) -> f64 {
+    // frobenius inner product of distance matrices corresponding to v1 and v2
+    let frob_prod_dist_mat = compute_frobenius_inner_product(v1, v2, len);
+
+    u_centered_inner_product(frob_prod_dist_mat, grand_mean_v1, grand_mean_v2, len)
+}
+
+/// computes the unbiased estimator of dVar^2 from intermediate input, needs len > 3
+pub(crate) fn dist_var_sq_u_helper(v: &[f64], grand_means: &[f64], len: usize) -> f64 {
+    let (sum, sum_of_sq) = v.iter().fold((0.0, 0.0), |(sum, sum_of_sq), &x| {
+        (sum + x, sum_of_sq + x * x)
+    });
+
+    // squared frobenius norm of the distance matrix corresponding to v
+    let dist_scalar_prod = 2.0 * len as f64 * sum_of_sq - 2.0 * sum.powi(2);
+
+    u_centered_inner_product(dist_scalar_prod, grand_means, grand_means, len)
+}
+
+/// computes the inner product of U-centered distance matrices divided by n(n-3)
+pub(crate) fn u_centered_inner_product(
+    frob_prod_dist_mat: f64,
+    grand_mean_v1: &[f64],
+    grand_mean_v2: &[f64],
+    len: usize,
+) -> f64 {
+    let len = len as f64;
+
+    // dot product of the grand means of the distance matrices corresponding to v1 and v2
+    let dot_prod_grand_means = izip!(grand_mean_v1, grand_mean_v2)
+        .map(|(a, b)| a * b)
+        .sum::<f64>();
+
+    // 1-norm of the distance matrices divided by len
+    let dist_mat_v1_one_norm = grand_mean_v1.iter().sum::<f64>();
+    let dist_mat_v2_one_norm = grand_mean_v2.iter().sum::<f64>();
+
+    (frob_prod_dist_mat / (len * len) - 2.0 * dot_prod_grand_means / (len - 2.0)
+        + dist_mat_v1_one_norm * dist_mat_v2_one_norm / ((len - 1.0) * (len - 2.0)))
+        * len
+        / (len - 3.0)
+}
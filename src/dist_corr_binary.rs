@@ -2,40 +2,107 @@
 // Using
 
 use itertools::izip;
-use std::error::Error;
 
 use crate::dist_corr::dist_var_sq_helper;
+use crate::error::DistCorrError;
 use crate::grand_mean::GrandMeans;
+use crate::numeric::Numeric;
 use crate::ordering::Ordering;
 
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// The 2x2 contingency table of two binary vectors `v1` and `v2`, where the smaller and the larger
+/// value of each vector are counted as `0.0` and `1.0`.
+///
+/// `nab` counts the indices `i` with `v1[i] == a` and `v2[i] == b`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContingencyTable {
+    /// Number of indices with `v1[i] == 0.0` and `v2[i] == 0.0`.
+    pub n00: usize,
+    /// Number of indices with `v1[i] == 0.0` and `v2[i] == 1.0`.
+    pub n01: usize,
+    /// Number of indices with `v1[i] == 1.0` and `v2[i] == 0.0`.
+    pub n10: usize,
+    /// Number of indices with `v1[i] == 1.0` and `v2[i] == 1.0`.
+    pub n11: usize,
+}
+
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Implementation
 
+impl ContingencyTable {
+    /// counts the 2x2 contingency table, v1 and v2 must be 0-1-valued
+    pub(crate) fn from_binary(v1: &[f64], v2: &[f64]) -> Result<Self, DistCorrError> {
+        izip!(v1, v2).enumerate().try_fold(
+            ContingencyTable {
+                n00: 0,
+                n01: 0,
+                n10: 0,
+                n11: 0,
+            },
+            |mut table, (index, (&a, &b))| {
+                match (a, b) {
+                    (0.0, 0.0) => table.n00 += 1,
+                    (0.0, 1.0) => table.n01 += 1,
+                    (1.0, 0.0) => table.n10 += 1,
+                    (1.0, 1.0) => table.n11 += 1,
+                    (0.0 | 1.0, value) | (value, _) => {
+                        return Err(DistCorrError::NonBinary { index, value })
+                    }
+                };
+                Ok(table)
+            },
+        )
+    }
+
+    /// Returns the total number of observations `n00 + n01 + n10 + n11`.
+    pub fn total(&self) -> usize {
+        self.n00 + self.n01 + self.n10 + self.n11
+    }
+
+    /// Returns the distance correlation of `v1` and `v2`, i.e. the absolute value of the phi coefficient.
+    pub fn dist_corr(&self) -> f64 {
+        dist_corr_both_binary_helper(
+            self.n00 as f64,
+            self.n01 as f64,
+            self.n10 as f64,
+            self.n11 as f64,
+        )
+    }
+
+    /// Returns the distance covariance of `v1` and `v2`.
+    pub fn dist_cov(&self) -> f64 {
+        let (n00, n01, n10, n11) = (
+            self.n00 as f64,
+            self.n01 as f64,
+            self.n10 as f64,
+            self.n11 as f64,
+        );
+
+        2.0 * (n11 * n00 - n10 * n01).abs() / (self.total() as f64).powi(2)
+    }
+}
+
 // v1 and v2 must be 0-1-valued
-pub(crate) fn dist_corr_both_binary(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
-    let (n00, n01, n10, n11) = izip!(v1, v2).try_fold(
-        (0.0, 0.0, 0.0, 0.0),
-        |(n00, n01, n10, n11), (&a, &b)| match (a, b) {
-            (0.0, 0.0) => Ok::<(f64, f64, f64, f64), Box<dyn Error>>((n00 + 1.0, n01, n10, n11)),
-            (0.0, 1.0) => Ok((n00, n01 + 1.0, n10, n11)),
-            (1.0, 0.0) => Ok((n00, n01, n10 + 1.0, n11)),
-            (1.0, 1.0) => Ok((n00, n01, n10, n11 + 1.0)),
-            (_, _) => Err("v1 and v2 must be binary (only 0.0 or 1.0)".into()),
-        },
-    )?;
+pub(crate) fn dist_corr_both_binary(v1: &[f64], v2: &[f64]) -> Result<f64, DistCorrError> {
+    Ok(ContingencyTable::from_binary(v1, v2)?.dist_corr())
+}
 
+/// computes dCor from the counts of the 2x2 contingency table of two 0-1-valued vectors
+pub(crate) fn dist_corr_both_binary_helper(n00: f64, n01: f64, n10: f64, n11: f64) -> f64 {
     let denominator: f64 = ((n11 + n10) * (n11 + n01) * (n00 + n01) * (n00 + n10)).sqrt();
 
     if denominator > 0.0 {
         let numerator: f64 = n11 * n00 - n10 * n01;
-        Ok((numerator / denominator).abs())
+        (numerator / denominator).abs()
     } else {
-        Ok(0.0)
+        0.0
     }
 }
 
 /// v1 must be 0-1-valued
-pub(crate) fn dist_corr_one_binary(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
+pub(crate) fn dist_corr_one_binary<U: Numeric>(v1: &[f64], v2: &[U]) -> Result<f64, DistCorrError> {
     let len = v1.len() as f64;
 
     // sort v1,v2 with respect to ordering of v2
@@ -54,22 +121,9 @@ pub(crate) fn dist_corr_one_binary(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dy
     let dist_var_v1 = dist_cov_both_binary(v1, v1)?;
 
     if dist_var_v1 > 0.0 && dist_var_v2 > 0.0 {
-        let (v1_dist_v1, v1_1, v1_dist_1, dist_1) =
-            izip!(v1_per, grand_means_v2_weighted, grand_means_v2).fold(
-                (0.0, 0.0, 0.0, 0.0),
-                |acc, (vi, vwi_weighted, vwi)| {
-                    (
-                        acc.0 + vi * vwi_weighted,
-                        acc.1 + vi,
-                        acc.2 + vi * vwi,
-                        acc.3 + vwi,
-                    )
-                },
-            );
-
         // compute squared distance covariance
-        let dist_cov_sq = -0.5 * v1_dist_v1 / len + (v1_1 / len) * (v1_dist_1 / len)
-            - (0.5 / len) * (v1_1.powi(2) / len) * (dist_1 / len);
+        let dist_cov_sq =
+            dist_cov_sq_one_binary_helper(&v1_per, &grand_means_v2, &grand_means_v2_weighted, len);
 
         Ok((dist_cov_sq / (dist_var_v2 * dist_var_v1))
             .clamp(0.0, 1.0)
@@ -79,24 +133,37 @@ pub(crate) fn dist_corr_one_binary(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dy
     }
 }
 
+/// computes dCov^2 from intermediate input
+///
+/// v1 must be (-1)-1-valued and ordered with respect to v2, grand_means_v2_weighted
+/// are the grand means of v2 weighted by v1
+pub(crate) fn dist_cov_sq_one_binary_helper(
+    v1: &[f64],
+    grand_means_v2: &[f64],
+    grand_means_v2_weighted: &[f64],
+    len: f64,
+) -> f64 {
+    let (v1_dist_v1, v1_1, v1_dist_1, dist_1) = izip!(v1, grand_means_v2_weighted, grand_means_v2)
+        .fold((0.0, 0.0, 0.0, 0.0), |acc, (vi, vwi_weighted, vwi)| {
+            (
+                acc.0 + vi * vwi_weighted,
+                acc.1 + vi,
+                acc.2 + vi * vwi,
+                acc.3 + vwi,
+            )
+        });
+
+    -0.5 * v1_dist_v1 / len + (v1_1 / len) * (v1_dist_1 / len)
+        - (0.5 / len) * (v1_1.powi(2) / len) * (dist_1 / len)
+}
+
 /// v1 and v2 must be a 0-1-valued
-pub(crate) fn dist_cov_both_binary(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
-    let (n00, n01, n10, n11) = izip!(v1, v2).try_fold(
-        (0.0, 0.0, 0.0, 0.0),
-        |(n00, n01, n10, n11), (&a, &b)| match (a, b) {
-            (0.0, 0.0) => Ok::<(f64, f64, f64, f64), Box<dyn Error>>((n00 + 1.0, n01, n10, n11)),
-            (0.0, 1.0) => Ok((n00, n01 + 1.0, n10, n11)),
-            (1.0, 0.0) => Ok((n00, n01, n10 + 1.0, n11)),
-            (1.0, 1.0) => Ok((n00, n01, n10, n11 + 1.0)),
-            (_, _) => Err("v1 and v2 must be binary (only 0.0 or 1.0)".into()),
-        },
-    )?;
-
-    Ok(2.0 * (n11 * n00 - n10 * n01).abs() / (v1.len() as f64).powi(2))
+pub(crate) fn dist_cov_both_binary(v1: &[f64], v2: &[f64]) -> Result<f64, DistCorrError> {
+    Ok(ContingencyTable::from_binary(v1, v2)?.dist_cov())
 }
 
 /// v1 must be 0-1-valued
-pub(crate) fn dist_cov_one_binary(v1: &[f64], v2: &[f64]) -> Result<f64, Box<dyn Error>> {
+pub(crate) fn dist_cov_one_binary<U: Numeric>(v1: &[f64], v2: &[U]) -> Result<f64, DistCorrError> {
     let len = v1.len();
 
     // sort v1,v2 with respect to ordering of v2
@@ -0,0 +1,232 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use itertools::izip;
+use std::collections::HashMap;
+
+use crate::dist_corr::dist_var_sq_helper;
+use crate::grand_mean::GrandMeans;
+use crate::ordering::Ordering;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// labels re-indexed to the levels `0..num_levels` in order of their first occurrence
+struct Levels {
+    levels: Vec<usize>,
+    counts: Vec<usize>,
+}
+
+/// the `k x m` contingency table of two categorical vectors, stored row by row
+struct CategoricalTable {
+    counts: Vec<usize>,
+    row_counts: Vec<usize>,
+    column_counts: Vec<usize>,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl Levels {
+    /// re-indexes labels in O(n)
+    fn new(labels: &[usize]) -> Self {
+        let mut index: HashMap<usize, usize> = HashMap::new();
+        let mut counts = Vec::new();
+
+        let levels = labels
+            .iter()
+            .map(|label| {
+                let level = *index.entry(*label).or_insert_with(|| {
+                    counts.push(0);
+                    counts.len() - 1
+                });
+                counts[level] += 1;
+                level
+            })
+            .collect();
+
+        Levels { levels, counts }
+    }
+
+    fn num_levels(&self) -> usize {
+        self.counts.len()
+    }
+}
+
+impl CategoricalTable {
+    /// counts the contingency table of labels_1 and labels_2 in O(n + k m)
+    fn new(labels_1: &[usize], labels_2: &[usize]) -> Self {
+        let levels_1 = Levels::new(labels_1);
+        let levels_2 = Levels::new(labels_2);
+        let num_columns = levels_2.num_levels();
+
+        let mut counts = vec![0; levels_1.num_levels() * num_columns];
+        izip!(&levels_1.levels, &levels_2.levels)
+            .for_each(|(k, l)| counts[k * num_columns + l] += 1);
+
+        CategoricalTable {
+            counts,
+            row_counts: levels_1.counts,
+            column_counts: levels_2.counts,
+        }
+    }
+
+    /// dCov^2 of the two categorical vectors under the discrete metric
+    ///
+    /// With `a_ij = 1 - [x_i == x_j]`, row means `a_i` and grand mean `a`, the double-centered
+    /// inner product equals `sum a_ij b_ij - 2 n sum_i a_i b_i + n^2 a b`, where every sum only
+    /// depends on the counts of the table.
+    fn dist_cov_sq(&self) -> f64 {
+        let len = self.row_counts.iter().sum::<usize>() as f64;
+        let num_columns = self.column_counts.len();
+
+        let sum_sq = |counts: &[usize]| counts.iter().map(|&c| (c as f64).powi(2)).sum::<f64>();
+        let (row_sum_sq, column_sum_sq, cell_sum_sq) = (
+            sum_sq(&self.row_counts),
+            sum_sq(&self.column_counts),
+            sum_sq(&self.counts),
+        );
+
+        let dist_inner_product = len * len - row_sum_sq - column_sum_sq + cell_sum_sq;
+
+        let row_means_inner_product = self
+            .counts
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c > 0)
+            .map(|(index, &c)| {
+                let row_count = self.row_counts[index / num_columns] as f64;
+                let column_count = self.column_counts[index % num_columns] as f64;
+                c as f64 * (len - row_count) * (len - column_count)
+            })
+            .sum::<f64>()
+            / (len * len);
+
+        let grand_mean_product =
+            (len * len - row_sum_sq) * (len * len - column_sum_sq) / len.powi(4);
+
+        ((dist_inner_product - 2.0 * len * row_means_inner_product
+            + len * len * grand_mean_product)
+            / (len * len))
+            .max(0.0)
+    }
+}
+
+/// dVar^2 of a categorical vector with level counts `counts` under the discrete metric
+fn discrete_dist_var_sq(counts: &[usize], len: f64) -> f64 {
+    let sum_sq = counts.iter().map(|&c| (c as f64).powi(2)).sum::<f64>();
+    let dist_one_norm = len * len - sum_sq;
+
+    let row_means_sum_sq = counts
+        .iter()
+        .map(|&c| c as f64 * (len - c as f64).powi(2))
+        .sum::<f64>()
+        / (len * len);
+
+    ((dist_one_norm - 2.0 * len * row_means_sum_sq + dist_one_norm.powi(2) / (len * len))
+        / (len * len))
+        .max(0.0)
+}
+
+/// computes dCov^2, dVar^2(labels) and dVar^2(v) of the categorical vector labels and the vector v
+///
+/// Generalizes the semi-binary case to k levels in O(n log n + k): since the distance matrix
+/// `A` of v is double-centered and the discrete metric is `1 - [l_i == l_j]`, the inner product
+/// reduces to `-sum_k sum_{i, j in G_k} A_ij`, where `G_k` are the indices of level k. For every
+/// level only its within-level distance sum and the sum of its grand means are needed, which are
+/// accumulated in a single pass over the sorted v.
+fn dist_cov_sq_categorical_helper(labels: &[usize], v: &[f64]) -> (f64, f64, f64) {
+    let len = v.len() as f64;
+    let Levels { levels, counts } = Levels::new(labels);
+
+    // sort levels and v with respect to ordering of v
+    let levels_f64: Vec<f64> = levels.iter().map(|&k| k as f64).collect();
+    let Ordering {
+        v1_per: levels_per,
+        v2_ord,
+        ..
+    } = Ordering::order_wrt_v2(&levels_f64, v, false);
+
+    let grand_means = GrandMeans::new(&v2_ord).compute_ordered();
+    let grand_mean = grand_means.iter().sum::<f64>() / len;
+
+    // within-level distance sums and grand mean sums
+    let mut level_counts = vec![0.0; counts.len()];
+    let mut level_sums = vec![0.0; counts.len()];
+    let mut dist_sums = vec![0.0; counts.len()];
+    let mut grand_mean_sums = vec![0.0; counts.len()];
+
+    izip!(&levels_per, &v2_ord, &grand_means).for_each(|(&k, &x, &grand_mean_i)| {
+        let k = k as usize;
+        // x is at least as large as all previous values of its level
+        dist_sums[k] += 2.0 * (x * level_counts[k] - level_sums[k]);
+        level_counts[k] += 1.0;
+        level_sums[k] += x;
+        grand_mean_sums[k] += grand_mean_i;
+    });
+
+    let dist_cov_sq = -izip!(&counts, &dist_sums, &grand_mean_sums)
+        .map(|(&c, dist_sum, grand_mean_sum)| {
+            let c = c as f64;
+            dist_sum - 2.0 * c * grand_mean_sum + c * c * grand_mean
+        })
+        .sum::<f64>()
+        / (len * len);
+
+    (
+        dist_cov_sq.max(0.0),
+        discrete_dist_var_sq(&counts, len),
+        dist_var_sq_helper(&v2_ord, &grand_means, len),
+    )
+}
+
+/// computes distance correlation of the categorical vector labels and the vector v
+///
+/// labels and v must be non-empty and of the same length
+pub(crate) fn dist_corr_categorical(labels: &[usize], v: &[f64]) -> f64 {
+    let (dist_cov_sq, dist_var_sq_labels, dist_var_sq_v) =
+        dist_cov_sq_categorical_helper(labels, v);
+
+    dist_corr_from_parts(dist_cov_sq, dist_var_sq_labels, dist_var_sq_v)
+}
+
+/// computes distance covariance of the categorical vector labels and the vector v
+///
+/// labels and v must be non-empty and of the same length
+pub(crate) fn dist_cov_categorical(labels: &[usize], v: &[f64]) -> f64 {
+    dist_cov_sq_categorical_helper(labels, v).0.sqrt()
+}
+
+/// computes distance correlation of the categorical vectors labels_1 and labels_2
+///
+/// labels_1 and labels_2 must be non-empty and of the same length
+pub(crate) fn dist_corr_both_categorical(labels_1: &[usize], labels_2: &[usize]) -> f64 {
+    let table = CategoricalTable::new(labels_1, labels_2);
+    let len = labels_1.len() as f64;
+
+    dist_corr_from_parts(
+        table.dist_cov_sq(),
+        discrete_dist_var_sq(&table.row_counts, len),
+        discrete_dist_var_sq(&table.column_counts, len),
+    )
+}
+
+/// computes distance covariance of the categorical vectors labels_1 and labels_2
+///
+/// labels_1 and labels_2 must be non-empty and of the same length
+pub(crate) fn dist_cov_both_categorical(labels_1: &[usize], labels_2: &[usize]) -> f64 {
+    CategoricalTable::new(labels_1, labels_2)
+        .dist_cov_sq()
+        .sqrt()
+}
+
+/// dCor from dCov^2 and the squared distance variances
+fn dist_corr_from_parts(dist_cov_sq: f64, dist_var_sq_1: f64, dist_var_sq_2: f64) -> f64 {
+    if dist_var_sq_1 > 0.0 && dist_var_sq_2 > 0.0 {
+        (dist_cov_sq / (dist_var_sq_1 * dist_var_sq_2).sqrt())
+            .clamp(0.0, 1.0)
+            .sqrt()
+    } else {
+        0.0
+    }
+}
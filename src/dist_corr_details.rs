@@ -0,0 +1,220 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use crate::auto_detection::{Algorithm, BinaryVector};
+use crate::dist_corr::{dist_var_sq_unclamped, v_centered_inner_product};
+use crate::dist_corr_bias_corrected::BiasCorrectedDistCorr;
+use crate::dist_corr_binary::{dist_cov_sq_one_binary_helper, ContingencyTable};
+use crate::error::DistCorrError;
+use crate::frob_inner_product::compute_frobenius_inner_product;
+use crate::grand_mean::GrandMeans;
+use crate::ordering::Ordering;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Distance correlation together with the intermediate quantities and diagnostics of its
+/// computation.
+///
+/// The squared distance covariance and variances are reported before clamping, such that
+/// rounding errors and degenerate inputs can be told apart from independence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistCorrDetails {
+    /// The distance correlation in `[0.0, 1.0]`, as returned by the corresponding `compute` method.
+    pub dist_corr: f64,
+    /// Squared distance covariance `dCov²(v1, v2)`, unclamped.
+    pub dist_cov_sq: f64,
+    /// Squared distance variance `dVar²(v1)`, unclamped.
+    pub dist_var_sq_v1: f64,
+    /// Squared distance variance `dVar²(v2)`, unclamped.
+    pub dist_var_sq_v2: f64,
+    /// Number of observations entering the computation, i.e. after pairwise deletion.
+    pub len: usize,
+    /// The algorithm used.
+    pub algorithm: Algorithm,
+    /// `true` if a distance variance is not positive, e.g. for a constant vector, such that the
+    /// distance correlation is undefined and set to `0.0`.
+    pub degenerate: bool,
+    /// `true` if a quantity was clamped to its valid range, i.e. a negative `dist_cov_sq` or
+    /// distance variance, or a squared distance correlation above one.
+    pub clamped: bool,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl DistCorrDetails {
+    /// details of a result propagated as `NaN` from len observations
+    pub(crate) fn propagated(len: usize) -> Self {
+        DistCorrDetails {
+            dist_corr: f64::NAN,
+            dist_cov_sq: f64::NAN,
+            dist_var_sq_v1: f64::NAN,
+            dist_var_sq_v2: f64::NAN,
+            len,
+            algorithm: Algorithm::General,
+            degenerate: false,
+            clamped: false,
+        }
+    }
+
+    /// details of the general algorithm from the unclamped dCov² and dVar², see `dist_corr`
+    pub(crate) fn from_general(
+        dist_cov_sq: f64,
+        dist_var_sq_v1: f64,
+        dist_var_sq_v2: f64,
+        len: usize,
+    ) -> Self {
+        let dist_var_v1 = dist_var_sq_v1.max(0.0).sqrt();
+        let dist_var_v2 = dist_var_sq_v2.max(0.0).sqrt();
+        let degenerate = dist_var_v1 <= 0.0 || dist_var_v2 <= 0.0;
+
+        let dist_corr = if degenerate {
+            0.0
+        } else {
+            dist_cov_sq.max(0.0).sqrt() / (dist_var_v1 * dist_var_v2).sqrt()
+        };
+
+        DistCorrDetails {
+            dist_corr: dist_corr.clamp(0.0, 1.0),
+            dist_cov_sq,
+            dist_var_sq_v1,
+            dist_var_sq_v2,
+            len,
+            algorithm: Algorithm::General,
+            degenerate,
+            clamped: dist_cov_sq < 0.0
+                || dist_var_sq_v1 < 0.0
+                || dist_var_sq_v2 < 0.0
+                || dist_corr > 1.0,
+        }
+    }
+
+    /// details of the bias-corrected distance correlation
+    pub(crate) fn from_bias_corrected(result: BiasCorrectedDistCorr) -> Self {
+        DistCorrDetails {
+            dist_corr: result.dist_corr,
+            dist_cov_sq: result.dist_cov_sq,
+            dist_var_sq_v1: result.dist_var_sq_v1,
+            dist_var_sq_v2: result.dist_var_sq_v2,
+            len: result.len,
+            algorithm: Algorithm::General,
+            degenerate: result.dist_var_sq_v1 * result.dist_var_sq_v2 <= 0.0,
+            clamped: !(0.0..=1.0).contains(&result.dist_corr_sq),
+        }
+    }
+}
+
+/// computes the details of the distance correlation of vectors v1 and v2, where b1 and b2 are
+/// the 0-1-valued versions of v1 and v2 if they are binary
+///
+/// Mirrors the computations of `dist_corr_detected`, such that `dist_corr` is identical.
+/// dCov² and dVar² of rescaled binary vectors are scaled back to the original values.
+pub(crate) fn dist_corr_details(
+    v1: &[f64],
+    b1: Option<BinaryVector>,
+    v2: &[f64],
+    b2: Option<BinaryVector>,
+) -> Result<DistCorrDetails, DistCorrError> {
+    match (b1, b2) {
+        (Some(b1), Some(b2)) => both_binary_details(&b1, &b2),
+        (Some(b1), None) => one_binary_details(&b1, v2),
+        (None, Some(b2)) => one_binary_details(&b2, v1).map(|details| DistCorrDetails {
+            dist_var_sq_v1: details.dist_var_sq_v2,
+            dist_var_sq_v2: details.dist_var_sq_v1,
+            ..details
+        }),
+        (None, None) => Ok(general_details(v1, v2)),
+    }
+}
+
+/// details of the general algorithm, see `dist_corr`
+pub(crate) fn general_details(v1: &[f64], v2: &[f64]) -> DistCorrDetails {
+    let len = v1.len();
+
+    // sort v1,v2 with respect to ordering of v2
+    let Ordering {
+        v1_per,
+        v2_ord,
+        order_v1_per,
+    } = Ordering::order_wrt_v2(v1, v2, true);
+
+    // compute grand means of v1 and v2
+    let grand_means_v1 = GrandMeans::new(&v1_per).compute_unordered(order_v1_per.as_ref().unwrap());
+    let grand_means_v2 = GrandMeans::new(&v2_ord).compute_ordered();
+
+    let dist_var_sq_v1 = dist_var_sq_unclamped(&v1_per, &grand_means_v1, len as f64);
+    let dist_var_sq_v2 = dist_var_sq_unclamped(&v2_ord, &grand_means_v2, len as f64);
+
+    let frob_prod_dist_mat = compute_frobenius_inner_product(&v1_per, &v2_ord, len);
+    let dist_cov_sq =
+        v_centered_inner_product(frob_prod_dist_mat, &grand_means_v1, &grand_means_v2, len);
+
+    DistCorrDetails::from_general(dist_cov_sq, dist_var_sq_v1, dist_var_sq_v2, len)
+}
+
+/// details of the algorithm for the binary vector b and the vector v, see `dist_corr_one_binary`
+///
+/// dist_var_sq_v1 refers to b and dist_var_sq_v2 to v.
+fn one_binary_details(b: &BinaryVector, v: &[f64]) -> Result<DistCorrDetails, DistCorrError> {
+    let len = v.len();
+
+    // sort b,v with respect to ordering of v
+    let Ordering {
+        mut v1_per, v2_ord, ..
+    } = Ordering::order_wrt_v2(&b.values, v, false);
+
+    v1_per.iter_mut().for_each(|vi| *vi = 2.0 * *vi - 1.0);
+
+    // compute grand means
+    let grand_means_v = GrandMeans::new(&v2_ord).compute_ordered();
+    let grand_means_v_weighted = GrandMeans::new(&v2_ord).compute_ordered_weighted(&v1_per);
+
+    let dist_var_sq_v = dist_var_sq_unclamped(&v2_ord, &grand_means_v, len as f64);
+    let dist_var_b = ContingencyTable::from_binary(&b.values, &b.values)?.dist_cov();
+    let dist_cov_sq =
+        dist_cov_sq_one_binary_helper(&v1_per, &grand_means_v, &grand_means_v_weighted, len as f64);
+
+    let dist_var_v = dist_var_sq_v.max(0.0).sqrt();
+    let degenerate = dist_var_b <= 0.0 || dist_var_v <= 0.0;
+
+    let dist_corr_sq = if degenerate {
+        0.0
+    } else {
+        dist_cov_sq / (dist_var_v * dist_var_b)
+    };
+
+    Ok(DistCorrDetails {
+        dist_corr: dist_corr_sq.clamp(0.0, 1.0).sqrt(),
+        dist_cov_sq: dist_cov_sq * b.scale,
+        dist_var_sq_v1: dist_var_b.powi(2) * b.scale.powi(2),
+        dist_var_sq_v2: dist_var_sq_v,
+        len,
+        algorithm: Algorithm::OneBinary,
+        degenerate,
+        clamped: !(0.0..=1.0).contains(&dist_corr_sq) || dist_var_sq_v < 0.0,
+    })
+}
+
+/// details of the algorithm for two binary vectors based on their contingency tables
+fn both_binary_details(
+    b1: &BinaryVector,
+    b2: &BinaryVector,
+) -> Result<DistCorrDetails, DistCorrError> {
+    let table = ContingencyTable::from_binary(&b1.values, &b2.values)?;
+    let dist_var_b1 = ContingencyTable::from_binary(&b1.values, &b1.values)?.dist_cov();
+    let dist_var_b2 = ContingencyTable::from_binary(&b2.values, &b2.values)?.dist_cov();
+
+    let dist_corr = table.dist_corr();
+
+    Ok(DistCorrDetails {
+        dist_corr: dist_corr.clamp(0.0, 1.0),
+        dist_cov_sq: table.dist_cov().powi(2) * b1.scale * b2.scale,
+        dist_var_sq_v1: dist_var_b1.powi(2) * b1.scale.powi(2),
+        dist_var_sq_v2: dist_var_b2.powi(2) * b2.scale.powi(2),
+        len: table.total(),
+        algorithm: Algorithm::BothBinary,
+        degenerate: dist_var_b1 <= 0.0 || dist_var_b2 <= 0.0,
+        clamped: dist_corr > 1.0,
+    })
+}
@@ -0,0 +1,211 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use itertools::izip;
+use rayon::prelude::*;
+
+use crate::dist_corr_bias_corrected::BiasCorrectedDistCorr;
+use crate::dist_corr_details::DistCorrDetails;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// number of contiguous row blocks, independent of the thread pool to make the sums reproducible
+const NUM_BLOCKS: usize = 32;
+
+/// sums over the distance matrices `a_ij = |v1_i - v1_j|^exponent` and
+/// `b_ij = |v2_i - v2_j|^exponent`, from which the V- and U-statistics follow
+///
+/// With row sums `a_i`, `b_i` and total sums `a`, `b` the double-centered inner products are
+///
+/// ```text
+/// n^2 dCov² = sum a_ij b_ij - 2/n sum a_i b_i + a b / n^2
+/// ```
+///
+/// and analogously for the U-centered ones, see `u_centered`. Identical pairs `(v1_i, v2_i)` have
+/// identical rows, hence the sums run over the q distinct pairs weighted by their multiplicities.
+pub(crate) struct ExponentSums {
+    len: usize,
+    /// sum_ij a_ij b_ij, sum_ij a_ij^2 and sum_ij b_ij^2
+    frob_ab: f64,
+    frob_aa: f64,
+    frob_bb: f64,
+    /// sum_i a_i b_i, sum_i a_i^2 and sum_i b_i^2
+    rows_ab: f64,
+    rows_aa: f64,
+    rows_bb: f64,
+    /// sum_i a_i and sum_i b_i
+    total_a: f64,
+    total_b: f64,
+}
+
+/// the distinct pairs `(v1_i, v2_i)` in lexicographic order with their multiplicities
+struct DistinctPairs {
+    v1: Vec<f64>,
+    v2: Vec<f64>,
+    counts: Vec<f64>,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl DistinctPairs {
+    /// sorts the pairs and merges identical ones in O(n log n)
+    fn new(v1: &[f64], v2: &[f64]) -> Self {
+        let mut pairs: Vec<(f64, f64)> = v1.iter().copied().zip(v2.iter().copied()).collect();
+        pairs
+            .sort_unstable_by(|(x_1, y_1), (x_2, y_2)| x_1.total_cmp(x_2).then(y_1.total_cmp(y_2)));
+
+        let mut distinct = DistinctPairs {
+            v1: Vec::new(),
+            v2: Vec::new(),
+            counts: Vec::new(),
+        };
+        for (x, y) in pairs {
+            match (
+                distinct.v1.last(),
+                distinct.v2.last(),
+                distinct.counts.last_mut(),
+            ) {
+                (Some(&x_last), Some(&y_last), Some(count)) if x == x_last && y == y_last => {
+                    *count += 1.0
+                }
+                _ => {
+                    distinct.v1.push(x);
+                    distinct.v2.push(y);
+                    distinct.counts.push(1.0);
+                }
+            }
+        }
+
+        distinct
+    }
+}
+
+impl ExponentSums {
+    /// accumulates all sums in a single parallel pass over the distinct pairs in
+    /// O(n log n + q^2) time and O(q) memory, where q is the number of distinct pairs
+    /// `(v1_i, v2_i)`
+    ///
+    /// Data with many ties, e.g. integers or rounded measurements, thus takes sub-quadratic time,
+    /// continuous data O(n^2). The result does not depend on the number of threads.
+    ///
+    /// v1 and v2 must be non-empty, finite and of the same length.
+    pub(crate) fn new(v1: &[f64], v2: &[f64], exponent: f64) -> Self {
+        let len = v1.len();
+        let DistinctPairs { v1, v2, counts } = DistinctPairs::new(v1, v2);
+        let num_distinct = counts.len();
+
+        let kernel = |d: f64| distance_power(d, exponent);
+
+        let row_sums = |i: usize| {
+            let (row_a, row_b, frob_ab, frob_aa, frob_bb) = izip!(&v1, &v2, &counts).fold(
+                (0.0, 0.0, 0.0, 0.0, 0.0),
+                |(row_a, row_b, frob_ab, frob_aa, frob_bb), (x, y, count)| {
+                    let a = kernel((v1[i] - x).abs());
+                    let b = kernel((v2[i] - y).abs());
+                    (
+                        row_a + count * a,
+                        row_b + count * b,
+                        frob_ab + count * a * b,
+                        frob_aa + count * a * a,
+                        frob_bb + count * b * b,
+                    )
+                },
+            );
+
+            [
+                frob_ab,
+                frob_aa,
+                frob_bb,
+                row_a * row_b,
+                row_a * row_a,
+                row_b * row_b,
+                row_a,
+                row_b,
+            ]
+            .map(|sum| counts[i] * sum)
+        };
+        let add = |mut acc: [f64; 8], sums: [f64; 8]| {
+            acc.iter_mut()
+                .zip(sums)
+                .for_each(|(acc_k, sum_k)| *acc_k += sum_k);
+            acc
+        };
+
+        // contiguous blocks of rows, reduced in a fixed order
+        let num_blocks = NUM_BLOCKS.min(num_distinct);
+        let blocks: Vec<[f64; 8]> = (0..num_blocks)
+            .into_par_iter()
+            .map(|block| {
+                (block * num_distinct / num_blocks..(block + 1) * num_distinct / num_blocks)
+                    .map(row_sums)
+                    .fold([0.0; 8], add)
+            })
+            .collect();
+
+        let [frob_ab, frob_aa, frob_bb, rows_ab, rows_aa, rows_bb, total_a, total_b] =
+            blocks.into_iter().fold([0.0; 8], add);
+
+        ExponentSums {
+            len,
+            frob_ab,
+            frob_aa,
+            frob_bb,
+            rows_ab,
+            rows_aa,
+            rows_bb,
+            total_a,
+            total_b,
+        }
+    }
+
+    /// details of the V-statistics dCov², dVar²(v1) and dVar²(v2)
+    pub(crate) fn details(&self) -> DistCorrDetails {
+        DistCorrDetails::from_general(
+            self.v_centered(self.frob_ab, self.rows_ab, self.total_a, self.total_b),
+            self.v_centered(self.frob_aa, self.rows_aa, self.total_a, self.total_a),
+            self.v_centered(self.frob_bb, self.rows_bb, self.total_b, self.total_b),
+            self.len,
+        )
+    }
+
+    /// the bias-corrected distance correlation based on the U-statistics, needs len > 3
+    pub(crate) fn bias_corrected(&self) -> BiasCorrectedDistCorr {
+        BiasCorrectedDistCorr::new(
+            self.u_centered(self.frob_ab, self.rows_ab, self.total_a, self.total_b),
+            self.u_centered(self.frob_aa, self.rows_aa, self.total_a, self.total_a),
+            self.u_centered(self.frob_bb, self.rows_bb, self.total_b, self.total_b),
+            self.len,
+        )
+    }
+
+    /// inner product of the double-centered matrices divided by n^2
+    fn v_centered(&self, frob: f64, rows: f64, total_1: f64, total_2: f64) -> f64 {
+        let len = self.len as f64;
+
+        frob / (len * len) - 2.0 * rows / len.powi(3) + total_1 * total_2 / len.powi(4)
+    }
+
+    /// inner product of the U-centered matrices divided by n(n-3)
+    fn u_centered(&self, frob: f64, rows: f64, total_1: f64, total_2: f64) -> f64 {
+        let len = self.len as f64;
+
+        (frob - 2.0 * rows / (len - 2.0) + total_1 * total_2 / ((len - 1.0) * (len - 2.0)))
+            / (len * (len - 3.0))
+    }
+}
+
+/// raises the distance d to the exponent
+///
+/// The exponents 1, 1/2 and 3/2 avoid the general power function. This saves a constant factor
+/// per distance only, the sums still run over all q^2 distinct pairs.
+#[inline]
+pub(crate) fn distance_power(d: f64, exponent: f64) -> f64 {
+    match exponent {
+        1.0 => d,
+        0.5 => d.sqrt(),
+        1.5 => d * d.sqrt(),
+        _ => d.powf(exponent),
+    }
+}
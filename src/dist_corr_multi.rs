@@ -2,163 +2,205 @@
 // Using
 
 use itertools::izip;
+use log::debug;
 use rayon::prelude::*;
 
-// move
-pub fn _dist_cov_multi(v1: &[Vec<f64>], v2: &[Vec<f64>]) -> f64 {
-    let data_length = v1.len() as f64;
-    let dist_frob_norm = v1
-        .par_iter()
-        .zip(v2.par_iter())
-        .enumerate()
-        .map(|(i, (a1, b1))| {
-            v1[i..]
-                .iter()
-                .zip(v2[i..].iter())
-                .map(|(a2, b2)| {
-                    izip!(a1, a2)
-                        .map(|(a1_i, a2_i)| (a1_i - a2_i).powi(2))
-                        .sum::<f64>()
-                        .sqrt()
-                        * izip!(b1, b2)
-                            .map(|(b1_i, b2_i)| (b1_i - b2_i).powi(2))
-                            .sum::<f64>()
-                            .sqrt()
-                })
-                .sum::<f64>()
-        })
-        .sum::<f64>()
-        * 2.0
-        / (data_length * data_length);
-
-    let dist_scalar_avg = v1
-        .par_iter()
-        .zip(v2.par_iter())
-        .map(|(a1, b1)| {
-            let data1i = v1
-                .iter()
-                .map(|a2| {
-                    izip!(a1, a2)
-                        .map(|(a1_i, a2_i)| (a1_i - a2_i).powi(2))
-                        .sum::<f64>()
-                        .sqrt()
-                })
-                .sum::<f64>();
-            let data2i = v2
-                .iter()
-                .map(|b2| {
-                    izip!(b1, b2)
-                        .map(|(b1_i, b2_i)| (b1_i - b2_i).powi(2))
-                        .sum::<f64>()
-                        .sqrt()
-                })
-                .sum::<f64>();
-            data1i * data2i
-        })
-        .sum::<f64>()
-        / (data_length * data_length * data_length);
-
-    let mut mean_a = 0.0;
-    let mut mean_b = 0.0;
-    v1.iter().zip(v2.iter()).for_each(|(a1, b1)| {
-        mean_a += v1
-            .iter()
-            .map(|a2| {
-                izip!(a1, a2)
-                    .map(|(a1_i, a2_i)| (a1_i - a2_i).powi(2))
-                    .sum::<f64>()
-                    .sqrt()
+use crate::dist_corr::v_centered_inner_product;
+use crate::dist_corr_bias_corrected::{u_centered_inner_product, BiasCorrectedDistCorr};
+use crate::dist_corr_exponent::distance_power;
+use crate::error::DistCorrError;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Definition
+
+/// number of row blocks of the pairwise pass
+///
+/// Fixed (and not derived from the number of threads) such that the order of summation
+/// does not depend on the machine. Each block holds its own grand mean buffers.
+const NUM_BLOCKS: usize = 32;
+
+/// Sums over the euclidean distance matrices `a^k_ij = |x^k_i - x^k_j|^exponent` of N samples
+/// `x^1, ..., x^N` of row-major matrices.
+#[derive(Clone)]
+pub(crate) struct DistSums<const N: usize> {
+    /// sum_ij a^k_ij a^l_ij
+    pub frob: [[f64; N]; N],
+    /// sum_j a^k_ij / n
+    pub grand_means: [Vec<f64>; N],
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl<const N: usize> DistSums<N> {
+    fn zeros(len: usize) -> Self {
+        DistSums {
+            frob: [[0.0; N]; N],
+            grand_means: std::array::from_fn(|_| vec![0.0; len]),
+        }
+    }
+
+    /// computes the sums in a single pass over all pairs i < j
+    ///
+    /// Every distance is computed exactly once and no distance matrix is stored, i.e. the
+    /// algorithm has time complexity `O(n^2 (p_1 + ... + p_N))` and memory complexity `O(n)`.
+    /// The samples are row-major with dims columns respectively.
+    pub(crate) fn compute(samples: [&[f64]; N], dims: [usize; N], exponent: f64) -> Self {
+        let len = samples[0].len() / dims[0];
+        let rows: [Vec<&[f64]>; N] =
+            std::array::from_fn(|k| samples[k].chunks_exact(dims[k]).collect());
+
+        // rows are assigned to blocks in an interleaved way to balance the triangular workload
+        let num_blocks = NUM_BLOCKS.min(len);
+
+        let blocks: Vec<DistSums<N>> = (0..num_blocks)
+            .into_par_iter()
+            .map(|block| {
+                let mut sums = DistSums::zeros(len);
+
+                (block..len).step_by(num_blocks).for_each(|i| {
+                    let mut row_sums = [0.0; N];
+
+                    for j in (i + 1)..len {
+                        let a: [f64; N] = std::array::from_fn(|k| {
+                            distance_power(euclidean_distance(rows[k][i], rows[k][j]), exponent)
+                        });
+
+                        for k in 0..N {
+                            for l in k..N {
+                                sums.frob[k][l] += a[k] * a[l];
+                            }
+                            row_sums[k] += a[k];
+                            sums.grand_means[k][j] += a[k];
+                        }
+                    }
+
+                    izip!(&mut sums.grand_means, row_sums).for_each(|(g, r)| g[i] += r);
+                });
+
+                sums
             })
-            .sum::<f64>();
-        mean_b += v2
-            .iter()
-            .map(|b2| {
-                izip!(b1, b2)
-                    .map(|(b1_i, b2_i)| (b1_i - b2_i).powi(2))
-                    .sum::<f64>()
-                    .sqrt()
+            .collect();
+
+        // reduce the blocks in a fixed order
+        let mut sums = blocks
+            .into_iter()
+            .reduce(|mut acc, block| {
+                izip!(acc.frob.iter_mut().flatten(), block.frob.iter().flatten())
+                    .for_each(|(a, b)| *a += b);
+                izip!(&mut acc.grand_means, block.grand_means)
+                    .for_each(|(acc_k, block_k)| izip!(acc_k, block_k).for_each(|(a, b)| *a += b));
+                acc
             })
-            .sum::<f64>();
-    });
+            .unwrap_or_else(|| DistSums::zeros(len));
+
+        // only pairs i < j have been visited and only k <= l has been accumulated
+        for k in 0..N {
+            for l in k..N {
+                sums.frob[k][l] *= 2.0;
+                sums.frob[l][k] = sums.frob[k][l];
+            }
+        }
+        sums.grand_means
+            .iter_mut()
+            .flatten()
+            .for_each(|a| *a /= len as f64);
+
+        sums
+    }
+
+    /// dCov^2 of the samples k and l, i.e. dVar^2 of sample k if k == l
+    pub(crate) fn dist_cov_sq(&self, k: usize, l: usize) -> f64 {
+        let dist_cov_sq = v_centered_inner_product(
+            self.frob[k][l],
+            &self.grand_means[k],
+            &self.grand_means[l],
+            self.grand_means[k].len(),
+        );
+        non_negative(dist_cov_sq)
+    }
 
-    let means = mean_a * mean_b / (data_length * data_length * data_length * data_length);
+    /// bias-corrected distance correlation of the samples k and l based on U-centering, needs len > 3
+    pub(crate) fn bias_corrected(&self, k: usize, l: usize) -> BiasCorrectedDistCorr {
+        let len = self.grand_means[k].len();
 
-    dist_frob_norm - 2.0 * dist_scalar_avg + means
+        let u_centered = |k: usize, l: usize| {
+            u_centered_inner_product(
+                self.frob[k][l],
+                &self.grand_means[k],
+                &self.grand_means[l],
+                len,
+            )
+        };
+
+        BiasCorrectedDistCorr::new(u_centered(k, l), u_centered(k, k), u_centered(l, l), len)
+    }
 }
 
-// move
-pub fn _dist_cov_multi_exp(v1: &[Vec<f64>], v2: &[Vec<f64>]) -> f64 {
-    let data_length = v1.len() as f64;
-    let dist_frob_norm = (v1
-        .par_iter()
-        .zip(v2.par_iter())
-        .enumerate()
-        .map(|(i, (a1, b1))| {
-            v1[i..]
-                .iter()
-                .zip(v2[i..].iter())
-                .map(|(a2, b2)| {
-                    izip!(a1, a2)
-                        .map(|(a1_i, a2_i)| (-(a1_i - a2_i).abs()).exp())
-                        .product::<f64>()
-                        * izip!(b1, b2)
-                            .map(|(b1_i, b2_i)| (-(b1_i - b2_i).abs()).exp())
-                            .product::<f64>()
-                })
-                .sum::<f64>()
-        })
-        .sum::<f64>()
-        * 2.0
-        - data_length)
-        / (data_length * data_length);
-
-    let dist_scalar_avg = v1
-        .par_iter()
-        .zip(v2.par_iter())
-        .map(|(a1, b1)| {
-            let data1i = v1
-                .iter()
-                .map(|a2| {
-                    izip!(a1, a2)
-                        .map(|(a1_i, a2_i)| (-(a1_i - a2_i).abs()).exp())
-                        .product::<f64>()
-                })
-                .sum::<f64>();
-            let data2i = v2
-                .iter()
-                .map(|b2| {
-                    izip!(b1, b2)
-                        .map(|(b1_i, b2_i)| (-(b1_i - b2_i).abs()).exp())
-                        .product::<f64>()
-                })
-                .sum::<f64>();
-            data1i * data2i
-        })
+/// euclidean distance of two points
+#[inline]
+fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
+    izip!(a, b)
+        .map(|(a_i, b_i)| (a_i - b_i).powi(2))
         .sum::<f64>()
-        / (data_length * data_length * data_length);
-
-    let mut mean_a = 0.0;
-    let mut mean_b = 0.0;
-    v1.iter().zip(v2.iter()).for_each(|(a1, b1)| {
-        mean_a += v1
-            .iter()
-            .map(|a2| {
-                izip!(a1, a2)
-                    .map(|(a1_i, a2_i)| (-(a1_i - a2_i).abs()).exp())
-                    .product::<f64>()
-            })
-            .sum::<f64>();
-        mean_b += v2
-            .iter()
-            .map(|b2| {
-                izip!(b1, b2)
-                    .map(|(b1_i, b2_i)| (-(b1_i - b2_i).abs()).exp())
-                    .product::<f64>()
-            })
-            .sum::<f64>();
-    });
+        .sqrt()
+}
+
+/// squared distance covariances must be >= 0.0
+/// if not there must be a numerical error
+fn non_negative(dist_cov_sq: f64) -> f64 {
+    if dist_cov_sq < 0.0 {
+        debug!(
+            "dist_corr_multi method gives negative: {:?} - use 0.0",
+            dist_cov_sq
+        );
+        0.0
+    } else {
+        dist_cov_sq
+    }
+}
+
+/// computes distance correlation of the row-major samples x and y
+pub(crate) fn dist_corr_multi(
+    x: &[f64],
+    dim_x: usize,
+    y: &[f64],
+    dim_y: usize,
+    exponent: f64,
+) -> Result<f64, DistCorrError> {
+    let sums = DistSums::compute([x, y], [dim_x, dim_y], exponent);
 
-    let means = mean_a * mean_b / (data_length * data_length * data_length * data_length);
+    let dist_var_x = sums.dist_cov_sq(0, 0).sqrt();
+    let dist_var_y = sums.dist_cov_sq(1, 1).sqrt();
+
+    if dist_var_x > 0.0 && dist_var_y > 0.0 {
+        Ok(sums.dist_cov_sq(0, 1).sqrt() / (dist_var_x * dist_var_y).sqrt())
+    } else {
+        Ok(0.0)
+    }
+}
+
+/// computes distance covariance of the row-major samples x and y
+pub(crate) fn dist_cov_multi(
+    x: &[f64],
+    dim_x: usize,
+    y: &[f64],
+    dim_y: usize,
+    exponent: f64,
+) -> Result<f64, DistCorrError> {
+    Ok(DistSums::compute([x, y], [dim_x, dim_y], exponent)
+        .dist_cov_sq(0, 1)
+        .sqrt())
+}
 
-    dist_frob_norm - 2.0 * dist_scalar_avg + means
+/// computes bias-corrected distance correlation of the row-major samples x and y, needs more than
+/// 3 rows
+pub(crate) fn dist_corr_multi_bias_corrected(
+    x: &[f64],
+    dim_x: usize,
+    y: &[f64],
+    dim_y: usize,
+    exponent: f64,
+) -> BiasCorrectedDistCorr {
+    DistSums::compute([x, y], [dim_x, dim_y], exponent).bias_corrected(0, 1)
 }
@@ -1,6 +1,7 @@
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Using
 
+use itertools::izip;
 use rayon::prelude::*;
 
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
@@ -10,6 +11,14 @@ use rayon::prelude::*;
 ///
 /// used to test if faster algorithms give correct results
 pub fn _dist_cov_sq_naive(data_1: &[f64], data_2: &[f64]) -> f64 {
+    _dist_cov_sq_naive_exponent(data_1, data_2, 1.0)
+}
+
+/// naive implementation of the squared distance covariance with distances `|x - y|^exponent`
+/// and n^2 complexity
+///
+/// used to test if faster algorithms give correct results
+pub fn _dist_cov_sq_naive_exponent(data_1: &[f64], data_2: &[f64], exponent: f64) -> f64 {
     let data_length = data_1.len() as f64;
     let dist_hs_norm = data_1
         .par_iter()
@@ -19,7 +28,7 @@ pub fn _dist_cov_sq_naive(data_1: &[f64], data_2: &[f64]) -> f64 {
             data_1[i..]
                 .iter()
                 .zip(data_2[i..].iter())
-                .map(|(a2, b2)| (a1 - a2).abs() * (b1 - b2).abs())
+                .map(|(a2, b2)| (a1 - a2).abs().powf(exponent) * (b1 - b2).abs().powf(exponent))
                 .sum::<f64>()
         })
         .sum::<f64>()
@@ -30,8 +39,14 @@ pub fn _dist_cov_sq_naive(data_1: &[f64], data_2: &[f64]) -> f64 {
         .par_iter()
         .zip(data_2.par_iter())
         .map(|(a1, b1)| {
-            let data1i = data_1.iter().map(|a2| (a1 - a2).abs()).sum::<f64>();
-            let data2i = data_2.iter().map(|b2| (b1 - b2).abs()).sum::<f64>();
+            let data1i = data_1
+                .iter()
+                .map(|a2| (a1 - a2).abs().powf(exponent))
+                .sum::<f64>();
+            let data2i = data_2
+                .iter()
+                .map(|b2| (b1 - b2).abs().powf(exponent))
+                .sum::<f64>();
             data1i * data2i
         })
         .sum::<f64>()
@@ -40,8 +55,14 @@ pub fn _dist_cov_sq_naive(data_1: &[f64], data_2: &[f64]) -> f64 {
     let mut mean_a = 0.0;
     let mut mean_b = 0.0;
     data_1.iter().zip(data_2.iter()).for_each(|(a1, b1)| {
-        mean_a += data_1.iter().map(|a2| (a1 - a2).abs()).sum::<f64>();
-        mean_b += data_2.iter().map(|b2| (b1 - b2).abs()).sum::<f64>();
+        mean_a += data_1
+            .iter()
+            .map(|a2| (a1 - a2).abs().powf(exponent))
+            .sum::<f64>();
+        mean_b += data_2
+            .iter()
+            .map(|b2| (b1 - b2).abs().powf(exponent))
+            .sum::<f64>();
     });
 
     let means = mean_a * mean_b / (data_length * data_length * data_length * data_length);
@@ -89,3 +110,350 @@ pub fn _dist_cov_naive_exp(v1: &[f64], v2: &[f64]) -> f64 {
 
     dist_frob_norm - 2.0 * dist_scalar_avg + means
 }
+
+/// naive implementation of the unbiased estimator of dCov^2 with n^2 complexity
+/// based on U-centered distance matrices
+///
+/// used to test if faster algorithms give correct results
+pub fn _dist_cov_sq_u_naive(data_1: &[f64], data_2: &[f64]) -> f64 {
+    _dist_cov_sq_u_naive_exponent(data_1, data_2, 1.0)
+}
+
+/// naive implementation of the bias-corrected squared distance covariance with distances
+/// `|x - y|^exponent` based on U-centered distance matrices
+///
+/// used to test if faster algorithms give correct results
+pub fn _dist_cov_sq_u_naive_exponent(data_1: &[f64], data_2: &[f64], exponent: f64) -> f64 {
+    let u_centered = |data: &[f64]| -> Vec<Vec<f64>> {
+        let n = data.len() as f64;
+        let dist: Vec<Vec<f64>> = data
+            .iter()
+            .map(|a| data.iter().map(|b| (a - b).abs().powf(exponent)).collect())
+            .collect();
+        let row_sums: Vec<f64> = dist.iter().map(|row| row.iter().sum::<f64>()).collect();
+        let total = row_sums.iter().sum::<f64>();
+
+        dist.iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, d)| {
+                        if i == j {
+                            0.0
+                        } else {
+                            d - row_sums[i] / (n - 2.0) - row_sums[j] / (n - 2.0)
+                                + total / ((n - 1.0) * (n - 2.0))
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    };
+
+    let data_length = data_1.len() as f64;
+    let (a, b) = (u_centered(data_1), u_centered(data_2));
+
+    a.iter()
+        .zip(b.iter())
+        .map(|(a_i, b_i)| a_i.iter().zip(b_i).map(|(x, y)| x * y).sum::<f64>())
+        .sum::<f64>()
+        / (data_length * (data_length - 3.0))
+}
+
+/// exact sums over the distance matrices `a_ij = |k1_i - k1_j|` and `b_ij = |k2_i - k2_j|` of
+/// integer data, i.e. sum_ij a_ij b_ij, sum_i a_i b_i with row sums a_i, b_i and the totals a, b
+fn _exact_sums(k1: &[i64], k2: &[i64]) -> (i128, i128, i128, i128) {
+    let rows: Vec<(i128, i128, i128)> = k1
+        .iter()
+        .zip(k2)
+        .map(|(x1, y1)| {
+            k1.iter()
+                .zip(k2)
+                .fold((0, 0, 0), |(frob, a_i, b_i), (x2, y2)| {
+                    let (a, b) = ((x1 - x2).abs() as i128, (y1 - y2).abs() as i128);
+                    (frob + a * b, a_i + a, b_i + b)
+                })
+        })
+        .collect();
+
+    rows.iter()
+        .fold((0, 0, 0, 0), |(frob, rows_ab, a, b), &(f, a_i, b_i)| {
+            (frob + f, rows_ab + a_i * b_i, a + a_i, b + b_i)
+        })
+}
+
+/// exact squared distance covariance of integer data as numerator and denominator
+pub fn _dist_cov_sq_exact_ratio(k1: &[i64], k2: &[i64]) -> (i128, i128) {
+    let n = k1.len() as i128;
+    let (frob, rows_ab, a, b) = _exact_sums(k1, k2);
+
+    // n^4 dCov² = n^2 sum a_ij b_ij - 2 n sum a_i b_i + a b
+    (n * n * frob - 2 * n * rows_ab + a * b, n * n * n * n)
+}
+
+/// exact bias-corrected squared distance covariance of integer data as numerator and denominator
+pub fn _dist_cov_sq_u_exact_ratio(k1: &[i64], k2: &[i64]) -> (i128, i128) {
+    let n = k1.len() as i128;
+    let (frob, rows_ab, a, b) = _exact_sums(k1, k2);
+
+    // n(n-1)(n-2)(n-3) dCov²_U = (n-1)(n-2) sum a_ij b_ij - 2 (n-1) sum a_i b_i + a b
+    (
+        (n - 1) * (n - 2) * frob - 2 * (n - 1) * rows_ab + a * b,
+        n * (n - 1) * (n - 2) * (n - 3),
+    )
+}
+
+/// exact squared distance covariance of integer data, rounded to the closest f64 up to a
+/// relative error of a few ulps
+///
+/// used as reference for the accuracy of the floating-point algorithms
+pub fn _dist_cov_sq_exact(k1: &[i64], k2: &[i64]) -> f64 {
+    let (numerator, denominator) = _dist_cov_sq_exact_ratio(k1, k2);
+
+    numerator as f64 / denominator as f64
+}
+
+/// exact bias-corrected squared distance covariance of integer data, rounded to the closest f64
+/// up to a relative error of a few ulps
+///
+/// used as reference for the accuracy of the floating-point algorithms
+pub fn _dist_cov_sq_u_exact(k1: &[i64], k2: &[i64]) -> f64 {
+    let (numerator, denominator) = _dist_cov_sq_u_exact_ratio(k1, k2);
+
+    numerator as f64 / denominator as f64
+}
+
+/// naive implementation of the partial distance correlation and covariance of
+/// multivariate samples with n^2 complexity
+///
+/// Projects the U-centered distance matrices of x and y onto the orthogonal complement of
+/// the one of z explicitly. Used to test if faster algorithms give correct results.
+pub fn _partial_dist_corr_naive(x: &[Vec<f64>], y: &[Vec<f64>], z: &[Vec<f64>]) -> (f64, f64) {
+    let u_centered = |data: &[Vec<f64>]| -> Vec<f64> {
+        let n = data.len() as f64;
+        let dist: Vec<Vec<f64>> = data
+            .iter()
+            .map(|a| {
+                data.iter()
+                    .map(|b| {
+                        izip!(a, b)
+                            .map(|(a_i, b_i)| (a_i - b_i).powi(2))
+                            .sum::<f64>()
+                            .sqrt()
+                    })
+                    .collect()
+            })
+            .collect();
+        let row_sums: Vec<f64> = dist.iter().map(|row| row.iter().sum::<f64>()).collect();
+        let total = row_sums.iter().sum::<f64>();
+
+        dist.iter()
+            .enumerate()
+            .flat_map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, d)| {
+                        if i == j {
+                            0.0
+                        } else {
+                            d - row_sums[i] / (n - 2.0) - row_sums[j] / (n - 2.0)
+                                + total / ((n - 1.0) * (n - 2.0))
+                        }
+                    })
+                    .collect::<Vec<f64>>()
+            })
+            .collect()
+    };
+
+    let data_length = x.len() as f64;
+    let inner_product = |a: &[f64], b: &[f64]| {
+        izip!(a, b).map(|(a_ij, b_ij)| a_ij * b_ij).sum::<f64>()
+            / (data_length * (data_length - 3.0))
+    };
+    let (a, b, c) = (u_centered(x), u_centered(y), u_centered(z));
+
+    let projection = |a: &[f64]| -> Vec<f64> {
+        let c_c = inner_product(&c, &c);
+        let scale = if c_c > 0.0 {
+            inner_product(a, &c) / c_c
+        } else {
+            0.0
+        };
+        izip!(a, &c)
+            .map(|(a_ij, c_ij)| a_ij - scale * c_ij)
+            .collect()
+    };
+    let (p_x, p_y) = (projection(&a), projection(&b));
+
+    let partial_dist_cov = inner_product(&p_x, &p_y);
+    let denominator = (inner_product(&p_x, &p_x) * inner_product(&p_y, &p_y)).sqrt();
+
+    let partial_dist_corr = if denominator > 0.0 {
+        partial_dist_cov / denominator
+    } else {
+        0.0
+    };
+
+    (partial_dist_corr, partial_dist_cov)
+}
+
+/// naive implementation of the multivariate distance covariance with n^2 complexity
+///
+/// used to test if faster algorithms give correct results
+pub fn _dist_cov_sq_multi_naive(v1: &[Vec<f64>], v2: &[Vec<f64>]) -> f64 {
+    let data_length = v1.len() as f64;
+    let dist_frob_norm = v1
+        .par_iter()
+        .zip(v2.par_iter())
+        .enumerate()
+        .map(|(i, (a1, b1))| {
+            v1[i..]
+                .iter()
+                .zip(v2[i..].iter())
+                .map(|(a2, b2)| {
+                    izip!(a1, a2)
+                        .map(|(a1_i, a2_i)| (a1_i - a2_i).powi(2))
+                        .sum::<f64>()
+                        .sqrt()
+                        * izip!(b1, b2)
+                            .map(|(b1_i, b2_i)| (b1_i - b2_i).powi(2))
+                            .sum::<f64>()
+                            .sqrt()
+                })
+                .sum::<f64>()
+        })
+        .sum::<f64>()
+        * 2.0
+        / (data_length * data_length);
+
+    let dist_scalar_avg = v1
+        .par_iter()
+        .zip(v2.par_iter())
+        .map(|(a1, b1)| {
+            let data1i = v1
+                .iter()
+                .map(|a2| {
+                    izip!(a1, a2)
+                        .map(|(a1_i, a2_i)| (a1_i - a2_i).powi(2))
+                        .sum::<f64>()
+                        .sqrt()
+                })
+                .sum::<f64>();
+            let data2i = v2
+                .iter()
+                .map(|b2| {
+                    izip!(b1, b2)
+                        .map(|(b1_i, b2_i)| (b1_i - b2_i).powi(2))
+                        .sum::<f64>()
+                        .sqrt()
+                })
+                .sum::<f64>();
+            data1i * data2i
+        })
+        .sum::<f64>()
+        / (data_length * data_length * data_length);
+
+    let mut mean_a = 0.0;
+    let mut mean_b = 0.0;
+    v1.iter().zip(v2.iter()).for_each(|(a1, b1)| {
+        mean_a += v1
+            .iter()
+            .map(|a2| {
+                izip!(a1, a2)
+                    .map(|(a1_i, a2_i)| (a1_i - a2_i).powi(2))
+                    .sum::<f64>()
+                    .sqrt()
+            })
+            .sum::<f64>();
+        mean_b += v2
+            .iter()
+            .map(|b2| {
+                izip!(b1, b2)
+                    .map(|(b1_i, b2_i)| (b1_i - b2_i).powi(2))
+                    .sum::<f64>()
+                    .sqrt()
+            })
+            .sum::<f64>();
+    });
+
+    let means = mean_a * mean_b / (data_length * data_length * data_length * data_length);
+
+    dist_frob_norm - 2.0 * dist_scalar_avg + means
+}
+
+pub fn _dist_cov_multi_exp(v1: &[Vec<f64>], v2: &[Vec<f64>]) -> f64 {
+    let data_length = v1.len() as f64;
+    let dist_frob_norm = (v1
+        .par_iter()
+        .zip(v2.par_iter())
+        .enumerate()
+        .map(|(i, (a1, b1))| {
+            v1[i..]
+                .iter()
+                .zip(v2[i..].iter())
+                .map(|(a2, b2)| {
+                    izip!(a1, a2)
+                        .map(|(a1_i, a2_i)| (-(a1_i - a2_i).abs()).exp())
+                        .product::<f64>()
+                        * izip!(b1, b2)
+                            .map(|(b1_i, b2_i)| (-(b1_i - b2_i).abs()).exp())
+                            .product::<f64>()
+                })
+                .sum::<f64>()
+        })
+        .sum::<f64>()
+        * 2.0
+        - data_length)
+        / (data_length * data_length);
+
+    let dist_scalar_avg = v1
+        .par_iter()
+        .zip(v2.par_iter())
+        .map(|(a1, b1)| {
+            let data1i = v1
+                .iter()
+                .map(|a2| {
+                    izip!(a1, a2)
+                        .map(|(a1_i, a2_i)| (-(a1_i - a2_i).abs()).exp())
+                        .product::<f64>()
+                })
+                .sum::<f64>();
+            let data2i = v2
+                .iter()
+                .map(|b2| {
+                    izip!(b1, b2)
+                        .map(|(b1_i, b2_i)| (-(b1_i - b2_i).abs()).exp())
+                        .product::<f64>()
+                })
+                .sum::<f64>();
+            data1i * data2i
+        })
+        .sum::<f64>()
+        / (data_length * data_length * data_length);
+
+    let mut mean_a = 0.0;
+    let mut mean_b = 0.0;
+    v1.iter().zip(v2.iter()).for_each(|(a1, b1)| {
+        mean_a += v1
+            .iter()
+            .map(|a2| {
+                izip!(a1, a2)
+                    .map(|(a1_i, a2_i)| (-(a1_i - a2_i).abs()).exp())
+                    .product::<f64>()
+            })
+            .sum::<f64>();
+        mean_b += v2
+            .iter()
+            .map(|b2| {
+                izip!(b1, b2)
+                    .map(|(b1_i, b2_i)| (-(b1_i - b2_i).abs()).exp())
+                    .product::<f64>()
+            })
+            .sum::<f64>();
+    });
+
+    let means = mean_a * mean_b / (data_length * data_length * data_length * data_length);
+
+    dist_frob_norm - 2.0 * dist_scalar_avg + means
+}
@@ -0,0 +1,301 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rayon::prelude::*;
+
+use crate::config::Estimator;
+use crate::error::DistCorrError;
+use crate::frob_inner_product::frobenius_inner_product;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Squared distance covariance and variances of integer data, computed with exact arithmetic.
+///
+/// Every value is the exact rational statistic correctly rounded to the nearest `f64`, such
+/// that the results are bit-identical across platforms, thread counts and input orders.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExactDistCov {
+    /// Squared distance covariance dCov²(v1, v2) of the configured estimator.
+    pub dist_cov_sq: f64,
+    /// Squared distance variance dVar²(v1) of the configured estimator.
+    pub dist_var_sq_v1: f64,
+    /// Squared distance variance dVar²(v2) of the configured estimator.
+    pub dist_var_sq_v2: f64,
+    /// Common length of `v1` and `v2`.
+    pub len: usize,
+}
+
+/// unsigned 256-bit integer with big-endian limbs, such that the derived ordering is numeric
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct U256([u64; 4]);
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+/// computes the exact squared distance covariance and variances of the integer vectors v1 and v2
+///
+/// v1 and v2 must be non-empty and of the same length, and of length at least 4 for the
+/// bias-corrected estimator. The values are shifted by their minimum, such that all distances,
+/// the Frobenius inner products and the row sums of the distance matrices are accumulated in
+/// `i128` without overflow if `n * range <= 2^61`. The final combinations of the inner products
+/// are formed in 256-bit integers and divided with correct rounding.
+pub(crate) fn dist_cov_exact(
+    v1: &[i128],
+    v2: &[i128],
+    estimator: Estimator,
+) -> Result<ExactDistCov, DistCorrError> {
+    let len = v1.len();
+    let (v1, range_1) = shifted(v1)?;
+    let (v2, range_2) = shifted(v2)?;
+    check_range(len, range_1.max(range_2))?;
+
+    // sort v1,v2 with respect to ordering of v2
+    let mut order: Vec<usize> = (0..len).collect();
+    order.par_sort_unstable_by_key(|&i| v2[i]);
+    let v1_per: Vec<i128> = order.iter().map(|&i| v1[i]).collect();
+    let v2_ord: Vec<i128> = order.iter().map(|&i| v2[i]).collect();
+
+    let frob_ab = frobenius_inner_product::<i128>(&v1_per, &v2_ord, len);
+    let (row_sums_1, row_sums_2) = (row_sums(&v1), row_sums(&v2));
+
+    let centered = |frob: i128, row_sums_a: &[i128], row_sums_b: &[i128]| match estimator {
+        Estimator::Standard => v_centered(frob, row_sums_a, row_sums_b),
+        Estimator::BiasCorrected => u_centered(frob, row_sums_a, row_sums_b),
+    };
+
+    Ok(ExactDistCov {
+        dist_cov_sq: centered(frob_ab, &row_sums_1, &row_sums_2),
+        dist_var_sq_v1: centered(squared_frobenius_norm(&v1), &row_sums_1, &row_sums_1),
+        dist_var_sq_v2: centered(squared_frobenius_norm(&v2), &row_sums_2, &row_sums_2),
+        len,
+    })
+}
+
+/// subtracts the minimum from every element of v and returns the range of v
+fn shifted(v: &[i128]) -> Result<(Vec<i128>, u128), DistCorrError> {
+    let min = v.iter().copied().min().unwrap_or_default();
+    let max = v.iter().copied().max().unwrap_or_default();
+    let range = max
+        .checked_sub(min)
+        .ok_or(DistCorrError::ExactRangeExceeded)?;
+
+    Ok((v.iter().map(|x| x - min).collect(), range as u128))
+}
+
+/// checks that (n * range)^2 <= 2^122, which bounds all sums of the Frobenius inner products
+fn check_range(len: usize, range: u128) -> Result<(), DistCorrError> {
+    (len as u128)
+        .checked_mul(range)
+        .and_then(|x| x.checked_mul(x))
+        .filter(|&x| x <= 1 << 122)
+        .map(|_| ())
+        .ok_or(DistCorrError::ExactRangeExceeded)
+}
+
+/// the row sums a_i = sum_j |v_i - v_j| of the distance matrix of v
+///
+/// For the k-th smallest value s_k with the sum p_k of all smaller values and the total t
+///
+/// ```text
+/// a_(k) = (2 k - n) s_k - 2 p_k + t
+/// ```
+fn row_sums(v: &[i128]) -> Vec<i128> {
+    let len = v.len() as i128;
+    let total: i128 = v.iter().sum();
+
+    let mut order: Vec<usize> = (0..v.len()).collect();
+    order.par_sort_unstable_by_key(|&i| v[i]);
+
+    let mut row_sums = vec![0; v.len()];
+    let mut prefix_sum = 0;
+    for (k, &i) in order.iter().enumerate() {
+        row_sums[i] = (2 * k as i128 - len) * v[i] - 2 * prefix_sum + total;
+        prefix_sum += v[i];
+    }
+
+    row_sums
+}
+
+/// the squared frobenius norm of the distance matrix of v
+///
+/// ```text
+/// sum_ij (v_i - v_j)^2 = 2 n sum_i v_i^2 - 2 (sum_i v_i)^2
+/// ```
+fn squared_frobenius_norm(v: &[i128]) -> i128 {
+    let sum: i128 = v.iter().sum();
+    let sum_sq: i128 = v.iter().map(|x| x * x).sum();
+
+    2 * v.len() as i128 * sum_sq - 2 * sum * sum
+}
+
+/// the V-statistic from the inner product and the row sums a_i, b_i of the distance matrices
+///
+/// ```text
+/// n^4 dCov² = n^2 sum a_ij b_ij - 2 n sum_i a_i b_i + sum_i a_i sum_i b_i
+/// ```
+fn v_centered(frob: i128, row_sums_a: &[i128], row_sums_b: &[i128]) -> f64 {
+    let len = U256::from(row_sums_a.len() as u128);
+    let len_sq = len.mul(len);
+
+    combine(
+        frob,
+        row_sums_a,
+        row_sums_b,
+        len_sq,
+        len,
+        len_sq.mul(len_sq),
+    )
+}
+
+/// the U-statistic from the inner product and the row sums a_i, b_i of the distance matrices
+///
+/// ```text
+/// n(n-1)(n-2)(n-3) dCov²_U = (n-1)(n-2) sum a_ij b_ij - 2 (n-1) sum_i a_i b_i
+///                            + sum_i a_i sum_i b_i
+/// ```
+fn u_centered(frob: i128, row_sums_a: &[i128], row_sums_b: &[i128]) -> f64 {
+    let len = row_sums_a.len() as u128;
+    let [n, n_1, n_2, n_3] = [len, len - 1, len - 2, len - 3].map(U256::from);
+
+    combine(
+        frob,
+        row_sums_a,
+        row_sums_b,
+        n_1.mul(n_2),
+        n_1,
+        n.mul(n_1).mul(n_2).mul(n_3),
+    )
+}
+
+/// correctly rounds (c_frob frob - 2 c_dot sum_i a_i b_i + sum_i a_i sum_i b_i) / denominator
+fn combine(
+    frob: i128,
+    row_sums_a: &[i128],
+    row_sums_b: &[i128],
+    c_frob: U256,
+    c_dot: U256,
+    denominator: U256,
+) -> f64 {
+    // all sums are non-negative, and each product a_i b_i is bounded by (n * range)^2
+    let dot = row_sums_a
+        .iter()
+        .zip(row_sums_b)
+        .fold(U256::default(), |sum, (&a, &b)| {
+            sum.add(U256::from((a * b) as u128))
+        });
+    let sum_a = U256::from(row_sums_a.iter().sum::<i128>() as u128);
+    let sum_b = U256::from(row_sums_b.iter().sum::<i128>() as u128);
+
+    let positive = c_frob.mul(U256::from(frob as u128)).add(sum_a.mul(sum_b));
+    let negative = c_dot.add(c_dot).mul(dot);
+
+    if positive >= negative {
+        positive.sub(negative).div_to_f64(denominator)
+    } else {
+        -negative.sub(positive).div_to_f64(denominator)
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(x: u128) -> Self {
+        U256([0, 0, (x >> 64) as u64, x as u64])
+    }
+}
+
+impl U256 {
+    /// returns self + other, which must not overflow
+    fn add(self, other: Self) -> Self {
+        let mut limbs = [0; 4];
+        let mut carry = false;
+        for i in (0..4).rev() {
+            let (sum, carry_1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, carry_2) = sum.overflowing_add(carry as u64);
+            limbs[i] = sum;
+            carry = carry_1 || carry_2;
+        }
+        debug_assert!(!carry);
+
+        U256(limbs)
+    }
+
+    /// returns self - other, where other must not exceed self
+    fn sub(self, other: Self) -> Self {
+        let mut limbs = [0; 4];
+        let mut borrow = false;
+        for i in (0..4).rev() {
+            let (diff, borrow_1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, borrow_2) = diff.overflowing_sub(borrow as u64);
+            limbs[i] = diff;
+            borrow = borrow_1 || borrow_2;
+        }
+        debug_assert!(!borrow);
+
+        U256(limbs)
+    }
+
+    /// returns self * other, which must not overflow
+    fn mul(self, other: Self) -> Self {
+        // little-endian schoolbook multiplication, dropping the limbs beyond 256 bits
+        let mut limbs = [0u64; 4];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 - i {
+                let product = self.0[3 - i] as u128 * other.0[3 - j] as u128
+                    + limbs[3 - i - j] as u128
+                    + carry;
+                limbs[3 - i - j] = product as u64;
+                carry = product >> 64;
+            }
+        }
+
+        U256(limbs)
+    }
+
+    /// the bit of weight 2^position
+    fn bit(self, position: usize) -> bool {
+        (self.0[3 - position / 64] >> (position % 64)) & 1 == 1
+    }
+
+    /// returns self / denominator rounded to the nearest f64, ties to even
+    ///
+    /// The binary long division stops after the leading 54 bits of the quotient, the last of which
+    /// is the rounding bit, and the remaining numerator bits and remainder form the sticky bit.
+    fn div_to_f64(self, denominator: Self) -> f64 {
+        if self == U256::default() {
+            return 0.0;
+        }
+
+        let mut remainder = U256::default();
+        let mut mantissa = 0u64;
+        let mut position = 255i32;
+        loop {
+            remainder = remainder.add(remainder);
+            if position >= 0 && self.bit(position as usize) {
+                remainder = remainder.add(U256::from(1));
+            }
+
+            let bit = remainder >= denominator;
+            if bit {
+                remainder = remainder.sub(denominator);
+            }
+            mantissa = (mantissa << 1) | bit as u64;
+
+            if mantissa >= 1 << 53 {
+                break;
+            }
+            position -= 1;
+        }
+
+        let sticky =
+            remainder != U256::default() || (0..position.max(0)).any(|p| self.bit(p as usize));
+        let round = mantissa & 1 == 1;
+        let mut mantissa = mantissa >> 1;
+        if round && (sticky || mantissa & 1 == 1) {
+            mantissa += 1;
+        }
+
+        // the mantissa has at most 54 bits and the power of two is a normal number
+        mantissa as f64 * 2f64.powi(position + 1)
+    }
+}
@@ -0,0 +1,142 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use std::fmt;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Error returned by the distance correlation and distance covariance computations.
+///
+/// The error only holds plain data and is therefore `Send + Sync`, such that it can be
+/// propagated across threads and async tasks.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum DistCorrError {
+    /// Inputs that must have the same number of observations differ in length.
+    LengthMismatch {
+        /// Number of observations of the first input.
+        expected: usize,
+        /// Number of observations of the mismatching input.
+        found: usize,
+    },
+    /// An input is empty.
+    EmptyInput,
+    /// An input has fewer observations than the computation requires.
+    TooFewObservations {
+        /// Minimal number of observations.
+        required: usize,
+        /// Number of observations of the input.
+        found: usize,
+    },
+    /// A vector declared binary contains a value outside of its two levels.
+    NonBinary {
+        /// Index of the first offending value.
+        index: usize,
+        /// The offending value.
+        value: f64,
+    },
+    /// An input contains `NaN` or an infinite value.
+    NonFinite {
+        /// Index of the first non-finite value.
+        index: usize,
+        /// The non-finite value.
+        value: f64,
+    },
+    /// The statistic is undefined since an input has zero distance variance.
+    DegenerateVariance,
+    /// The length of a row-major sample is not a multiple of its dimension.
+    InvalidDimension {
+        /// Length of the row-major sample.
+        len: usize,
+        /// Dimension of the rows.
+        dim: usize,
+    },
+    /// A parameter that must be positive is zero.
+    NonPositiveParameter {
+        /// Name of the parameter.
+        name: &'static str,
+    },
+    /// The exponent of the distances does not lie in `(0, 2)`.
+    InvalidExponent {
+        /// The configured exponent.
+        exponent: f64,
+    },
+    /// The range of integer inputs is too large for the exact computation with 128-bit integers.
+    ExactRangeExceeded,
+    /// An integer input exceeds `2^53` in magnitude and cannot be converted to `f64` without rounding.
+    InexactConversion {
+        /// Index of the first offending value.
+        index: usize,
+    },
+    /// The computation does not support a configured setting.
+    UnsupportedSetting {
+        /// Name of the setting of the builder.
+        setting: &'static str,
+    },
+    /// The thread pool of the configured sequential or deterministic computation cannot be built.
+    ThreadPoolUnavailable,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl fmt::Display for DistCorrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DistCorrError::LengthMismatch { expected, found } => write!(
+                f,
+                "Length of inputs must be identical, expected {} but found {}",
+                expected, found
+            ),
+            DistCorrError::EmptyInput => write!(f, "Inputs must not be empty"),
+            DistCorrError::TooFewObservations { required, found } => write!(
+                f,
+                "Inputs must contain at least {} observations, found {}",
+                required, found
+            ),
+            DistCorrError::NonBinary { index, value } => write!(
+                f,
+                "Binary input has a third distinct value {} at index {}",
+                value, index
+            ),
+            DistCorrError::NonFinite { index, value } => {
+                write!(f, "Input has non-finite value {} at index {}", value, index)
+            }
+            DistCorrError::DegenerateVariance => {
+                write!(f, "Input has zero distance variance")
+            }
+            DistCorrError::InvalidDimension { len, dim } => write!(
+                f,
+                "Length {} must be a multiple of the positive dimension {}",
+                len, dim
+            ),
+            DistCorrError::NonPositiveParameter { name } => write!(f, "{} must be positive", name),
+            DistCorrError::InvalidExponent { exponent } => {
+                write!(f, "Exponent must lie in (0, 2), found {}", exponent)
+            }
+            DistCorrError::ExactRangeExceeded => {
+                write!(
+                    f,
+                    "Range of integer inputs is too large for the exact computation"
+                )
+            }
+            DistCorrError::InexactConversion { index } => write!(
+                f,
+                "Input has an integer beyond 2^53 at index {}, which is not exact in f64",
+                index
+            ),
+            DistCorrError::UnsupportedSetting { setting } => {
+                write!(f, "Setting {} is not supported by the computation", setting)
+            }
+            DistCorrError::ThreadPoolUnavailable => {
+                write!(
+                    f,
+                    "Thread pool of the configured computation cannot be built"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DistCorrError {}
@@ -0,0 +1,114 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use crate::dist_corr_binary::ContingencyTable;
+use crate::special_functions::{chi_square_sf, ln_choose};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Result of a test of independence of two binary vectors based on their 2x2 contingency table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinaryIndependenceTest {
+    /// The 2x2 contingency table of the two vectors.
+    pub table: ContingencyTable,
+    /// Distance correlation of the two vectors, i.e. the absolute value of the phi coefficient.
+    pub statistic: f64,
+    /// Chi-square statistic `n * dCor^2` of the table.
+    pub chi_square: f64,
+    /// Exact p-value under the hypergeometric distribution of the table given its margins.
+    pub p_value_exact: f64,
+    /// Asymptotic p-value from the chi-square distribution with one degree of freedom.
+    pub p_value_chi_square: f64,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl BinaryIndependenceTest {
+    /// test propagated as `NaN`, whose table is empty
+    pub(crate) fn propagated() -> Self {
+        BinaryIndependenceTest {
+            table: ContingencyTable {
+                n00: 0,
+                n01: 0,
+                n10: 0,
+                n11: 0,
+            },
+            statistic: f64::NAN,
+            chi_square: f64::NAN,
+            p_value_exact: f64::NAN,
+            p_value_chi_square: f64::NAN,
+        }
+    }
+
+    /// tests independence from the contingency table
+    pub(crate) fn from_table(table: ContingencyTable) -> Self {
+        let statistic = table.dist_corr();
+        let chi_square = table.total() as f64 * statistic * statistic;
+
+        BinaryIndependenceTest {
+            table,
+            statistic,
+            chi_square,
+            p_value_exact: hypergeometric_p_value(&table),
+            p_value_chi_square: chi_square_sf(chi_square, 1.0),
+        }
+    }
+}
+
+/// computes the exact p-value P(dCor_H >= dCor) where dCor_H is the distance correlation
+/// of a table drawn from the hypergeometric distribution with the margins of `table`
+///
+/// Given the margins, dCor is increasing in |n * n11 - r1 * c1|, hence the p-value is the
+/// mass of the two tails of the hypergeometric distribution of n11. This coincides with the
+/// two-sided Fisher exact test whenever the hypergeometric distribution is symmetric.
+fn hypergeometric_p_value(table: &ContingencyTable) -> f64 {
+    let n = table.total();
+    let r1 = table.n10 + table.n11;
+    let c1 = table.n01 + table.n11;
+
+    let (n_i, mean_n) = (n as i128, r1 as i128 * c1 as i128);
+    let deviation = (n_i * table.n11 as i128 - mean_n).abs();
+
+    if deviation == 0 {
+        return 1.0;
+    }
+
+    let lo = (r1 + c1).saturating_sub(n);
+    let hi = r1.min(c1);
+
+    let ln_denominator = ln_choose(n, c1);
+    let pmf = |k: usize| (ln_choose(r1, k) + ln_choose(n - r1, c1 - k) - ln_denominator).exp();
+
+    // tails are summed from the mode outwards until the terms become negligible
+    let tail_sum = |ks: &mut dyn Iterator<Item = usize>| {
+        let mut sum = 0.0;
+        for k in ks {
+            let p = pmf(k);
+            sum += p;
+            if p <= sum * f64::EPSILON {
+                break;
+            }
+        }
+        sum
+    };
+
+    // lower tail: n * k <= r1 * c1 - deviation
+    let lower = if mean_n - deviation >= 0 {
+        let k_max = (((mean_n - deviation) / n_i) as usize).min(hi);
+        tail_sum(&mut (lo..=k_max).rev())
+    } else {
+        0.0
+    };
+
+    // upper tail: n * k >= r1 * c1 + deviation
+    let k_min = ((mean_n + deviation + n_i - 1) / n_i) as usize;
+    let upper = if k_min <= hi {
+        tail_sum(&mut (k_min.max(lo)..=hi))
+    } else {
+        0.0
+    };
+
+    (lower + upper).clamp(0.0, 1.0)
+}
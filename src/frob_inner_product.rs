@@ -6,22 +6,29 @@ use rayon::iter::IndexedParallelIterator;
 use rayon::iter::ParallelIterator;
 use rayon::prelude::ParallelSliceMut;
 
+use crate::config::is_deterministic;
+use crate::summation::Summation;
+
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Definition
 
+/// maximal number of chunks sorted in parallel on deterministic workers, where the chunking
+/// must only depend on the length
+const DETERMINISTIC_CHUNKS: usize = 32;
+
 #[derive(Clone, Default)]
-struct Iv {
+struct Iv<S> {
     num: usize,
-    x: f64,
-    y: f64,
-    xy: f64,
+    x: S,
+    y: S,
+    xy: S,
 }
 
 #[derive(Clone, Default)]
-struct Csum {
-    x: f64,
-    y: f64,
-    xy: f64,
+struct Csum<S> {
+    x: S,
+    y: S,
+    xy: S,
 }
 
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
@@ -37,18 +44,31 @@ struct Csum {
 ///
 /// Important: v2 needs to be ordered increasingly
 pub fn compute_frobenius_inner_product(v1: &[f64], v2: &[f64], len: usize) -> f64 {
+    frobenius_inner_product::<f64>(v1, v2, len)
+}
+
+/// computes the frobenius inner product of the distance matrices as in
+/// `compute_frobenius_inner_product`, accumulating the sums with the summation strategy `S`
+pub(crate) fn frobenius_inner_product<S: Summation>(
+    v1: &[S::Value],
+    v2: &[S::Value],
+    len: usize,
+) -> S {
     // initialize indices
     let mut idxs_before: Vec<usize> = (0..len).collect();
     let mut idxs_after: Vec<usize> = vec![0; len];
 
-    let num_threads = rayon::current_num_threads();
-    //dbg!(("Number of threads:", num_threads));
+    let num_chunks = if is_deterministic() {
+        DETERMINISTIC_CHUNKS
+    } else {
+        rayon::current_num_threads()
+    };
 
     let max_chunks = (len as f64 / 2000.0).ceil() as usize;
-    let chunk_size = (len as f64 / (num_threads.min(max_chunks)) as f64).ceil() as usize;
+    let chunk_size = (len as f64 / (num_chunks.min(max_chunks)) as f64).ceil() as usize;
 
-    let mut ivs = vec![Iv::default(); len];
-    let mut csums = vec![Csum::default(); len + num_threads];
+    let mut ivs = vec![Iv::<S>::default(); len];
+    let mut csums = vec![Csum::<S>::default(); len + num_chunks];
 
     idxs_before
         .par_chunks_mut(chunk_size)
@@ -84,35 +104,44 @@ pub fn compute_frobenius_inner_product(v1: &[f64], v2: &[f64], len: usize) -> f6
         &mut ivs,
     );
 
-    let cov_term = len as f64 * csums[len].xy - csums[len].x * csums[len].y;
+    let cov_term = S::default()
+        .add_scaled(csums[len].xy, S::from_count(len))
+        .add_scaled(csums[len].x, -csums[len].y.value());
 
     let sum = izip!(ivs, v1, v2)
-        .map(|(iv, s0, s1)| 4.0 * (iv.num as f64 * s0 * s1 + iv.xy - iv.x * s0 - iv.y * s1))
-        .sum::<f64>();
-
-    sum - 2.0 * cov_term
+        .map(|(iv, &s0, &s1)| {
+            iv.xy
+                .add_product(S::from_count(iv.num) * s0, s1)
+                .add_scaled(iv.x, -s0)
+                .add_scaled(iv.y, -s1)
+        })
+        .fold(S::default(), S::add_sum);
+
+    // the scaling by the power of two is exact, such that 4 sum - 2 cov_term is rounded once
+    sum.scale(S::from_count(4))
+        .add_scaled(cov_term, -S::from_count(2))
 }
 
 #[allow(clippy::too_many_arguments)]
-fn perform_loop(
-    samples0: &[f64],
-    samples1: &[f64],
+fn perform_loop<S: Summation>(
+    samples0: &[S::Value],
+    samples1: &[S::Value],
     idxs_before: &mut [usize],
     idxs_after: &mut [usize],
     len: usize,
     idx_start: &mut usize,
     first_index: usize,
-    csums: &mut [Csum],
-    ivs: &mut [Iv],
+    csums: &mut [Csum<S>],
+    ivs: &mut [Iv<S>],
 ) {
     while *idx_start < len {
         // update cum sums
         (0..len).for_each(|ind| {
             let (x, y) = (samples1[idxs_before[ind]], samples0[idxs_before[ind]]);
 
-            csums[ind + 1].x = x + csums[ind].x;
-            csums[ind + 1].y = y + csums[ind].y;
-            csums[ind + 1].xy = x * y + csums[ind].xy;
+            csums[ind + 1].x = csums[ind].x.add(x);
+            csums[ind + 1].y = csums[ind].y.add(y);
+            csums[ind + 1].xy = csums[ind].xy.add_product(x, y);
         });
 
         izip!(
@@ -142,10 +171,12 @@ fn perform_loop(
 
                     let idx2_eff = idx2 - first_index;
 
-                    ivs[idx2_eff].num += e1_rel - st1;
-                    ivs[idx2_eff].x += csums[e1_abs].x - csums[j + st1].x;
-                    ivs[idx2_eff].y += csums[e1_abs].y - csums[j + st1].y;
-                    ivs[idx2_eff].xy += csums[e1_abs].xy - csums[j + st1].xy;
+                    let iv = &mut ivs[idx2_eff];
+
+                    iv.num += e1_rel - st1;
+                    iv.x = iv.x.add_difference(csums[e1_abs].x, csums[j + st1].x);
+                    iv.y = iv.y.add_difference(csums[e1_abs].y, csums[j + st1].y);
+                    iv.xy = iv.xy.add_difference(csums[e1_abs].xy, csums[j + st1].xy);
                 }
 
                 k += 1;
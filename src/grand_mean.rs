@@ -3,6 +3,8 @@
 
 use itertools::izip;
 
+use crate::summation::Summation;
+
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Struct
 
@@ -42,23 +44,33 @@ impl<'a> GrandMeans<'a> {
     /// The resulting grand means are returned as a newly allocated vector.
     /// The algorithm has complexity `O(v.len())`.
     pub fn compute_ordered(self) -> Vec<f64> {
-        let mut current_sum_ascending = 0.0;
-        let mut current_sum_descending = 0.0;
-        let mut out = vec![0.0; self.len()];
+        self.compute_ordered_with::<f64>()
+    }
+
+    /// Computes the grand means as in `compute_ordered`, accumulating the sums with the
+    /// summation strategy `S`.
+    pub(crate) fn compute_ordered_with<S: Summation<Value = f64>>(self) -> Vec<f64> {
+        let mut current_sum_ascending = S::default();
+        let mut current_sum_descending = S::default();
+        let mut out = vec![S::default(); self.len()];
 
         izip!(self.v.iter(), self.v.iter().rev())
             .enumerate()
-            .for_each(|(i, (v_i, rev_v_i))| {
-                out[i] +=
-                    (2 * (i as i64) - self.len() as i64 + 1) as f64 * v_i - current_sum_ascending;
-                out[self.len() - i - 1] += current_sum_descending;
-                current_sum_descending += rev_v_i;
-                current_sum_ascending += v_i;
+            .for_each(|(i, (&v_i, &rev_v_i))| {
+                let factor = (2 * (i as i64) - self.len() as i64 + 1) as f64;
+                out[i] = out[i].add_sum(
+                    S::default()
+                        .add_product(factor, v_i)
+                        .add_scaled(current_sum_ascending, -1.0),
+                );
+                out[self.len() - i - 1] = out[self.len() - i - 1].add_sum(current_sum_descending);
+                current_sum_descending = current_sum_descending.add(rev_v_i);
+                current_sum_ascending = current_sum_ascending.add(v_i);
             });
 
-        out.iter_mut().for_each(|x| *x /= self.len() as f64);
-
-        out
+        out.into_iter()
+            .map(|x| x.value() / self.len() as f64)
+            .collect()
     }
 
     /// Computes the grand means of the matrix
@@ -78,24 +90,37 @@ impl<'a> GrandMeans<'a> {
     /// The resulting grand means are returned as a newly allocated vector.
     /// The algorithm has complexity `O(v.len())`.
     pub fn compute_unordered(self, order: &[usize]) -> Vec<f64> {
+        self.compute_unordered_with::<f64>(order)
+    }
+
+    /// Computes the grand means as in `compute_unordered`, accumulating the sums with the
+    /// summation strategy `S`.
+    pub(crate) fn compute_unordered_with<S: Summation<Value = f64>>(
+        self,
+        order: &[usize],
+    ) -> Vec<f64> {
         assert_eq!(order.len(), self.len(), "order must be same length as v");
-        let mut current_sum_ascending = 0.0;
-        let mut current_sum_descending = 0.0;
-        let mut out = vec![0.0; self.len()];
+        let mut current_sum_ascending = S::default();
+        let mut current_sum_descending = S::default();
+        let mut out = vec![S::default(); self.len()];
 
         izip!(order.iter(), order.iter().rev())
             .enumerate()
-            .for_each(|(i, (ord_j, rev_ord_j))| {
-                out[*ord_j] += (2 * (i as i64) - self.len() as i64 + 1) as f64 * self.v[*ord_j]
-                    - current_sum_ascending;
-                out[*rev_ord_j] += current_sum_descending;
-                current_sum_descending += self.v[*rev_ord_j];
-                current_sum_ascending += self.v[*ord_j];
+            .for_each(|(i, (&ord_j, &rev_ord_j))| {
+                let factor = (2 * (i as i64) - self.len() as i64 + 1) as f64;
+                out[ord_j] = out[ord_j].add_sum(
+                    S::default()
+                        .add_product(factor, self.v[ord_j])
+                        .add_scaled(current_sum_ascending, -1.0),
+                );
+                out[rev_ord_j] = out[rev_ord_j].add_sum(current_sum_descending);
+                current_sum_descending = current_sum_descending.add(self.v[rev_ord_j]);
+                current_sum_ascending = current_sum_ascending.add(self.v[ord_j]);
             });
 
-        out.iter_mut().for_each(|x| *x /= self.len() as f64);
-
-        out
+        out.into_iter()
+            .map(|x| x.value() / self.len() as f64)
+            .collect()
     }
 
     /// Computes the matrix multiplication
@@ -47,7 +47,7 @@
 //!
 //! ### Binary data
 //!
-//! If one or both vectors are binary (containing only `0.0` or `1.0`), you can opt into faster, specialized routines by using the `compute_binary` method:
+//! If one or both vectors are binary (containing two distinct values, e.g. `0.0` and `1.0`), you can opt into faster, specialized routines by using the `compute_binary` method:
 //!
 //! ```rust
 //! use dist_corr::{DistCorrelation, DistCovariance};
@@ -78,59 +78,168 @@
 //! <a href="https://github.com/mg-gebert/dist_corr/blob/master/dist_corr_notes_gebert_lee.pdf" target="_blank" rel="noopener noreferrer">dist_corr_notes_gebert_lee.pdf</a>
 //!
 //!
+//! ### Permutation test of independence
+//!
+//! A p-value for the hypothesis that `v1` and `v2` are independent is obtained by comparing the distance
+//! correlation with the distance correlations of randomly permuted data:
+//!
+//! ```rust
+//! use dist_corr::DistCorrelation;
+//!
+//! let v1: Vec<f64> = (0..50).map(|i| (i as f64).cos()).collect();
+//! let v2: Vec<f64> = v1.iter().map(|x| x.abs()).collect();
+//!
+//! let dist_corr = DistCorrelation;
+//! // 999 permutations with seed 7
+//! let test = dist_corr.permutation_test(&v1, &v2, 999, 7).unwrap();
+//! println!("dCor = {}, p-value = {}", test.statistic, test.p_value);
+//! ```
+//!
+//! The vector `v2` is sorted only once, so each permutation costs a single `O(n log n)` evaluation.
+//!
 //! ### Calculating the Distance Correlation Matrix
 //!
-//! In the following example, we efficiently compute the cross distance correlation matrix, which contains the distance correlations between all pairs of vectors from two lists.
+//! The distance correlation matrix of a list of vectors and the cross distance correlation matrix, which
+//! contains the distance correlations between all pairs of vectors from two lists, are computed in parallel.
+//! Every vector is sorted only once and for the symmetric matrix only the upper triangle is evaluated.
 //!
 //! ```rust
-//! use dist_corr::DistCovariance;
+//! use dist_corr::DistCorrelation;
 //!
 //! let list_1 = [[0.1, 1.0, 2.0, 1.0], [0.0,-1.0,1.0,2.0]];
 //! let list_2 = [[-0.1, 1.0, -2.0, 1.0], [0.0,1.0,1.0,2.0]];
 //!
-//! let dist_cov = DistCovariance;
+//! let dist_corr = DistCorrelation;
+//!
+//! // symmetric distance correlation matrix of list_1, stored as packed upper triangle
+//! let dist_corr_mat = dist_corr.correlation_matrix(&list_1).unwrap();
+//! let row_0: Vec<f64> = dist_corr_mat.row(0);
 //!
-//! //compute distance variances for each list
-//! //note that (.compute_var(&v) is faster than calling .compute(&v,&v) )
-//! let dist_var_1: Vec<f64> = list_1.iter().map(|v_1| dist_cov.compute_var(v_1).unwrap()).collect();
-//! let dist_var_2: Vec<f64> = list_2.iter().map(|v_2| dist_cov.compute_var(v_2).unwrap()).collect();
-//!
-//! // compute distance correlation of all vectors pairs in list_1 vs list_2
-//! let dist_corr_mat: Vec<Vec<f64>> = list_1
-//!        .iter()
-//!        .zip(dist_var_1.iter())
-//!        .map(|(v_1, var_1)| {
-//!            let sqrt_var_1 = var_1.sqrt();
-//!            list_2
-//!                .iter()
-//!                .zip(dist_var_2.iter())
-//!                .map(|(v_2, var_2)| {
-//!                     let sqrt_var_2 = var_2.sqrt();
-//!                     let covariance = dist_cov.compute(v_1, v_2).unwrap();
-//!                     (covariance / (sqrt_var_1 * sqrt_var_2)).sqrt()
-//!                })
-//!                .collect()
-//!        })
-//!        .collect();
+//! // distance correlation of all vectors pairs in list_1 vs list_2
+//! let cross_dist_corr_mat: Vec<Vec<f64>> = dist_corr.cross_correlation_matrix(&list_1, &list_2).unwrap();
 //! ```
+//!
+//! ### Exponent of the distances
+//!
+//! Székely, Rizzo and Bakirov (2007) define the distance covariance for the distances `|x - y|^α` with any
+//! exponent `α` in `(0, 2)`, which is set with the `exponent` method of the builders:
+//!
+//! ```rust
+//! use dist_corr::DistCorrelation;
+//!
+//! let v1 = vec![1.0, 2.0, 3.0, 4.0];
+//! let v2 = vec![1.0, 4.0, 9.0, 16.0];
+//!
+//! let dist_corr = DistCorrelation::builder().exponent(0.5).build();
+//! let corr = dist_corr.compute(&v1, &v2).unwrap();
+//! ```
+//!
+//! Other exponents make the computations fail with `DistCorrError::InvalidExponent`. For `α = 1` the
+//! `O(n log n)` algorithms are used, for all other exponents an exact algorithm that sums over the `q`
+//! distinct pairs `(x_i, y_i)` weighted by their multiplicities in `O(n log n + q^2)` time and `O(q)`
+//! additional memory. Data with many ties, such as integers or rounded measurements, thus take
+//! sub-quadratic time, continuous data `O(n^2)`.
+//!
+//! No exact sub-quadratic algorithm for continuous data is known for `α ≠ 1`, the dyadic exponents `1/2`
+//! and `3/2` included. The `O(n log n)` algorithm of Chaudhuri and Hu (2019) relies on `|x - y|` being
+//! linear in `x` and `y` on either side of the sort order, which turns the row sums of the distance matrix
+//! into prefix sums and the Frobenius inner product into weighted inversion counts. `|x - y|^α` does not
+//! split into terms of `x` and `y` in this way.
+//!
+//! Multivariate samples use the Euclidean distances raised to `α`. Computations tied to `α = 1`, such as
+//! prepared vectors, random projections, categorical data with values and the permutation tests, fail with
+//! `DistCorrError::UnsupportedSetting`.
 
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Modules
 
 pub mod api;
+pub(crate) mod auto_detection;
+pub(crate) mod config;
 pub(crate) mod dist_corr;
+pub(crate) mod dist_corr_accurate;
+pub(crate) mod dist_corr_bias_corrected;
 pub(crate) mod dist_corr_binary;
+pub(crate) mod dist_corr_categorical;
+pub(crate) mod dist_corr_details;
+pub(crate) mod dist_corr_exponent;
 pub(crate) mod dist_corr_multi;
 pub(crate) mod dist_corr_naive;
+pub(crate) mod dist_cov_exact;
+pub(crate) mod error;
+pub(crate) mod exact_test;
 pub(crate) mod frob_inner_product;
 pub(crate) mod grand_mean;
+pub(crate) mod matrix;
+pub(crate) mod nan_policy;
+#[cfg(feature = "ndarray")]
+pub(crate) mod ndarray_api;
+pub(crate) mod numeric;
 pub(crate) mod ordering;
+pub(crate) mod partial_dist_corr;
+pub(crate) mod permutation_test;
+pub(crate) mod prepared_vector;
+pub(crate) mod random_projection;
+pub(crate) mod screening;
+pub(crate) mod special_functions;
+pub(crate) mod summation;
+pub(crate) mod t_test;
 pub(crate) mod tests;
 
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Export
 
+#[doc(inline)]
+pub use api::ConfiguredDistCorrelation;
+#[doc(inline)]
+pub use api::ConfiguredDistCovariance;
 #[doc(inline)]
 pub use api::DistCorrelation;
 #[doc(inline)]
 pub use api::DistCovariance;
+#[doc(inline)]
+pub use auto_detection::Algorithm;
+#[doc(inline)]
+pub use auto_detection::AutoResult;
+#[doc(inline)]
+pub use auto_detection::BinaryDetection;
+#[doc(inline)]
+pub use config::DistCorrelationBuilder;
+#[doc(inline)]
+pub use config::DistCovarianceBuilder;
+#[doc(inline)]
+pub use config::Estimator;
+#[doc(inline)]
+pub use dist_corr_bias_corrected::BiasCorrectedDistCorr;
+#[doc(inline)]
+pub use dist_corr_binary::ContingencyTable;
+#[doc(inline)]
+pub use dist_corr_details::DistCorrDetails;
+#[doc(inline)]
+pub use dist_cov_exact::ExactDistCov;
+#[doc(inline)]
+pub use error::DistCorrError;
+#[doc(inline)]
+pub use exact_test::BinaryIndependenceTest;
+#[doc(inline)]
+pub use matrix::SymmetricMatrix;
+#[doc(inline)]
+pub use nan_policy::NanPolicy;
+#[doc(inline)]
+pub use numeric::Numeric;
+#[doc(inline)]
+pub use partial_dist_corr::PartialDistCorr;
+#[doc(inline)]
+pub use permutation_test::PermutationTest;
+#[doc(inline)]
+pub use prepared_vector::FeatureResult;
+#[doc(inline)]
+pub use prepared_vector::PreparedVector;
+#[doc(inline)]
+pub use random_projection::RandomProjectionEstimate;
+#[doc(inline)]
+pub use screening::ScreenedFeature;
+#[doc(inline)]
+pub use screening::ScreeningCutoff;
+#[doc(inline)]
+pub use t_test::DistCorrTTest;
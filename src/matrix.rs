@@ -0,0 +1,190 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rayon::prelude::*;
+
+use crate::error::DistCorrError;
+use crate::numeric::{Column, Numeric};
+use crate::prepared_vector::{dist_corr_prepared, PreparedVector};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// A symmetric matrix storing only its upper triangle including the diagonal.
+///
+/// The entries are packed row by row, i.e. `(0, 0), (0, 1), ..., (0, dim - 1), (1, 1), ...`,
+/// such that a matrix of dimension `dim` needs `dim (dim + 1) / 2` values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymmetricMatrix {
+    dim: usize,
+    packed: Vec<f64>,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl SymmetricMatrix {
+    /// Returns the number of rows, which equals the number of columns.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the entry in row `i` and column `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.dim && j < self.dim, "index out of bounds");
+        self.packed[self.packed_index(i.min(j), i.max(j))]
+    }
+
+    /// Returns row `i` as a newly allocated vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn row(&self, i: usize) -> Vec<f64> {
+        (0..self.dim).map(|j| self.get(i, j)).collect()
+    }
+
+    /// Returns column `j` as a newly allocated vector, which equals row `j` by symmetry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `j` is out of bounds.
+    pub fn column(&self, j: usize) -> Vec<f64> {
+        self.row(j)
+    }
+
+    /// Returns the packed upper triangle.
+    pub fn packed(&self) -> &[f64] {
+        &self.packed
+    }
+
+    /// Returns the full matrix as a vector of rows.
+    pub fn to_dense(&self) -> Vec<Vec<f64>> {
+        (0..self.dim).map(|i| self.row(i)).collect()
+    }
+
+    /// index of the entry (i, j) with i <= j in the packed upper triangle
+    #[inline]
+    fn packed_index(&self, i: usize, j: usize) -> usize {
+        i * self.dim - i * (i + 1) / 2 + j
+    }
+}
+
+/// computes the distance correlations of all pairs of columns
+///
+/// Every column is prepared once, only the upper triangle is evaluated and the rows of the
+/// upper triangle are processed in parallel. Columns must be non-empty and of the same length.
+pub(crate) fn dist_corr_matrix<T: Numeric, C: Column<T>>(columns: &[C]) -> SymmetricMatrix {
+    let prepared = prepare_all(columns);
+    let dim = prepared.len();
+
+    let packed: Vec<f64> = (0..dim)
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let prepared = &prepared;
+            (i..dim).map(move |j| {
+                if i == j {
+                    // dCor(v, v) = 1 unless v is constant
+                    if prepared[i].dist_var() > 0.0 {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                } else {
+                    dist_corr_prepared(&prepared[i], &prepared[j])
+                }
+            })
+        })
+        .collect();
+
+    SymmetricMatrix { dim, packed }
+}
+
+/// computes the distance correlations of all vectors of list_1 with all vectors of list_2
+///
+/// Returns the rows corresponding to list_1. Vectors must be non-empty and of the same length.
+pub(crate) fn cross_dist_corr_matrix<T, U, C, D>(list_1: &[C], list_2: &[D]) -> Vec<Vec<f64>>
+where
+    T: Numeric,
+    U: Numeric,
+    C: Column<T>,
+    D: Column<U>,
+{
+    let prepared_1 = prepare_all(list_1);
+    let prepared_2 = prepare_all(list_2);
+
+    prepared_1
+        .par_iter()
+        .map(|v_1| {
+            prepared_2
+                .iter()
+                .map(|v_2| dist_corr_prepared(v_1, v_2))
+                .collect()
+        })
+        .collect()
+}
+
+/// computes the symmetric matrix of dist_corr evaluated on all pairs of columns
+///
+/// Only the upper triangle is evaluated and its rows are processed in parallel.
+pub(crate) fn pairwise_matrix<T, C, F>(
+    columns: &[C],
+    dist_corr: F,
+) -> Result<SymmetricMatrix, DistCorrError>
+where
+    T: Numeric,
+    C: Column<T>,
+    F: Fn(&[T], &[T]) -> Result<f64, DistCorrError> + Sync,
+{
+    let dim = columns.len();
+
+    let packed = (0..dim)
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let dist_corr = &dist_corr;
+            let column_i = columns[i].values();
+            (i..dim).map(move |j| dist_corr(&column_i, &columns[j].values()))
+        })
+        .collect::<Result<Vec<f64>, DistCorrError>>()?;
+
+    Ok(SymmetricMatrix { dim, packed })
+}
+
+/// computes dist_corr of all vectors of list_1 with all vectors of list_2
+///
+/// Returns the rows corresponding to list_1.
+pub(crate) fn pairwise_cross_matrix<T, U, C, D, F>(
+    list_1: &[C],
+    list_2: &[D],
+    dist_corr: F,
+) -> Result<Vec<Vec<f64>>, DistCorrError>
+where
+    T: Numeric,
+    U: Numeric,
+    C: Column<T>,
+    D: Column<U>,
+    F: Fn(&[T], &[U]) -> Result<f64, DistCorrError> + Sync,
+{
+    list_1
+        .par_iter()
+        .map(|v_1| {
+            let v_1 = v_1.values();
+            list_2
+                .iter()
+                .map(|v_2| dist_corr(&v_1, &v_2.values()))
+                .collect()
+        })
+        .collect()
+}
+
+/// prepares all vectors in parallel, converting each of them to f64 on its own
+fn prepare_all<T: Numeric, C: Column<T>>(vectors: &[C]) -> Vec<PreparedVector> {
+    vectors
+        .par_iter()
+        .map(|v| PreparedVector::prepare(&T::slice_to_f64(&v.values())))
+        .collect()
+}
@@ -0,0 +1,189 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use itertools::izip;
+use std::borrow::Cow;
+
+use crate::error::DistCorrError;
+use crate::numeric::{check_exact, Numeric};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Treatment of `NaN` and infinite values in the input data.
+///
+/// `NaN` and infinities are handled alike, since the distance to an infinite value is not finite.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Return [`DistCorrError::NonFinite`] pointing to the first non-finite value.
+    #[default]
+    Reject,
+    /// Drop every pair `(v1[i], v2[i])` in which one of the values is not finite.
+    PairwiseDeletion,
+    /// Return `NaN` as result if any value is not finite.
+    Propagate,
+}
+
+/// the complete pairs of two vectors after applying a [`NanPolicy`]
+pub(crate) enum CompletePairs<'a, T: Clone = f64, U: Clone = f64> {
+    /// the complete pairs, borrowed if no pair had to be dropped
+    Pairs(Cow<'a, [T]>, Cow<'a, [U]>),
+    /// the result is `NaN`
+    Propagated,
+}
+
+/// the complete observations of labels and values after applying a [`NanPolicy`] to the values
+pub(crate) enum CompleteLabeled<'a> {
+    /// the complete observations, borrowed if no observation had to be dropped
+    Pairs(Cow<'a, [usize]>, Cow<'a, [f64]>),
+    /// the result is `NaN`
+    Propagated,
+}
+
+/// the complete rows of N row-major samples after applying a [`NanPolicy`]
+pub(crate) enum CompleteRows<'a, const N: usize> {
+    /// the samples restricted to the complete rows, borrowed if no row had to be dropped
+    Rows([Cow<'a, [f64]>; N]),
+    /// the result is `NaN`
+    Propagated,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl NanPolicy {
+    /// applies the policy to the vectors v1 and v2 of identical length
+    ///
+    /// Fails if v1 and v2 contain values without exact f64 representation, if they contain
+    /// non-finite values and the policy is `Reject`, or if no complete pair is left after
+    /// pairwise deletion.
+    pub(crate) fn apply<'a, T: Numeric, U: Numeric>(
+        &self,
+        v1: &'a [T],
+        v2: &'a [U],
+    ) -> Result<CompletePairs<'a, T, U>, DistCorrError> {
+        check_exact(v1)?;
+        check_exact(v2)?;
+
+        let is_complete = |(a, b): (&T, &U)| a.to_f64().is_finite() && b.to_f64().is_finite();
+
+        match self {
+            NanPolicy::Reject => {
+                check_finite(v1)?;
+                check_finite(v2)?;
+
+                Ok(CompletePairs::Pairs(Cow::Borrowed(v1), Cow::Borrowed(v2)))
+            }
+            _ if izip!(v1, v2).all(is_complete) => {
+                Ok(CompletePairs::Pairs(Cow::Borrowed(v1), Cow::Borrowed(v2)))
+            }
+            NanPolicy::PairwiseDeletion => {
+                let (v1_complete, v2_complete): (Vec<T>, Vec<U>) =
+                    izip!(v1, v2).filter(|&pair| is_complete(pair)).unzip();
+
+                if v1_complete.is_empty() {
+                    return Err(DistCorrError::EmptyInput);
+                }
+
+                Ok(CompletePairs::Pairs(
+                    Cow::Owned(v1_complete),
+                    Cow::Owned(v2_complete),
+                ))
+            }
+            NanPolicy::Propagate => Ok(CompletePairs::Propagated),
+        }
+    }
+
+    /// applies the policy to the values of labels and values of identical length, where the
+    /// labels of deleted values are dropped
+    ///
+    /// Fails as `apply`.
+    pub(crate) fn apply_labeled<'a>(
+        &self,
+        labels: &'a [usize],
+        values: &'a [f64],
+    ) -> Result<CompleteLabeled<'a>, DistCorrError> {
+        let borrowed = || CompleteLabeled::Pairs(Cow::Borrowed(labels), Cow::Borrowed(values));
+
+        match self {
+            NanPolicy::Reject => {
+                check_finite(values)?;
+
+                Ok(borrowed())
+            }
+            _ if values.iter().all(|x| x.is_finite()) => Ok(borrowed()),
+            NanPolicy::PairwiseDeletion => {
+                let (labels_complete, values_complete): (Vec<usize>, Vec<f64>) =
+                    izip!(labels, values)
+                        .filter(|(_, value)| value.is_finite())
+                        .unzip();
+
+                if values_complete.is_empty() {
+                    return Err(DistCorrError::EmptyInput);
+                }
+
+                Ok(CompleteLabeled::Pairs(
+                    Cow::Owned(labels_complete),
+                    Cow::Owned(values_complete),
+                ))
+            }
+            NanPolicy::Propagate => Ok(CompleteLabeled::Propagated),
+        }
+    }
+
+    /// applies the policy to row-major samples with the same number of rows, where the rows of
+    /// `samples[k]` have `dims[k]` values
+    ///
+    /// Pairwise deletion drops the rows in which a value of any of the samples is not finite.
+    /// Fails as `apply`.
+    pub(crate) fn apply_rows<'a, const N: usize>(
+        &self,
+        samples: [&'a [f64]; N],
+        dims: [usize; N],
+    ) -> Result<CompleteRows<'a, N>, DistCorrError> {
+        let is_finite = |sample: &[f64]| sample.iter().all(|x| x.is_finite());
+
+        match self {
+            NanPolicy::Reject => {
+                samples.into_iter().try_for_each(check_finite)?;
+
+                Ok(CompleteRows::Rows(samples.map(Cow::Borrowed)))
+            }
+            _ if samples.into_iter().all(is_finite) => {
+                Ok(CompleteRows::Rows(samples.map(Cow::Borrowed)))
+            }
+            NanPolicy::PairwiseDeletion => {
+                let len = samples[0].len() / dims[0];
+                let complete: Vec<bool> = (0..len)
+                    .map(|i| izip!(samples, dims).all(|(s, d)| is_finite(&s[i * d..(i + 1) * d])))
+                    .collect();
+
+                if !complete.contains(&true) {
+                    return Err(DistCorrError::EmptyInput);
+                }
+
+                Ok(CompleteRows::Rows(std::array::from_fn(|k| {
+                    let rows = samples[k].chunks_exact(dims[k]).zip(&complete);
+                    Cow::Owned(
+                        rows.filter(|(_, &is_complete)| is_complete)
+                            .flat_map(|(row, _)| row)
+                            .copied()
+                            .collect(),
+                    )
+                })))
+            }
+            NanPolicy::Propagate => Ok(CompleteRows::Propagated),
+        }
+    }
+}
+
+/// checks that v contains only finite values and returns the first non-finite value otherwise
+pub(crate) fn check_finite<T: Numeric>(v: &[T]) -> Result<(), DistCorrError> {
+    match v.iter().position(|x| !x.to_f64().is_finite()) {
+        Some(index) => Err(DistCorrError::NonFinite {
+            index,
+            value: v[index].to_f64(),
+        }),
+        None => Ok(()),
+    }
+}
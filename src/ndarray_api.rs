@@ -0,0 +1,379 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use std::borrow::Cow;
+
+use ndarray::{ArrayView1, ArrayView2, Axis};
+
+use crate::api::{ConfiguredDistCorrelation, ConfiguredDistCovariance};
+use crate::error::DistCorrError;
+use crate::matrix::SymmetricMatrix;
+use crate::numeric::{Column, Numeric};
+use crate::prepared_vector::FeatureResult;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// a column of an array view, borrowed as slice if contiguous and copied otherwise
+pub(crate) struct ColumnView<'a, T>(ArrayView1<'a, T>);
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl<T: Numeric> Column<T> for ColumnView<'_, T> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn values(&self) -> Cow<'_, [T]> {
+        view_to_slice(self.0.view())
+    }
+}
+
+impl ConfiguredDistCorrelation {
+    /// Computes the distance correlation between two array views, see
+    /// [`ConfiguredDistCorrelation::compute`].
+    ///
+    /// Contiguous views are borrowed, strided views are copied into a vector once.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A view of the first data vector.
+    /// * `v2` - A view of the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance correlation between the two input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error in the cases of [`ConfiguredDistCorrelation::compute`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    /// use ndarray::array;
+    ///
+    /// let data = array![[0.0, 1.0], [1.0, 3.0], [2.0, 2.0], [3.0, 7.0]];
+    ///
+    /// // the columns of a row-major array are strided
+    /// let result = DistCorrelation
+    ///     .compute_array(data.column(0), data.column(1))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result, DistCorrelation.compute(&[0.0, 1.0, 2.0, 3.0], &[1.0, 3.0, 2.0, 7.0]).unwrap());
+    /// ```
+    pub fn compute_array<T: Numeric, U: Numeric>(
+        &self,
+        v1: ArrayView1<T>,
+        v2: ArrayView1<U>,
+    ) -> Result<f64, DistCorrError> {
+        self.compute(&view_to_slice(v1), &view_to_slice(v2))
+    }
+
+    /// Computes the distance correlation of the target with every column of the features, see
+    /// [`ConfiguredDistCorrelation::compute_one_vs_many`].
+    ///
+    /// The columns of column-major (Fortran order) views are borrowed, strided columns are copied
+    /// one at a time within the parallel task processing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A view of the target vector.
+    /// * `features` - A view of the features with one observation per row and one feature per
+    ///   column.
+    ///
+    /// # Returns
+    ///
+    /// A vector with one [`FeatureResult`] per column, in the order of the columns.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error in the cases of
+    /// [`ConfiguredDistCorrelation::compute_one_vs_many`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    /// use ndarray::array;
+    ///
+    /// let target = array![0.0, 1.0, 2.0, 3.0];
+    /// let features = array![[0.0, 1.0], [1.0, 0.0], [2.0, 1.0], [3.0, 0.0]];
+    ///
+    /// let results = DistCorrelation
+    ///     .compute_one_vs_many_array(target.view(), features.view())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(results.len(), 2);
+    /// assert!(results[0].as_ref().unwrap() > results[1].as_ref().unwrap());
+    /// ```
+    pub fn compute_one_vs_many_array<T: Numeric, U: Numeric>(
+        &self,
+        target: ArrayView1<T>,
+        features: ArrayView2<U>,
+    ) -> Result<Vec<FeatureResult>, DistCorrError> {
+        self.one_vs_many_helper(&view_to_slice(target), &column_views(features))
+    }
+
+    /// Computes the distance correlation matrix of the columns, see
+    /// [`ConfiguredDistCorrelation::correlation_matrix`].
+    ///
+    /// The columns of column-major (Fortran order) views are borrowed, strided columns are copied
+    /// one at a time within the parallel task processing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - A view with one observation per row and one data vector per column.
+    ///
+    /// # Returns
+    ///
+    /// The symmetric distance correlation matrix of the columns.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error in the cases of
+    /// [`ConfiguredDistCorrelation::correlation_matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    /// use ndarray::array;
+    ///
+    /// let data = array![[0.0, 1.0, 5.0], [1.0, 0.0, 3.0], [2.0, 1.0, 4.0], [3.0, 0.0, 1.0]];
+    ///
+    /// let matrix = DistCorrelation.correlation_matrix_array(data.view()).unwrap();
+    ///
+    /// assert_eq!(matrix.dim(), 3);
+    /// assert_eq!(matrix.get(0, 0), 1.0);
+    /// ```
+    pub fn correlation_matrix_array<T: Numeric>(
+        &self,
+        columns: ArrayView2<T>,
+    ) -> Result<SymmetricMatrix, DistCorrError> {
+        self.correlation_matrix_helper(&column_views(columns))
+    }
+
+    /// Computes the distance correlations between all columns of two views, see
+    /// [`ConfiguredDistCorrelation::cross_correlation_matrix`].
+    ///
+    /// The columns of column-major (Fortran order) views are borrowed, strided columns are copied
+    /// one at a time within the parallel task processing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `list_1` - A view whose columns correspond to the rows of the result.
+    /// * `list_2` - A view whose columns correspond to the columns of the result.
+    ///
+    /// # Returns
+    ///
+    /// A matrix, i.e. `Vec<Vec<f64>>`, with entry `[i][j]` the distance correlation of column `i`
+    /// of `list_1` and column `j` of `list_2`.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error in the cases of
+    /// [`ConfiguredDistCorrelation::cross_correlation_matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    /// use ndarray::array;
+    ///
+    /// let list_1 = array![[0.0, 1.0], [1.0, 0.0], [2.0, 1.0], [3.0, 0.0]];
+    /// let list_2 = array![[0.0], [2.0], [4.0], [6.0]];
+    ///
+    /// let matrix = DistCorrelation
+    ///     .cross_correlation_matrix_array(list_1.view(), list_2.view())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(matrix.len(), 2);
+    /// assert_eq!(matrix[0][0], 1.0);
+    /// ```
+    pub fn cross_correlation_matrix_array<T: Numeric, U: Numeric>(
+        &self,
+        list_1: ArrayView2<T>,
+        list_2: ArrayView2<U>,
+    ) -> Result<Vec<Vec<f64>>, DistCorrError> {
+        self.cross_correlation_matrix_helper(&column_views(list_1), &column_views(list_2))
+    }
+
+    /// Computes the distance correlation between two multivariate samples, see
+    /// [`ConfiguredDistCorrelation::compute_multi`].
+    ///
+    /// Row-major (standard layout) views are borrowed without a copy.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A view of the first sample with one observation per row.
+    /// * `y` - A view of the second sample with one observation per row.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance correlation between the two samples.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error in the cases of
+    /// [`ConfiguredDistCorrelation::compute_multi`], in particular if a view has no columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCorrelation;
+    /// use ndarray::array;
+    ///
+    /// let x = array![[0.0, 1.0], [1.0, 0.0], [2.0, 2.0], [3.0, 1.0]];
+    ///
+    /// let result = DistCorrelation.compute_multi_array(x.view(), x.view()).unwrap();
+    ///
+    /// assert!((result - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn compute_multi_array<T: Numeric, U: Numeric>(
+        &self,
+        x: ArrayView2<T>,
+        y: ArrayView2<U>,
+    ) -> Result<f64, DistCorrError> {
+        let (dim_x, dim_y) = (x.ncols(), y.ncols());
+
+        self.compute_multi(&rows_to_slice(x), dim_x, &rows_to_slice(y), dim_y)
+    }
+}
+
+impl ConfiguredDistCovariance {
+    /// Computes the distance covariance between two array views, see
+    /// [`ConfiguredDistCovariance::compute`].
+    ///
+    /// Contiguous views are borrowed, strided views are copied into a vector once.
+    ///
+    /// # Arguments
+    ///
+    /// * `v1` - A view of the first data vector.
+    /// * `v2` - A view of the second data vector.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two input vectors.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error in the cases of [`ConfiguredDistCovariance::compute`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    /// use ndarray::array;
+    ///
+    /// let data = array![[0.0, 0.0], [1.0, 1.0], [0.0, 1.0], [1.0, 0.0]];
+    ///
+    /// let result = DistCovariance
+    ///     .compute_array(data.column(0), data.column(1))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result, 0.0);
+    /// ```
+    pub fn compute_array<T: Numeric, U: Numeric>(
+        &self,
+        v1: ArrayView1<T>,
+        v2: ArrayView1<U>,
+    ) -> Result<f64, DistCorrError> {
+        self.compute(&view_to_slice(v1), &view_to_slice(v2))
+    }
+
+    /// Computes the distance variance of an array view, see [`ConfiguredDistCovariance::compute_var`].
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - A view of the input data vector.
+    ///
+    /// # Returns
+    ///
+    /// A `f64` value representing the distance variance of the input vector.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error in the cases of [`ConfiguredDistCovariance::compute_var`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    /// use ndarray::array;
+    ///
+    /// let data = array![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+    ///
+    /// assert_eq!(DistCovariance.compute_var_array(data.column(0)).unwrap(), 0.0);
+    /// ```
+    pub fn compute_var_array<T: Numeric>(&self, v: ArrayView1<T>) -> Result<f64, DistCorrError> {
+        self.compute_var(&view_to_slice(v))
+    }
+
+    /// Computes the distance covariance between two multivariate samples, see
+    /// [`ConfiguredDistCovariance::compute_multi`].
+    ///
+    /// Row-major (standard layout) views are borrowed without a copy.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - A view of the first sample with one observation per row.
+    /// * `y` - A view of the second sample with one observation per row.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `f64` representing the distance covariance between the two samples.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error in the cases of
+    /// [`ConfiguredDistCovariance::compute_multi`], in particular if a view has no columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dist_corr::DistCovariance;
+    /// use ndarray::array;
+    ///
+    /// let x = array![[0.0, 1.0], [1.0, 0.0], [2.0, 2.0], [3.0, 1.0]];
+    /// let y = array![[1.0], [0.0], [1.0], [0.0]];
+    ///
+    /// let result = DistCovariance.compute_multi_array(x.view(), y.view()).unwrap();
+    ///
+    /// assert!(result > 0.0);
+    /// ```
+    pub fn compute_multi_array<T: Numeric, U: Numeric>(
+        &self,
+        x: ArrayView2<T>,
+        y: ArrayView2<U>,
+    ) -> Result<f64, DistCorrError> {
+        let (dim_x, dim_y) = (x.ncols(), y.ncols());
+
+        self.compute_multi(&rows_to_slice(x), dim_x, &rows_to_slice(y), dim_y)
+    }
+}
+
+/// the values of the view as slice, borrowing contiguous views
+pub(crate) fn view_to_slice<T: Numeric>(v: ArrayView1<'_, T>) -> Cow<'_, [T]> {
+    match v.to_slice() {
+        Some(values) => Cow::Borrowed(values),
+        None => Cow::Owned(v.to_vec()),
+    }
+}
+
+/// the columns of the view, which are converted to slices only when their values are accessed
+pub(crate) fn column_views<T: Numeric>(columns: ArrayView2<'_, T>) -> Vec<ColumnView<'_, T>> {
+    (0..columns.ncols())
+        .map(|j| ColumnView(columns.index_axis_move(Axis(1), j)))
+        .collect()
+}
+
+/// the view as row-major matrix, borrowing views in standard layout
+pub(crate) fn rows_to_slice<T: Numeric>(rows: ArrayView2<'_, T>) -> Cow<'_, [T]> {
+    match rows.to_slice() {
+        Some(values) => Cow::Borrowed(values),
+        None => Cow::Owned(rows.iter().copied().collect()),
+    }
+}
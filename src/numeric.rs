@@ -0,0 +1,146 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use std::borrow::Cow;
+
+use crate::error::DistCorrError;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Definition
+
+/// largest magnitude up to which every integer has an exact f64 representation
+const MAX_EXACT: u128 = 1 << 53;
+
+/// Numeric element type of the input vectors, such as `f64`, `f32` or integers.
+///
+/// All computations accumulate in `f64`. The general `O(n log n)` algorithms convert the values
+/// while sorting them, the other algorithms convert a vector once per call, respectively the
+/// vectors of the batch computations one at a time within their parallel tasks, and slices of
+/// `f64` are borrowed without a copy. The conversion is exact for `f32` and for the integer types
+/// up to 32 bits. `i64`, `u64`, `isize` and `usize` values beyond `2^53` in magnitude have no exact
+/// `f64` representation and make the computations fail with
+/// [`DistCorrError::InexactConversion`](crate::DistCorrError::InexactConversion); see
+/// [`ConfiguredDistCovariance::compute_exact`](crate::ConfiguredDistCovariance::compute_exact) for
+/// exact results on such data.
+///
+/// The methods of [`ConfiguredDistCorrelation`](crate::ConfiguredDistCorrelation) and
+/// [`ConfiguredDistCovariance`](crate::ConfiguredDistCovariance) take slices of any `Numeric` type,
+/// the `f64` methods of the unit structs `DistCorrelation` and `DistCovariance` are kept for
+/// existing code.
+///
+/// # Examples
+///
+/// ```
+/// use dist_corr::{ConfiguredDistCorrelation, DistCorrError};
+///
+/// let v1: Vec<f32> = vec![0.5, 1.5, 2.5, 4.0];
+/// let v2: Vec<u8> = vec![1, 2, 3, 5];
+///
+/// let dist_corr = ConfiguredDistCorrelation::default();
+/// let result = dist_corr.compute(&v1, &v2).unwrap();
+///
+/// assert_eq!(result, dist_corr.compute(&[0.5, 1.5, 2.5, 4.0], &[1.0, 2.0, 3.0, 5.0]).unwrap());
+/// assert_eq!(
+///     dist_corr.compute(&v1, &[1_i64, 2, 3, 1 << 60]),
+///     Err(DistCorrError::InexactConversion { index: 3 })
+/// );
+/// ```
+pub trait Numeric: Copy + Send + Sync + 'static {
+    /// Converts the value to `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Returns `true` if [`Numeric::to_f64`] converts the value without rounding.
+    fn is_exact(self) -> bool {
+        true
+    }
+
+    /// Converts the values to `f64`, borrowing them if they already are.
+    fn slice_to_f64(values: &[Self]) -> Cow<'_, [f64]> {
+        Cow::Owned(values.iter().map(|x| x.to_f64()).collect())
+    }
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl Numeric for f64 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn slice_to_f64(values: &[Self]) -> Cow<'_, [f64]> {
+        Cow::Borrowed(values)
+    }
+}
+
+macro_rules! impl_numeric {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(f32, i8, i16, i32, u8, u16, u32);
+
+/// integers with more than 53 bits, which are exact in f64 up to 2^53 in magnitude
+macro_rules! impl_numeric_wide {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn is_exact(self) -> bool {
+                    (self as i128).unsigned_abs() <= MAX_EXACT
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_wide!(i64, isize, u64, usize);
+
+/// checks that every value of v is converted to f64 exactly and returns the index of the first
+/// inexact value otherwise
+pub(crate) fn check_exact<T: Numeric>(v: &[T]) -> Result<(), DistCorrError> {
+    match v.iter().position(|x| !x.is_exact()) {
+        Some(index) => Err(DistCorrError::InexactConversion { index }),
+        None => Ok(()),
+    }
+}
+
+/// converts v to f64 if every value is converted exactly, slices of f64 are borrowed
+pub(crate) fn to_f64_checked<T: Numeric>(v: &[T]) -> Result<Cow<'_, [f64]>, DistCorrError> {
+    check_exact(v)?;
+
+    Ok(T::slice_to_f64(v))
+}
+
+/// a data vector of Numeric values, such as a slice or a column of an array
+pub(crate) trait Column<T: Numeric>: Sync {
+    /// returns the number of values
+    fn len(&self) -> usize;
+
+    /// returns the values, borrowed if they are stored contiguously
+    fn values(&self) -> Cow<'_, [T]>;
+}
+
+impl<T: Numeric, V: AsRef<[T]> + Sync> Column<T> for V {
+    fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+
+    fn values(&self) -> Cow<'_, [T]> {
+        Cow::Borrowed(self.as_ref())
+    }
+}
@@ -3,6 +3,8 @@
 
 use rayon::prelude::*;
 
+use crate::numeric::Numeric;
+
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Struct
 
@@ -20,8 +22,8 @@ impl Ordering {
     ///
     /// # Arguments
     ///
-    /// * `v1` - A slice of floating-point values.
-    /// * `v2` - A slice of floating-point values.
+    /// * `v1` - A slice of numeric values, converted to `f64` while permuting.
+    /// * `v2` - A slice of numeric values, converted to `f64` while permuting.
     /// * `store_order_v1` - bool.
     ///
     /// # Returns
@@ -30,19 +32,25 @@ impl Ordering {
     /// - `v1_per`: A `Vec<f64>` with elements of `v1` reordered to match the permutation that sorts `v2`.
     /// - `v2_ord`: A `Vec<f64>` which is a sorted version of `v2` (in increasing order).
     /// - `ordering`: A `Vec<usize>` representing the indices that would sort `v1_per` in increasing order.
-    pub(crate) fn order_wrt_v2(v1: &[f64], v2: &[f64], store_order_v1: bool) -> Ordering {
+    pub(crate) fn order_wrt_v2<T: Numeric, U: Numeric>(
+        v1: &[T],
+        v2: &[U],
+        store_order_v1: bool,
+    ) -> Ordering {
         let mut ordering: Vec<usize> = (0..v1.len()).collect();
 
         // compute ordering of v2
-        ordering.par_sort_unstable_by(|&i, &j| v2[i].partial_cmp(&v2[j]).unwrap());
+        ordering.par_sort_unstable_by(|&i, &j| v2[i].to_f64().total_cmp(&v2[j].to_f64()));
 
         // sort v1 and v2 according to above ordering of v2
-        let (v1_per, v2_ord): (Vec<f64>, Vec<f64>) =
-            ordering.iter().map(|&i| (v1[i], v2[i])).unzip();
+        let (v1_per, v2_ord): (Vec<f64>, Vec<f64>) = ordering
+            .iter()
+            .map(|&i| (v1[i].to_f64(), v2[i].to_f64()))
+            .unzip();
 
         if store_order_v1 {
             // update ordering to reflect ordering of v1_shuffled
-            ordering.par_sort_unstable_by(|&i, &j| v1_per[i].partial_cmp(&v1_per[j]).unwrap());
+            ordering.par_sort_unstable_by(|&i, &j| v1_per[i].total_cmp(&v1_per[j]));
 
             Ordering {
                 v1_per,
@@ -0,0 +1,112 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use crate::dist_corr_bias_corrected::BiasCorrectedDistCorr;
+use crate::dist_corr_multi::DistSums;
+use crate::error::DistCorrError;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Definition
+
+/// `1 - R*^2` below this tolerance is treated as zero, i.e. the U-centered distance matrix
+/// of x (resp. y) is considered to lie in the span of the one of z
+const RESIDUAL_TOL: f64 = 1e-10;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Partial distance correlation of x and y given z based on U-centered distance matrices.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartialDistCorr {
+    /// Partial distance correlation `pdCor(x, y; z)` in `[-1.0, 1.0]`, `0.0` if x or y is fully explained by z.
+    pub partial_dist_corr: f64,
+    /// Partial distance covariance `pdCov(x, y; z)`, the inner product of the projections of the U-centered
+    /// distance matrices of x and y onto the orthogonal complement of the one of z.
+    pub partial_dist_cov: f64,
+    /// Bias-corrected distance correlation of x and y.
+    pub dist_corr_xy: BiasCorrectedDistCorr,
+    /// Bias-corrected distance correlation of x and z.
+    pub dist_corr_xz: BiasCorrectedDistCorr,
+    /// Bias-corrected distance correlation of y and z.
+    pub dist_corr_yz: BiasCorrectedDistCorr,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl PartialDistCorr {
+    /// result propagated as `NaN` from len observations
+    pub(crate) fn propagated(len: usize) -> Self {
+        PartialDistCorr {
+            partial_dist_corr: f64::NAN,
+            partial_dist_cov: f64::NAN,
+            dist_corr_xy: BiasCorrectedDistCorr::propagated(len),
+            dist_corr_xz: BiasCorrectedDistCorr::propagated(len),
+            dist_corr_yz: BiasCorrectedDistCorr::propagated(len),
+        }
+    }
+
+    /// combines the pairwise bias-corrected distance correlations (Székely and Rizzo, 2014)
+    ///
+    /// ```text
+    /// pdCor(x, y; z) = (R*_xy - R*_xz R*_yz) / sqrt((1 - R*_xz^2) (1 - R*_yz^2))
+    /// pdCov(x, y; z) = dCov²_U(x, y) - dCov²_U(x, z) dCov²_U(y, z) / dVar²_U(z)
+    /// ```
+    pub(crate) fn new(
+        dist_corr_xy: BiasCorrectedDistCorr,
+        dist_corr_xz: BiasCorrectedDistCorr,
+        dist_corr_yz: BiasCorrectedDistCorr,
+    ) -> Self {
+        let r_xy = dist_corr_xy.dist_corr_sq;
+        let r_xz = dist_corr_xz.dist_corr_sq;
+        let r_yz = dist_corr_yz.dist_corr_sq;
+
+        let residual_x = 1.0 - r_xz * r_xz;
+        let residual_y = 1.0 - r_yz * r_yz;
+
+        let partial_dist_corr = if residual_x > RESIDUAL_TOL && residual_y > RESIDUAL_TOL {
+            ((r_xy - r_xz * r_yz) / (residual_x * residual_y).sqrt()).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        };
+
+        // the projection onto the orthogonal complement of a constant z is the identity
+        let dist_var_sq_z = dist_corr_xz.dist_var_sq_v2;
+        let partial_dist_cov = if dist_var_sq_z > 0.0 {
+            dist_corr_xy.dist_cov_sq
+                - dist_corr_xz.dist_cov_sq * dist_corr_yz.dist_cov_sq / dist_var_sq_z
+        } else {
+            dist_corr_xy.dist_cov_sq
+        };
+
+        PartialDistCorr {
+            partial_dist_corr,
+            partial_dist_cov,
+            dist_corr_xy,
+            dist_corr_xz,
+            dist_corr_yz,
+        }
+    }
+}
+
+/// computes the partial distance correlation of the row-major samples x and y given z
+///
+/// The sums of all three samples are computed in a single pass, such that every distance is
+/// computed once.
+pub(crate) fn partial_dist_corr_multi(
+    x: &[f64],
+    dim_x: usize,
+    y: &[f64],
+    dim_y: usize,
+    z: &[f64],
+    dim_z: usize,
+    exponent: f64,
+) -> Result<PartialDistCorr, DistCorrError> {
+    let sums = DistSums::compute([x, y, z], [dim_x, dim_y, dim_z], exponent);
+
+    Ok(PartialDistCorr::new(
+        sums.bias_corrected(0, 1),
+        sums.bias_corrected(0, 2),
+        sums.bias_corrected(1, 2),
+    ))
+}
@@ -0,0 +1,248 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::dist_corr::{dist_cov_sq_helper, dist_var_sq_helper};
+use crate::dist_corr_binary::{
+    dist_corr_both_binary_helper, dist_cov_both_binary, dist_cov_sq_one_binary_helper,
+};
+use crate::error::DistCorrError;
+use crate::grand_mean::GrandMeans;
+use crate::ordering::Ordering;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Result of a permutation test of independence based on the distance correlation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PermutationTest {
+    /// Observed distance correlation of the unpermuted data.
+    pub statistic: f64,
+    /// Permutation p-value `(1 + #{b : T_b >= T}) / (1 + n_perm)`.
+    pub p_value: f64,
+    /// Number of permutations used.
+    pub n_perm: usize,
+    /// Mean of the distance correlations under permutation.
+    pub null_mean: f64,
+    /// Standard deviation of the distance correlations under permutation.
+    pub null_std_dev: f64,
+    /// Maximum of the distance correlations under permutation.
+    pub null_max: f64,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl PermutationTest {
+    /// test propagated as `NaN` for n_perm permutations
+    pub(crate) fn propagated(n_perm: usize) -> Self {
+        PermutationTest {
+            statistic: f64::NAN,
+            p_value: f64::NAN,
+            n_perm,
+            null_mean: f64::NAN,
+            null_std_dev: f64::NAN,
+            null_max: f64::NAN,
+        }
+    }
+
+    /// summarizes the permutation distribution `null` of the observed `statistic`
+    pub(crate) fn from_null_distribution(statistic: f64, null: &[f64]) -> Self {
+        let n_perm = null.len();
+
+        let exceedances = null.iter().filter(|&&t| t >= statistic).count();
+        let p_value = (1 + exceedances) as f64 / (1 + n_perm) as f64;
+
+        let null_mean = null.iter().sum::<f64>() / n_perm as f64;
+        let null_std_dev = if n_perm > 1 {
+            (null.iter().map(|t| (t - null_mean).powi(2)).sum::<f64>() / (n_perm - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+        let null_max = null.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        PermutationTest {
+            statistic,
+            p_value,
+            n_perm,
+            null_mean,
+            null_std_dev,
+            null_max,
+        }
+    }
+}
+
+/// random number generator of the independent task number `stream`
+///
+/// Every task draws from its own ChaCha stream of `seed`, so that the result
+/// does not depend on how rayon schedules the tasks.
+pub(crate) fn stream_rng(seed: u64, stream: usize) -> ChaCha8Rng {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(stream as u64);
+    rng
+}
+
+/// random permutation of `0..len` for permutation number `b`
+pub(crate) fn permutation(len: usize, seed: u64, b: usize) -> Vec<usize> {
+    let mut rng = stream_rng(seed, b);
+
+    let mut perm: Vec<usize> = (0..len).collect();
+    perm.shuffle(&mut rng);
+    perm
+}
+
+/// permutation test of independence for the distance correlation of v1 and v2
+///
+/// v2 is sorted once. Permuting v1 only permutes its grand means, hence each
+/// permutation costs a single frobenius inner product.
+pub(crate) fn dist_corr_permutation_test(
+    v1: &[f64],
+    v2: &[f64],
+    n_perm: usize,
+    seed: u64,
+) -> Result<PermutationTest, DistCorrError> {
+    let len = v1.len();
+
+    // sort v1,v2 with respect to ordering of v2
+    let Ordering {
+        v1_per,
+        v2_ord,
+        order_v1_per,
+    } = Ordering::order_wrt_v2(v1, v2, true);
+
+    // compute grand means of v1 and v2
+    let grand_means_v1 = GrandMeans::new(&v1_per).compute_unordered(order_v1_per.as_ref().unwrap());
+    let grand_means_v2 = GrandMeans::new(&v2_ord).compute_ordered();
+
+    // distance variances are invariant under permutation
+    let dist_var_v1 = dist_var_sq_helper(&v1_per, &grand_means_v1, len as f64).sqrt();
+    let dist_var_v2 = dist_var_sq_helper(&v2_ord, &grand_means_v2, len as f64).sqrt();
+    let denominator = (dist_var_v1 * dist_var_v2).sqrt();
+
+    let dist_corr_permuted = |v1_p: &[f64], grand_means_v1_p: &[f64]| {
+        if denominator > 0.0 {
+            let dist_cov_sq =
+                dist_cov_sq_helper(v1_p, &v2_ord, grand_means_v1_p, &grand_means_v2, len);
+            (dist_cov_sq.sqrt() / denominator).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    };
+
+    let statistic = dist_corr_permuted(&v1_per, &grand_means_v1);
+
+    let null: Vec<f64> = (0..n_perm)
+        .into_par_iter()
+        .map(|b| {
+            let (v1_p, grand_means_v1_p): (Vec<f64>, Vec<f64>) = permutation(len, seed, b)
+                .iter()
+                .map(|&i| (v1_per[i], grand_means_v1[i]))
+                .unzip();
+
+            dist_corr_permuted(&v1_p, &grand_means_v1_p)
+        })
+        .collect();
+
+    Ok(PermutationTest::from_null_distribution(statistic, &null))
+}
+
+/// permutation test of independence for the distance correlation of v1 and v2
+///
+/// v1 must be 0-1-valued. v2 is sorted once, afterwards every permutation of the
+/// labels of v1 costs a single weighted grand mean pass of complexity `O(n)`.
+pub(crate) fn dist_corr_one_binary_permutation_test(
+    v1: &[f64],
+    v2: &[f64],
+    n_perm: usize,
+    seed: u64,
+) -> Result<PermutationTest, DistCorrError> {
+    let len = v1.len();
+
+    // sort v1,v2 with respect to ordering of v2
+    let Ordering {
+        mut v1_per, v2_ord, ..
+    } = Ordering::order_wrt_v2(v1, v2, false);
+
+    v1_per.iter_mut().for_each(|vi| *vi = 2.0 * *vi - 1.0);
+
+    // grand means and distance variances are invariant under permutation
+    let grand_means_v2 = GrandMeans::new(&v2_ord).compute_ordered();
+    let dist_var_v2 = dist_var_sq_helper(&v2_ord, &grand_means_v2, len as f64).sqrt();
+    let dist_var_v1 = dist_cov_both_binary(v1, v1)?;
+    let denominator = dist_var_v1 * dist_var_v2;
+
+    let dist_corr_permuted = |v1_p: &[f64]| {
+        if denominator > 0.0 {
+            let grand_means_v2_weighted = GrandMeans::new(&v2_ord).compute_ordered_weighted(v1_p);
+            let dist_cov_sq = dist_cov_sq_one_binary_helper(
+                v1_p,
+                &grand_means_v2,
+                &grand_means_v2_weighted,
+                len as f64,
+            );
+            (dist_cov_sq / denominator).clamp(0.0, 1.0).sqrt()
+        } else {
+            0.0
+        }
+    };
+
+    let statistic = dist_corr_permuted(&v1_per);
+
+    let null: Vec<f64> = (0..n_perm)
+        .into_par_iter()
+        .map(|b| {
+            let v1_p: Vec<f64> = permutation(len, seed, b)
+                .iter()
+                .map(|&i| v1_per[i])
+                .collect();
+
+            dist_corr_permuted(&v1_p)
+        })
+        .collect();
+
+    Ok(PermutationTest::from_null_distribution(statistic, &null))
+}
+
+/// permutation test of independence for the distance correlation of v1 and v2
+///
+/// v1 and v2 must be 0-1-valued. Permuting v1 leaves the margins of the 2x2
+/// contingency table unchanged, hence only n11 is recounted in `O(n)`.
+pub(crate) fn dist_corr_both_binary_permutation_test(
+    v1: &[f64],
+    v2: &[f64],
+    n_perm: usize,
+    seed: u64,
+) -> Result<PermutationTest, DistCorrError> {
+    let len = v1.len();
+
+    // sort v1,v2 with respect to ordering of v2 such that the permutations
+    // coincide with the ones of the non-binary permutation test
+    let Ordering { v1_per, v2_ord, .. } = Ordering::order_wrt_v2(v1, v2, false);
+
+    let (n1_, n_1) = (v1.iter().sum::<f64>(), v2.iter().sum::<f64>());
+    let n00_minus_n11 = len as f64 - n1_ - n_1;
+
+    let dist_corr_counted =
+        |n11: f64| dist_corr_both_binary_helper(n00_minus_n11 + n11, n_1 - n11, n1_ - n11, n11);
+
+    let statistic = dist_corr_counted(v1.iter().zip(v2).map(|(a, b)| a * b).sum::<f64>());
+
+    let null: Vec<f64> = (0..n_perm)
+        .into_par_iter()
+        .map(|b| {
+            let n11 = permutation(len, seed, b)
+                .iter()
+                .zip(&v2_ord)
+                .map(|(&i, b)| v1_per[i] * b)
+                .sum::<f64>();
+
+            dist_corr_counted(n11)
+        })
+        .collect();
+
+    Ok(PermutationTest::from_null_distribution(statistic, &null))
+}
@@ -0,0 +1,241 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rayon::prelude::*;
+
+use crate::auto_detection::{BinaryDetection, BinaryVector};
+use crate::dist_corr::{dist_cov_sq_helper, dist_var_sq_helper};
+use crate::dist_corr_binary::{dist_cov_sq_one_binary_helper, ContingencyTable};
+use crate::error::DistCorrError;
+use crate::grand_mean::GrandMeans;
+use crate::nan_policy::check_finite;
+use crate::numeric::{to_f64_checked, Column, Numeric};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Definition
+
+/// Result of a single feature of a one-vs-many computation.
+pub type FeatureResult = Result<f64, DistCorrError>;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// A vector together with the preprocessing shared by all distance covariance computations
+/// it takes part in.
+///
+/// Building a `PreparedVector` sorts the data and computes its grand means and distance variance
+/// once in `O(n log n)`. Passing it to
+/// [`ConfiguredDistCorrelation::compute_prepared`](crate::ConfiguredDistCorrelation::compute_prepared)
+/// or [`ConfiguredDistCovariance::compute_prepared`](crate::ConfiguredDistCovariance::compute_prepared)
+/// skips this
+/// preprocessing, which pays off if the same vector is compared with many others.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreparedVector {
+    values: Vec<f64>,
+    sorted: Vec<f64>,
+    order: Vec<usize>,
+    ranks: Vec<usize>,
+    grand_means: Vec<f64>,
+    dist_var: f64,
+    /// the values mapped to 0-1-values and the scale of the map if the vector is binary
+    binary: Option<(Vec<f64>, f64)>,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl PreparedVector {
+    /// Prepares the vector `v` for repeated distance covariance computations.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an error if `v` is empty, contains `NaN` or infinite values or
+    /// integers beyond `2^53` in magnitude.
+    pub fn new<T: Numeric>(v: &[T]) -> Result<Self, DistCorrError> {
+        if v.is_empty() {
+            return Err(DistCorrError::EmptyInput);
+        }
+
+        let v = to_f64_checked(v)?;
+        check_finite(&v)?;
+
+        Ok(Self::prepare(&v))
+    }
+
+    /// prepares the non-empty vector v
+    pub(crate) fn prepare(v: &[f64]) -> Self {
+        let len = v.len();
+
+        // compute ordering of v
+        let mut order: Vec<usize> = (0..len).collect();
+        order.par_sort_unstable_by(|&i, &j| v[i].total_cmp(&v[j]));
+
+        let sorted: Vec<f64> = order.iter().map(|&i| v[i]).collect();
+
+        let mut ranks = vec![0; len];
+        order
+            .iter()
+            .enumerate()
+            .for_each(|(rank, &i)| ranks[i] = rank);
+
+        let grand_means = GrandMeans::new(&sorted).compute_ordered();
+        let dist_var = dist_var_sq_helper(&sorted, &grand_means, len as f64).sqrt();
+
+        let binary = BinaryVector::detect(v, BinaryDetection::TwoValued)
+            .map(|b| (b.values.into_owned(), b.scale));
+
+        PreparedVector {
+            values: v.to_vec(),
+            sorted,
+            order,
+            ranks,
+            grand_means,
+            dist_var,
+            binary,
+        }
+    }
+
+    /// Returns the length of the vector.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the vector is empty, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the values in their original order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the indices that sort the values increasingly.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the rank of every value, i.e. its position in the sorted vector.
+    pub fn ranks(&self) -> &[usize] {
+        &self.ranks
+    }
+
+    /// Returns the grand means `sum_j |v[i] - v[j]| / n` in the original order of the values.
+    pub fn grand_means(&self) -> Vec<f64> {
+        self.ranks.iter().map(|&r| self.grand_means[r]).collect()
+    }
+
+    /// Returns the distance variance `dVar(v)`.
+    pub fn dist_var(&self) -> f64 {
+        self.dist_var
+    }
+
+    /// Returns `true` if the vector is binary, i.e. has at most two distinct values.
+    pub fn is_binary(&self) -> bool {
+        self.binary.is_some()
+    }
+}
+
+/// computes distance correlation of the prepared vectors v1 and v2
+pub(crate) fn dist_corr_prepared(v1: &PreparedVector, v2: &PreparedVector) -> f64 {
+    if v1.dist_var > 0.0 && v2.dist_var > 0.0 {
+        (dist_cov_sq_prepared(v1, v2) / (v1.dist_var * v2.dist_var))
+            .clamp(0.0, 1.0)
+            .sqrt()
+    } else {
+        0.0
+    }
+}
+
+/// computes the distance correlation of every feature with the target
+///
+/// The target is prepared once and the features are processed in parallel, each of them is
+/// converted to f64 within its own task. The results are returned in the order of the features,
+/// a feature fails if its length differs from the length of the target or if it contains
+/// non-finite or inexact values.
+pub(crate) fn dist_corr_one_vs_many<T: Numeric, C: Column<T>>(
+    target: &[f64],
+    features: &[C],
+) -> Vec<FeatureResult> {
+    let prepared_target = PreparedVector::prepare(target);
+
+    features
+        .par_iter()
+        .map(|feature| {
+            let feature = feature.values();
+            let feature = to_f64_checked(&feature)?;
+
+            if feature.len() != target.len() {
+                return Err(DistCorrError::LengthMismatch {
+                    expected: target.len(),
+                    found: feature.len(),
+                });
+            }
+
+            check_finite(&feature)?;
+
+            Ok(dist_corr_prepared(
+                &PreparedVector::prepare(&feature),
+                &prepared_target,
+            ))
+        })
+        .collect()
+}
+
+/// computes distance covariance of the prepared vectors v1 and v2
+pub(crate) fn dist_cov_prepared(v1: &PreparedVector, v2: &PreparedVector) -> f64 {
+    dist_cov_sq_prepared(v1, v2).sqrt()
+}
+
+/// computes dCov^2 of the prepared vectors v1 and v2
+///
+/// Uses the `O(n)` binary formulas if one or both vectors are binary and a single
+/// frobenius inner product otherwise.
+fn dist_cov_sq_prepared(v1: &PreparedVector, v2: &PreparedVector) -> f64 {
+    match (&v1.binary, &v2.binary) {
+        // counting cannot fail since both vectors are 0-1-valued,
+        // dCov^2(a v1 + b, v2) = |a| dCov^2(v1, v2) scales back the rescaled vectors
+        (Some((b1, scale_1)), Some((b2, scale_2))) => {
+            ContingencyTable::from_binary(b1, b2)
+                .unwrap()
+                .dist_cov()
+                .powi(2)
+                * scale_1
+                * scale_2
+        }
+        (Some((b1, scale_1)), None) => dist_cov_sq_one_binary_prepared(b1, v2) * scale_1,
+        (None, Some((b2, scale_2))) => dist_cov_sq_one_binary_prepared(b2, v1) * scale_2,
+        (None, None) => {
+            // permute v1 and its grand means with respect to the ordering of v2
+            let (v1_per, grand_means_v1_per): (Vec<f64>, Vec<f64>) = v2
+                .order
+                .iter()
+                .map(|&i| (v1.values[i], v1.grand_means[v1.ranks[i]]))
+                .unzip();
+
+            dist_cov_sq_helper(
+                &v1_per,
+                &v2.sorted,
+                &grand_means_v1_per,
+                &v2.grand_means,
+                v1.len(),
+            )
+        }
+    }
+}
+
+/// computes dCov^2 of the 0-1-valued vector v1 and the prepared vector v2
+fn dist_cov_sq_one_binary_prepared(v1: &[f64], v2: &PreparedVector) -> f64 {
+    // transform v1 to (-1)-1-values ordered with respect to v2
+    let v1_per: Vec<f64> = v2.order.iter().map(|&i| 2.0 * v1[i] - 1.0).collect();
+
+    let grand_means_v2_weighted = GrandMeans::new(&v2.sorted).compute_ordered_weighted(&v1_per);
+
+    dist_cov_sq_one_binary_helper(
+        &v1_per,
+        &v2.grand_means,
+        &grand_means_v2_weighted,
+        v1.len() as f64,
+    )
+    .max(0.0)
+}
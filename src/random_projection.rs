@@ -0,0 +1,219 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::RngExt;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::dist_corr::v_centered_inner_product;
+use crate::error::DistCorrError;
+use crate::frob_inner_product::compute_frobenius_inner_product;
+use crate::grand_mean::GrandMeans;
+use crate::ordering::Ordering;
+use crate::permutation_test::stream_rng;
+use crate::special_functions::ln_gamma;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Random-projection estimate of the multivariate distance covariance or correlation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomProjectionEstimate {
+    /// Estimated distance covariance (resp. distance correlation).
+    pub estimate: f64,
+    /// Jackknife standard error of `estimate` over the projections, `NaN` if `n_proj == 1`.
+    pub std_error: f64,
+    /// Number of random projections used.
+    pub n_proj: usize,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl RandomProjectionEstimate {
+    /// estimate propagated as `NaN` for n_proj projections
+    pub(crate) fn propagated(n_proj: usize) -> Self {
+        RandomProjectionEstimate {
+            estimate: f64::NAN,
+            std_error: f64::NAN,
+            n_proj,
+        }
+    }
+
+    /// evaluates `func` at the mean over all projections and computes its jackknife
+    /// standard error from the leave-one-out means
+    fn jackknife<const N: usize>(samples: &[[f64; N]], func: impl Fn(&[f64; N]) -> f64) -> Self {
+        let n_proj = samples.len();
+
+        let mut sums = [0.0; N];
+        for sample in samples {
+            for (sum, s) in sums.iter_mut().zip(sample) {
+                *sum += s;
+            }
+        }
+
+        let estimate = func(&sums.map(|sum| sum / n_proj as f64));
+
+        let std_error = if n_proj > 1 {
+            let leave_one_out: Vec<f64> = samples
+                .iter()
+                .map(|sample| {
+                    let mut means = sums;
+                    for (mean, s) in means.iter_mut().zip(sample) {
+                        *mean = (*mean - s) / (n_proj - 1) as f64;
+                    }
+                    func(&means)
+                })
+                .collect();
+
+            let mean = leave_one_out.iter().sum::<f64>() / n_proj as f64;
+            let sum_sq = leave_one_out
+                .iter()
+                .map(|t| (t - mean).powi(2))
+                .sum::<f64>();
+
+            ((n_proj - 1) as f64 / n_proj as f64 * sum_sq).sqrt()
+        } else {
+            f64::NAN
+        };
+
+        RandomProjectionEstimate {
+            estimate,
+            std_error,
+            n_proj,
+        }
+    }
+}
+
+/// estimates the distance covariance of the row-major samples x and y by random projections
+///
+/// Following Huang and Huo (2017), for directions `u` and `v` drawn uniformly from the unit
+/// spheres `E[C_p C_q dCov^2(<u, x>, <v, y>)] = dCov^2(x, y)`, where `C_p` is defined in
+/// [`projection_constant`]. Each projection costs a single univariate `O(n log n)` evaluation.
+pub(crate) fn dist_cov_random_projection(
+    x: &[f64],
+    dim_x: usize,
+    y: &[f64],
+    dim_y: usize,
+    n_proj: usize,
+    seed: u64,
+) -> Result<RandomProjectionEstimate, DistCorrError> {
+    let constant = projection_constant(dim_x) * projection_constant(dim_y);
+
+    let samples: Vec<[f64; 1]> = (0..n_proj)
+        .into_par_iter()
+        .map(|k| {
+            let mut rng = stream_rng(seed, k);
+            let x_u = project(x, &random_direction(dim_x, &mut rng));
+            let y_v = project(y, &random_direction(dim_y, &mut rng));
+
+            [constant * dist_cov_sq_unclamped(&x_u, &y_v)]
+        })
+        .collect();
+
+    Ok(RandomProjectionEstimate::jackknife(
+        &samples,
+        |[dist_cov_sq]| dist_cov_sq.max(0.0).sqrt(),
+    ))
+}
+
+/// estimates the distance correlation of the row-major samples x and y by random projections
+///
+/// The distance variances are estimated with pairs of independent directions `u, u'` via
+/// `E[C_p^2 dCov^2(<u, x>, <u', x>)] = dVar^2(x)`.
+pub(crate) fn dist_corr_random_projection(
+    x: &[f64],
+    dim_x: usize,
+    y: &[f64],
+    dim_y: usize,
+    n_proj: usize,
+    seed: u64,
+) -> Result<RandomProjectionEstimate, DistCorrError> {
+    let (constant_x, constant_y) = (projection_constant(dim_x), projection_constant(dim_y));
+
+    let samples: Vec<[f64; 3]> = (0..n_proj)
+        .into_par_iter()
+        .map(|k| {
+            let mut rng = stream_rng(seed, k);
+            let x_u = project(x, &random_direction(dim_x, &mut rng));
+            let y_v = project(y, &random_direction(dim_y, &mut rng));
+            let x_u_prime = project(x, &random_direction(dim_x, &mut rng));
+            let y_v_prime = project(y, &random_direction(dim_y, &mut rng));
+
+            [
+                constant_x * constant_y * dist_cov_sq_unclamped(&x_u, &y_v),
+                constant_x * constant_x * dist_cov_sq_unclamped(&x_u, &x_u_prime),
+                constant_y * constant_y * dist_cov_sq_unclamped(&y_v, &y_v_prime),
+            ]
+        })
+        .collect();
+
+    Ok(RandomProjectionEstimate::jackknife(
+        &samples,
+        |[dist_cov_sq, dist_var_sq_x, dist_var_sq_y]| {
+            if *dist_var_sq_x > 0.0 && *dist_var_sq_y > 0.0 {
+                (dist_cov_sq / (dist_var_sq_x * dist_var_sq_y).sqrt())
+                    .clamp(0.0, 1.0)
+                    .sqrt()
+            } else {
+                0.0
+            }
+        },
+    ))
+}
+
+/// `C_p = sqrt(pi) Gamma((p + 1) / 2) / Gamma(p / 2)`, such that `|x| = C_p E|<u, x>|` for
+/// `u` uniformly distributed on the unit sphere of dimension p
+fn projection_constant(dim: usize) -> f64 {
+    let dim = dim as f64;
+    std::f64::consts::PI.sqrt() * (ln_gamma((dim + 1.0) / 2.0) - ln_gamma(dim / 2.0)).exp()
+}
+
+/// direction uniformly distributed on the unit sphere, i.e. a normalized standard normal vector
+fn random_direction(dim: usize, rng: &mut ChaCha8Rng) -> Vec<f64> {
+    loop {
+        let direction: Vec<f64> = (0..dim).map(|_i| standard_normal(rng)).collect();
+        let norm = direction.iter().map(|d| d * d).sum::<f64>().sqrt();
+
+        if norm > 0.0 {
+            return direction.iter().map(|d| d / norm).collect();
+        }
+    }
+}
+
+/// standard normal random number by the Box-Muller transform
+fn standard_normal(rng: &mut ChaCha8Rng) -> f64 {
+    // u_1 in (0, 1] such that the logarithm is finite
+    let u_1 = 1.0 - rng.random::<f64>();
+    let u_2 = rng.random::<f64>();
+
+    (-2.0 * u_1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u_2).cos()
+}
+
+/// projects the rows of the row-major matrix x onto direction
+fn project(x: &[f64], direction: &[f64]) -> Vec<f64> {
+    x.chunks_exact(direction.len())
+        .map(|row| row.iter().zip(direction).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+/// computes dCov^2 of vectors v1 and v2 without clamping negative values,
+/// such that the average over projections stays unbiased
+fn dist_cov_sq_unclamped(v1: &[f64], v2: &[f64]) -> f64 {
+    let len = v1.len();
+
+    // sort v1,v2 with respect to ordering of v2
+    let Ordering {
+        v1_per,
+        v2_ord,
+        order_v1_per,
+    } = Ordering::order_wrt_v2(v1, v2, true);
+
+    // compute grand means of v1 and v2
+    let grand_means_v1 = GrandMeans::new(&v1_per).compute_unordered(order_v1_per.as_ref().unwrap());
+    let grand_means_v2 = GrandMeans::new(&v2_ord).compute_ordered();
+
+    let frob_prod_dist_mat = compute_frobenius_inner_product(&v1_per, &v2_ord, len);
+
+    v_centered_inner_product(frob_prod_dist_mat, &grand_means_v1, &grand_means_v2, len)
+}
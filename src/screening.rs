@@ -0,0 +1,139 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rayon::prelude::*;
+
+use crate::auto_detection::{BinaryDetection, BinaryVector};
+use crate::error::DistCorrError;
+use crate::nan_policy::{CompletePairs, NanPolicy};
+use crate::numeric::Numeric;
+use crate::permutation_test::{
+    dist_corr_both_binary_permutation_test, dist_corr_one_binary_permutation_test,
+    dist_corr_permutation_test,
+};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Rule deciding how many of the ranked features are retained by the screening.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScreeningCutoff {
+    /// Retain all features.
+    All,
+    /// Retain the `k` features with the largest distance correlation.
+    TopK(usize),
+    /// Retain the `[n / log(n)]` features with the largest distance correlation, where `n` denotes
+    /// the sample size, as proposed by Li, Zhong and Zhu (2012).
+    NOverLogN,
+}
+
+/// A feature retained by the screening.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScreenedFeature {
+    /// Index of the feature in the input collection.
+    pub index: usize,
+    /// Distance correlation between the feature and the target.
+    pub dist_corr: f64,
+    /// Permutation p-value of the distance correlation, if requested.
+    pub p_value: Option<f64>,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl ScreeningCutoff {
+    /// number of retained features out of num_features for sample size len
+    fn num_retained(&self, num_features: usize, len: usize) -> usize {
+        match self {
+            ScreeningCutoff::All => num_features,
+            ScreeningCutoff::TopK(k) => (*k).min(num_features),
+            ScreeningCutoff::NOverLogN => {
+                if len < 3 {
+                    num_features
+                } else {
+                    ((len as f64 / (len as f64).ln()).floor() as usize).min(num_features)
+                }
+            }
+        }
+    }
+}
+
+/// ranks the features by their distance correlation with the target (DC-SIS)
+///
+/// The distance correlations `dist_corr(feature)` are computed in parallel, after converting each
+/// feature to f64 within its own task, and features with a `NaN` distance correlation are ranked
+/// last. Ties are ranked by index. If `permutation` is `Some((n_perm, seed))` permutation p-values
+/// are attached to the retained features, computed on the complete pairs of feature and target
+/// with respect to nan_policy.
+/// Features must be of the same length as the non-empty target.
+pub(crate) fn dist_corr_screening<T, V, F>(
+    target: &[f64],
+    features: &[V],
+    cutoff: ScreeningCutoff,
+    permutation: Option<(usize, u64)>,
+    nan_policy: NanPolicy,
+    dist_corr: F,
+) -> Result<Vec<ScreenedFeature>, DistCorrError>
+where
+    T: Numeric,
+    V: AsRef<[T]> + Sync,
+    F: Fn(&[f64]) -> Result<f64, DistCorrError> + Sync,
+{
+    let mut ranked = features
+        .par_iter()
+        .enumerate()
+        .map(|(index, feature)| {
+            Ok(ScreenedFeature {
+                index,
+                dist_corr: dist_corr(&T::slice_to_f64(feature.as_ref()))?,
+                p_value: None,
+            })
+        })
+        .collect::<Result<Vec<ScreenedFeature>, DistCorrError>>()?;
+
+    // stable sort keeps ties in the order of the indices
+    let rank_key = |screened: &ScreenedFeature| {
+        if screened.dist_corr.is_nan() {
+            f64::NEG_INFINITY
+        } else {
+            screened.dist_corr
+        }
+    };
+    ranked.sort_by(|a, b| rank_key(b).total_cmp(&rank_key(a)));
+    ranked.truncate(cutoff.num_retained(features.len(), target.len()));
+
+    if let Some((n_perm, seed)) = permutation {
+        for screened in ranked.iter_mut() {
+            let feature = T::slice_to_f64(features[screened.index].as_ref());
+            let (feature, target) = match nan_policy.apply(&feature, target)? {
+                CompletePairs::Pairs(feature, target) => (feature, target),
+                CompletePairs::Propagated => {
+                    screened.p_value = Some(f64::NAN);
+                    continue;
+                }
+            };
+
+            let binary = |v| BinaryVector::detect(v, BinaryDetection::TwoValued);
+
+            let test = match (binary(&feature), binary(&target)) {
+                (Some(feature), Some(target)) => dist_corr_both_binary_permutation_test(
+                    &feature.values,
+                    &target.values,
+                    n_perm,
+                    seed,
+                ),
+                (Some(feature), None) => {
+                    dist_corr_one_binary_permutation_test(&feature.values, &target, n_perm, seed)
+                }
+                (None, Some(target)) => {
+                    dist_corr_one_binary_permutation_test(&target.values, &feature, n_perm, seed)
+                }
+                (None, None) => dist_corr_permutation_test(&feature, &target, n_perm, seed),
+            }?;
+
+            screened.p_value = Some(test.p_value);
+        }
+    }
+
+    Ok(ranked)
+}
@@ -0,0 +1,212 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Definition
+
+/// coefficients of the Lanczos approximation with g = 7, n = 9
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEF: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// maximal number of iterations of the series and continued fraction expansions
+const MAX_ITER: usize = 10_000;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+/// computes ln Gamma(x) for x > 0 with the Lanczos approximation
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // reflection formula
+        (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).ln() - ln_gamma(1.0 - x)
+    } else {
+        let x = x - 1.0;
+        let a = LANCZOS_COEF[1..]
+            .iter()
+            .enumerate()
+            .fold(LANCZOS_COEF[0], |acc, (i, c)| {
+                acc + c / (x + (i + 1) as f64)
+            });
+        let t = x + LANCZOS_G + 0.5;
+
+        0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+    }
+}
+
+/// computes ln (n choose k)
+pub(crate) fn ln_choose(n: usize, k: usize) -> f64 {
+    ln_gamma((n + 1) as f64) - ln_gamma((k + 1) as f64) - ln_gamma((n - k + 1) as f64)
+}
+
+/// computes the regularized upper incomplete gamma function
+///
+/// ```text
+/// Q(a, x) = 1/Gamma(a) int_x^infty t^(a-1) e^(-t) dt
+/// ```
+///
+/// using the series expansion of P = 1 - Q for x < a + 1 and the continued
+/// fraction of Q otherwise.
+pub(crate) fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+
+    let ln_prefactor = a * x.ln() - x - ln_gamma(a);
+
+    if x < a + 1.0 {
+        // series expansion of P(a, x)
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITER {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * f64::EPSILON {
+                break;
+            }
+        }
+        (1.0 - sum * ln_prefactor.exp()).clamp(0.0, 1.0)
+    } else {
+        // modified Lentz algorithm for the continued fraction of Q(a, x)
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / f64::MIN_POSITIVE;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for n in 1..MAX_ITER {
+            let an = -(n as f64) * (n as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < f64::MIN_POSITIVE {
+                d = f64::MIN_POSITIVE;
+            }
+            c = b + an / c;
+            if c.abs() < f64::MIN_POSITIVE {
+                c = f64::MIN_POSITIVE;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < f64::EPSILON {
+                break;
+            }
+        }
+        (ln_prefactor.exp() * h).clamp(0.0, 1.0)
+    }
+}
+
+/// computes P(X >= x) for a chi-square distributed X with `df` degrees of freedom
+pub(crate) fn chi_square_sf(x: f64, df: f64) -> f64 {
+    gamma_q(0.5 * df, 0.5 * x)
+}
+
+/// computes the regularized incomplete beta function
+///
+/// ```text
+/// I_x(a, b) = 1/B(a, b) int_0^x t^(a-1) (1-t)^(b-1) dt
+/// ```
+///
+/// using the continued fraction for x < (a + 1) / (a + b + 2) and the symmetry
+/// I_x(a, b) = 1 - I_(1-x)(b, a) otherwise.
+pub(crate) fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let ln_prefactor =
+        a * x.ln() + b * (-x).ln_1p() - (ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b));
+
+    if x < (a + 1.0) / (a + b + 2.0) {
+        (ln_prefactor.exp() * beta_continued_fraction(a, b, x) / a).clamp(0.0, 1.0)
+    } else {
+        (1.0 - ln_prefactor.exp() * beta_continued_fraction(b, a, 1.0 - x) / b).clamp(0.0, 1.0)
+    }
+}
+
+/// modified Lentz algorithm for the continued fraction of the incomplete beta function
+///
+/// The number of iterations grows like sqrt(max(a, b)), hence the iteration limit is
+/// scaled accordingly.
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let max_iter = MAX_ITER.max(10 * a.max(b).sqrt() as usize);
+
+    let tiny = |d: f64| {
+        if d.abs() < f64::MIN_POSITIVE {
+            f64::MIN_POSITIVE
+        } else {
+            d
+        }
+    };
+
+    let mut c = 1.0;
+    let mut d = 1.0 / tiny(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..max_iter {
+        let m = m as f64;
+
+        // even step
+        let an = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / tiny(1.0 + an * d);
+        c = tiny(1.0 + an / c);
+        h *= d * c;
+
+        // odd step
+        let an = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / tiny(1.0 + an * d);
+        c = tiny(1.0 + an / c);
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// computes P(Z >= z) for a standard normal distributed Z
+pub(crate) fn normal_sf(z: f64) -> f64 {
+    let tail = 0.5 * gamma_q(0.5, 0.5 * z * z);
+
+    if z > 0.0 {
+        tail
+    } else {
+        1.0 - tail
+    }
+}
+
+/// computes P(T >= t) for a Student t distributed T with `df` degrees of freedom
+///
+/// For `df > 1e6` the incomplete beta function loses precision, instead the expansion
+///
+/// ```text
+/// P(T >= t) = P(Z >= t) + phi(t) (t^3 + t) / (4 df) + O(df^-2)
+/// ```
+///
+/// around the standard normal distribution is used.
+pub(crate) fn student_t_sf(t: f64, df: f64) -> f64 {
+    if t.is_infinite() {
+        return if t > 0.0 { 0.0 } else { 1.0 };
+    }
+
+    if df > 1e6 {
+        let density = (-0.5 * t * t).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        return (normal_sf(t) + density * (t.powi(3) + t) / (4.0 * df)).clamp(0.0, 1.0);
+    }
+
+    let tail = 0.5 * incomplete_beta(0.5 * df, 0.5, df / (df + t * t));
+
+    if t > 0.0 {
+        tail
+    } else {
+        1.0 - tail
+    }
+}
@@ -0,0 +1,246 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use std::ops::{Mul, Neg};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Definition
+
+/// strategy for the accumulation of sums
+///
+/// The implementation for `f64` performs the plain operations in the order of the original
+/// expressions, such that generic code gives bit-identical results to the non-generic one. The
+/// implementation for `i128` accumulates integer values exactly.
+pub(crate) trait Summation: Copy + Default + Send + Sync {
+    /// type of the summands
+    type Value: Copy
+        + PartialOrd
+        + Neg<Output = Self::Value>
+        + Mul<Output = Self::Value>
+        + Send
+        + Sync;
+
+    /// the count as a summand
+    fn from_count(count: usize) -> Self::Value;
+
+    /// returns self + x
+    fn add(self, x: Self::Value) -> Self;
+
+    /// returns self + other
+    fn add_sum(self, other: Self) -> Self;
+
+    /// returns self + x * y
+    fn add_product(self, x: Self::Value, y: Self::Value) -> Self;
+
+    /// returns self + other * c
+    fn add_scaled(self, other: Self, c: Self::Value) -> Self;
+
+    /// returns self + (a - b)
+    fn add_difference(self, a: Self, b: Self) -> Self;
+
+    /// returns self * c
+    fn scale(self, c: Self::Value) -> Self;
+
+    /// the accumulated value
+    fn value(self) -> Self::Value;
+}
+
+/// compensated sum of Neumaier, which keeps track of the rounding errors of the additions and of
+/// the products in a separate compensation term
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Compensated {
+    sum: f64,
+    compensation: f64,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl Summation for f64 {
+    type Value = f64;
+
+    #[inline]
+    fn from_count(count: usize) -> f64 {
+        count as f64
+    }
+
+    #[inline]
+    fn add(self, x: f64) -> Self {
+        self + x
+    }
+
+    #[inline]
+    fn add_sum(self, other: Self) -> Self {
+        self + other
+    }
+
+    #[inline]
+    fn add_product(self, x: f64, y: f64) -> Self {
+        x * y + self
+    }
+
+    #[inline]
+    fn add_scaled(self, other: Self, c: f64) -> Self {
+        self + other * c
+    }
+
+    #[inline]
+    fn add_difference(self, a: Self, b: Self) -> Self {
+        self + (a - b)
+    }
+
+    #[inline]
+    fn scale(self, c: f64) -> Self {
+        self * c
+    }
+
+    #[inline]
+    fn value(self) -> f64 {
+        self
+    }
+}
+
+impl Summation for Compensated {
+    type Value = f64;
+
+    #[inline]
+    fn from_count(count: usize) -> f64 {
+        count as f64
+    }
+
+    #[inline]
+    fn add(self, x: f64) -> Self {
+        let sum = self.sum + x;
+
+        // the rounding error of the addition is exact
+        let error = if self.sum.abs() >= x.abs() {
+            (self.sum - sum) + x
+        } else {
+            (x - sum) + self.sum
+        };
+
+        Compensated {
+            sum,
+            compensation: self.compensation + error,
+        }
+    }
+
+    #[inline]
+    fn add_sum(self, other: Self) -> Self {
+        let Compensated { sum, compensation } = self.add(other.sum);
+
+        Compensated {
+            sum,
+            compensation: compensation + other.compensation,
+        }
+    }
+
+    #[inline]
+    fn add_product(self, x: f64, y: f64) -> Self {
+        let product = x * y;
+        // the rounding error of the product is exact with a fused multiply-add
+        let error = x.mul_add(y, -product);
+        let Compensated { sum, compensation } = self.add(product);
+
+        Compensated {
+            sum,
+            compensation: compensation + error,
+        }
+    }
+
+    #[inline]
+    fn add_scaled(self, other: Self, c: f64) -> Self {
+        let Compensated { sum, compensation } = self.add_product(other.sum, c);
+
+        Compensated {
+            sum,
+            compensation: compensation + other.compensation * c,
+        }
+    }
+
+    #[inline]
+    fn add_difference(self, a: Self, b: Self) -> Self {
+        self.add_sum(a).add_sum(-b)
+    }
+
+    #[inline]
+    fn scale(self, c: f64) -> Self {
+        Compensated::default().add_scaled(self, c)
+    }
+
+    #[inline]
+    fn value(self) -> f64 {
+        self.sum + self.compensation
+    }
+}
+
+impl Summation for i128 {
+    type Value = i128;
+
+    #[inline]
+    fn from_count(count: usize) -> i128 {
+        count as i128
+    }
+
+    #[inline]
+    fn add(self, x: i128) -> Self {
+        self + x
+    }
+
+    #[inline]
+    fn add_sum(self, other: Self) -> Self {
+        self + other
+    }
+
+    #[inline]
+    fn add_product(self, x: i128, y: i128) -> Self {
+        self + x * y
+    }
+
+    #[inline]
+    fn add_scaled(self, other: Self, c: i128) -> Self {
+        self + other * c
+    }
+
+    #[inline]
+    fn add_difference(self, a: Self, b: Self) -> Self {
+        self + (a - b)
+    }
+
+    #[inline]
+    fn scale(self, c: i128) -> Self {
+        self * c
+    }
+
+    #[inline]
+    fn value(self) -> i128 {
+        self
+    }
+}
+
+impl Neg for Compensated {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Compensated {
+            sum: -self.sum,
+            compensation: -self.compensation,
+        }
+    }
+}
+
+/// compensated sum of the values
+pub(crate) fn compensated_sum<'a>(values: impl IntoIterator<Item = &'a f64>) -> Compensated {
+    values
+        .into_iter()
+        .fold(Compensated::default(), |sum, &x| sum.add(x))
+}
+
+/// compensated dot product of the values
+pub(crate) fn compensated_dot(v1: &[f64], v2: &[f64]) -> Compensated {
+    v1.iter()
+        .zip(v2)
+        .fold(Compensated::default(), |sum, (&x, &y)| {
+            sum.add_product(x, y)
+        })
+}
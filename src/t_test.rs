@@ -0,0 +1,74 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use crate::dist_corr_bias_corrected::BiasCorrectedDistCorr;
+use crate::error::DistCorrError;
+use crate::special_functions::student_t_sf;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Struct
+
+/// Result of the distance correlation t-test of independence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistCorrTTest {
+    /// t statistic `sqrt(df) * R / sqrt(1 - R^2)` of the bias-corrected squared distance correlation `R`.
+    pub statistic: f64,
+    /// Degrees of freedom `n(n-3)/2 - 1`.
+    pub df: f64,
+    /// p-value `P(T >= statistic)` of the Student t distribution with `df` degrees of freedom.
+    pub p_value: f64,
+    /// Bias-corrected squared distance correlation `R`, unclamped.
+    pub dist_corr_sq: f64,
+}
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Implementation
+
+impl DistCorrTTest {
+    /// test propagated as `NaN` from len observations
+    pub(crate) fn propagated(len: usize) -> Self {
+        let len = len as f64;
+
+        DistCorrTTest {
+            statistic: f64::NAN,
+            df: len * (len - 3.0) / 2.0 - 1.0,
+            p_value: f64::NAN,
+            dist_corr_sq: f64::NAN,
+        }
+    }
+
+    /// computes the t-test from the bias-corrected distance correlation
+    ///
+    /// Under independence `sqrt(df) * R / sqrt(1 - R^2)` is approximately t distributed
+    /// with `df = n(n-3)/2 - 1` degrees of freedom as the dimensions of the samples grow
+    /// (Székely and Rizzo, 2013). A propagated `NaN` is passed on, and fails if one of the
+    /// samples is constant.
+    pub(crate) fn from_bias_corrected(
+        bias_corrected: &BiasCorrectedDistCorr,
+    ) -> Result<Self, DistCorrError> {
+        if bias_corrected.dist_corr_sq.is_nan() {
+            return Ok(DistCorrTTest::propagated(bias_corrected.len));
+        }
+
+        if bias_corrected.dist_var_sq_v1 <= 0.0 || bias_corrected.dist_var_sq_v2 <= 0.0 {
+            return Err(DistCorrError::DegenerateVariance);
+        }
+
+        let len = bias_corrected.len as f64;
+        let df = len * (len - 3.0) / 2.0 - 1.0;
+        let dist_corr_sq = bias_corrected.dist_corr_sq;
+
+        let statistic = if dist_corr_sq.abs() < 1.0 {
+            df.sqrt() * dist_corr_sq / (1.0 - dist_corr_sq * dist_corr_sq).sqrt()
+        } else {
+            dist_corr_sq.signum() * f64::INFINITY
+        };
+
+        Ok(DistCorrTTest {
+            statistic,
+            df,
+            p_value: student_t_sf(statistic, df),
+            dist_corr_sq,
+        })
+    }
+}
@@ -1,8 +1,48 @@
 #[cfg(test)]
 mod test;
 #[cfg(test)]
+mod test_accurate;
+#[cfg(test)]
+mod test_auto;
+#[cfg(test)]
+mod test_bias_corrected;
+#[cfg(test)]
 mod test_binary;
 #[cfg(test)]
+mod test_builder;
+#[cfg(test)]
+mod test_categorical;
+#[cfg(test)]
+mod test_details;
+#[cfg(test)]
 mod test_determinism;
 #[cfg(test)]
+mod test_error;
+#[cfg(test)]
+mod test_exact;
+#[cfg(test)]
+mod test_exact_arithmetic;
+#[cfg(test)]
+mod test_exponent;
+#[cfg(test)]
+mod test_matrix;
+#[cfg(test)]
 mod test_multi;
+#[cfg(test)]
+mod test_nan_policy;
+#[cfg(all(test, feature = "ndarray"))]
+mod test_ndarray;
+#[cfg(test)]
+mod test_numeric;
+#[cfg(test)]
+mod test_partial;
+#[cfg(test)]
+mod test_permutation;
+#[cfg(test)]
+mod test_prepared;
+#[cfg(test)]
+mod test_random_projection;
+#[cfg(test)]
+mod test_screening;
+#[cfg(test)]
+mod test_t_test;
@@ -0,0 +1,186 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::config::Estimator;
+use crate::dist_corr_naive::{_dist_cov_sq_exact, _dist_cov_sq_u_exact};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// grid width of the test data, such that offset + k * STEP is exact
+const STEP: f64 = 1.0 / 1024.0;
+
+/// integer data and the corresponding exactly representable values offset + k * STEP
+fn grid_data(seed: u64, len: usize, offset_1: f64, offset_2: f64) -> [(Vec<i64>, Vec<f64>); 2] {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let k1: Vec<i64> = (0..len).map(|_| rng.random_range(0..1 << 16)).collect();
+    let k2: Vec<i64> = k1
+        .iter()
+        .map(|k| k * k / (1 << 17) + rng.random_range(0..1 << 14))
+        .collect();
+
+    let values = |k: &[i64], offset: f64| -> Vec<f64> {
+        k.iter().map(|&k| offset + k as f64 * STEP).collect()
+    };
+    let v1 = values(&k1, offset_1);
+    let v2 = values(&k2, offset_2);
+
+    [(k1, v1), (k2, v2)]
+}
+
+fn relative_error(value: f64, exact: f64) -> f64 {
+    ((value - exact) / exact).abs()
+}
+
+/// for well-conditioned data the accuracy mode agrees with the default computations
+#[test]
+fn agrees_with_default() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let v1: Vec<f64> = (0..5_000).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1
+        .iter()
+        .map(|x| (3.0 * x).sin() + rng.random::<f64>())
+        .collect();
+    let v_bin: Vec<f64> = v1.iter().map(|&x| (x > 0.3) as u8 as f64).collect();
+
+    for estimator in [Estimator::Standard, Estimator::BiasCorrected] {
+        let dist_corr = DistCorrelation::builder().estimator(estimator).build();
+        let dist_cov = DistCovariance::builder().estimator(estimator).build();
+        let accurate_corr = DistCorrelation::builder()
+            .estimator(estimator)
+            .accurate(true)
+            .build();
+        let accurate_cov = DistCovariance::builder()
+            .estimator(estimator)
+            .accurate(true)
+            .build();
+        assert!(accurate_corr.accurate() && accurate_cov.accurate());
+
+        for (v_a, v_b) in [(&v1, &v2), (&v_bin, &v2)] {
+            let expected = dist_corr.compute(v_a, v_b).unwrap();
+            let result = accurate_corr.compute(v_a, v_b).unwrap();
+            assert!(relative_error(result, expected) < 1e-12);
+
+            let expected = dist_cov.compute(v_a, v_b).unwrap();
+            let result = accurate_cov.compute(v_a, v_b).unwrap();
+            assert!(relative_error(result, expected) < 1e-12);
+
+            let expected = dist_cov.compute_var(v_a).unwrap();
+            let result = accurate_cov.compute_var(v_a).unwrap();
+            assert!(relative_error(result, expected) < 1e-12);
+        }
+
+        // binary flags only validate the inputs
+        let expected = accurate_corr.compute(&v_bin, &v2).unwrap();
+        let result = accurate_corr
+            .compute_binary(&v_bin, &v2, true, false)
+            .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    // exponents other than one are centered
+    let dist_corr = DistCorrelation::builder().exponent(0.5).build();
+    let accurate_corr = DistCorrelation::builder()
+        .exponent(0.5)
+        .accurate(true)
+        .build();
+    let expected = dist_corr.compute(&v1[..500], &v2[..500]).unwrap();
+    let result = accurate_corr.compute(&v1[..500], &v2[..500]).unwrap();
+    assert!(relative_error(result, expected) < 1e-12);
+}
+
+/// with a large offset the accuracy mode matches the exact reference, whereas the default
+/// computation loses all digits of the distance variance
+#[test]
+fn offset_exact_reference() {
+    let [(k1, v1), (k2, v2)] = grid_data(1, 2_000, 1.7e9, -3.0e8);
+
+    let scale = STEP * STEP;
+    let dist_cov_sq = _dist_cov_sq_exact(&k1, &k2) * scale;
+    let dist_var_sq_v1 = _dist_cov_sq_exact(&k1, &k1) * scale;
+    let dist_var_sq_v2 = _dist_cov_sq_exact(&k2, &k2) * scale;
+    let dist_corr = (dist_cov_sq / (dist_var_sq_v1 * dist_var_sq_v2).sqrt()).sqrt();
+
+    let details = DistCorrelation::builder()
+        .accurate(true)
+        .build()
+        .compute_detailed(&v1, &v2, false, false)
+        .unwrap();
+    assert!(relative_error(details.dist_cov_sq, dist_cov_sq) < 1e-12);
+    assert!(relative_error(details.dist_var_sq_v1, dist_var_sq_v1) < 1e-12);
+    assert!(relative_error(details.dist_var_sq_v2, dist_var_sq_v2) < 1e-12);
+    assert!(relative_error(details.dist_corr, dist_corr) < 1e-12);
+    assert!(!details.clamped && !details.degenerate);
+
+    let default = DistCorrelation
+        .compute_detailed(&v1, &v2, false, false)
+        .unwrap();
+    assert!(relative_error(default.dist_var_sq_v1, dist_var_sq_v1) > 1e-3);
+
+    // the bias-corrected estimator
+    let dist_cov_sq_u = _dist_cov_sq_u_exact(&k1, &k2) * scale;
+    let dist_var_sq_u_v1 = _dist_cov_sq_u_exact(&k1, &k1) * scale;
+
+    let details = DistCorrelation::builder()
+        .estimator(Estimator::BiasCorrected)
+        .accurate(true)
+        .build()
+        .compute_detailed(&v1, &v2, false, false)
+        .unwrap();
+    assert!(relative_error(details.dist_cov_sq, dist_cov_sq_u) < 1e-12);
+    assert!(relative_error(details.dist_var_sq_v1, dist_var_sq_u_v1) < 1e-12);
+
+    let dist_cov = DistCovariance::builder().accurate(true).build();
+    assert!(relative_error(dist_cov.compute(&v1, &v2).unwrap(), dist_cov_sq.sqrt()) < 1e-12);
+    assert!(relative_error(dist_cov.compute_var(&v1).unwrap(), dist_var_sq_v1.sqrt()) < 1e-12);
+}
+
+/// for weakly dependent data of the same offset the small dCov² is still resolved
+#[test]
+fn weak_dependence_exact_reference() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2);
+    let len = 3_000;
+    let k1: Vec<i64> = (0..len).map(|_| rng.random_range(0..1 << 16)).collect();
+    let k2: Vec<i64> = (0..len).map(|_| rng.random_range(0..1 << 16)).collect();
+    let v1: Vec<f64> = k1.iter().map(|&k| 1e9 + k as f64 * STEP).collect();
+    let v2: Vec<f64> = k2.iter().map(|&k| 1e9 + k as f64 * STEP).collect();
+
+    let dist_cov_sq = _dist_cov_sq_exact(&k1, &k2) * STEP * STEP;
+
+    let details = DistCorrelation::builder()
+        .accurate(true)
+        .build()
+        .compute_detailed(&v1, &v2, false, false)
+        .unwrap();
+    assert!(relative_error(details.dist_cov_sq, dist_cov_sq) < 1e-10);
+}
+
+/// for a large sample, where the exact O(n^2) reference is infeasible, the accuracy mode does not
+/// depend on the offset, whereas the default computation with offset is dominated by cancellation
+#[test]
+fn large_sample() {
+    let [(_, v1), (_, v2)] = grid_data(3, 300_000, 1.7e9, 5e8);
+    let [(_, u1), (_, u2)] = grid_data(3, 300_000, 0.0, 0.0);
+
+    let dist_corr = DistCorrelation::builder().accurate(true).build();
+    let expected = dist_corr.compute_detailed(&u1, &u2, false, false).unwrap();
+    let details = dist_corr.compute_detailed(&v1, &v2, false, false).unwrap();
+
+    assert!(relative_error(details.dist_cov_sq, expected.dist_cov_sq) < 1e-12);
+    assert!(relative_error(details.dist_var_sq_v1, expected.dist_var_sq_v1) < 1e-12);
+    assert!(relative_error(details.dist_corr, expected.dist_corr) < 1e-12);
+
+    let default = DistCorrelation
+        .compute_detailed(&u1, &u2, false, false)
+        .unwrap();
+    assert!(relative_error(default.dist_corr, expected.dist_corr) < 1e-9);
+
+    let default_offset = DistCorrelation
+        .compute_detailed(&v1, &v2, false, false)
+        .unwrap();
+    assert!(relative_error(default_offset.dist_var_sq_v1, expected.dist_var_sq_v1) > 1e-3);
+}
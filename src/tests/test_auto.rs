@@ -0,0 +1,158 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::auto_detection::{Algorithm, BinaryDetection};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn algorithm_choice() {
+    let (v_real, v_bin, v_two) = sample(500, 1);
+
+    let cases = [
+        (
+            &v_real,
+            &v_real,
+            BinaryDetection::ZeroOne,
+            Algorithm::General,
+        ),
+        (
+            &v_bin,
+            &v_real,
+            BinaryDetection::ZeroOne,
+            Algorithm::OneBinary,
+        ),
+        (
+            &v_real,
+            &v_bin,
+            BinaryDetection::ZeroOne,
+            Algorithm::OneBinary,
+        ),
+        (
+            &v_bin,
+            &v_bin,
+            BinaryDetection::ZeroOne,
+            Algorithm::BothBinary,
+        ),
+        (
+            &v_two,
+            &v_real,
+            BinaryDetection::ZeroOne,
+            Algorithm::General,
+        ),
+        (
+            &v_two,
+            &v_real,
+            BinaryDetection::TwoValued,
+            Algorithm::OneBinary,
+        ),
+        (
+            &v_two,
+            &v_bin,
+            BinaryDetection::TwoValued,
+            Algorithm::BothBinary,
+        ),
+        (
+            &v_real,
+            &v_real,
+            BinaryDetection::TwoValued,
+            Algorithm::General,
+        ),
+    ];
+
+    for (v1, v2, detection, algorithm) in cases {
+        let dist_corr = DistCorrelation.compute_auto(v1, v2, detection).unwrap();
+        let dist_cov = DistCovariance.compute_auto(v1, v2, detection).unwrap();
+
+        println!("{:?} {:?}: {:?}", detection, algorithm, dist_corr);
+
+        assert_eq!(dist_corr.algorithm, algorithm);
+        assert_eq!(dist_cov.algorithm, algorithm);
+        assert!((dist_corr.value - DistCorrelation.compute(v1, v2).unwrap()).abs() < 1e-10);
+        assert!((dist_cov.value - DistCovariance.compute(v1, v2).unwrap()).abs() < 1e-10);
+    }
+}
+
+/// dCov scales with the square root of the distance of the two values
+#[test]
+fn rescaling() {
+    let (v_real, v_bin, _v_two) = sample(300, 2);
+
+    for (low, high) in [(-1.0, 1.0), (1.0, 2.0), (3.0, -7.5)] {
+        let v_two: Vec<f64> = v_bin
+            .iter()
+            .map(|x| if *x == 1.0 { high } else { low })
+            .collect();
+
+        let dist_cov = DistCovariance
+            .compute_auto(&v_two, &v_real, BinaryDetection::TwoValued)
+            .unwrap();
+        let dist_cov_bin = DistCovariance.compute(&v_bin, &v_real).unwrap();
+
+        assert!((dist_cov.value - dist_cov_bin * f64::abs(high - low).sqrt()).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn constant() {
+    let v_const = vec![2.0; 10];
+    let v_zeros = vec![0.0; 10];
+    let v_real: Vec<f64> = (0..10).map(|i| i as f64).collect();
+
+    let result = DistCorrelation
+        .compute_auto(&v_zeros, &v_real, BinaryDetection::ZeroOne)
+        .unwrap();
+    assert_eq!(result.algorithm, Algorithm::OneBinary);
+    assert_eq!(result.value, 0.0);
+
+    let result = DistCorrelation
+        .compute_auto(&v_const, &v_real, BinaryDetection::TwoValued)
+        .unwrap();
+    assert_eq!(result.algorithm, Algorithm::OneBinary);
+    assert_eq!(result.value, 0.0);
+
+    let result = DistCovariance
+        .compute_auto(&v_const, &v_real, BinaryDetection::ZeroOne)
+        .unwrap();
+    assert_eq!(result.algorithm, Algorithm::General);
+    assert_eq!(result.value, 0.0);
+}
+
+#[test]
+fn errors() {
+    let dist_correlation = DistCorrelation;
+
+    assert!(dist_correlation
+        .compute_auto(&[1.0, 2.0], &[1.0], BinaryDetection::ZeroOne)
+        .is_err());
+    assert!(dist_correlation
+        .compute_auto(&[0.0; 0], &[0.0; 0], BinaryDetection::TwoValued)
+        .is_err());
+    assert!(DistCovariance
+        .compute_auto(&[0.0; 0], &[0.0; 0], BinaryDetection::ZeroOne)
+        .is_err());
+}
+
+/// real, 0-1-valued and two-valued vectors
+fn sample(sample_size: usize, seed: u64) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let v_real: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let v_bin: Vec<f64> = v_real
+        .iter()
+        .map(|x| if x.abs() < 4.0 { 1.0 } else { 0.0 })
+        .collect();
+    let v_two: Vec<f64> = v_real
+        .iter()
+        .map(|x| if *x < 2.0 { -3.0 } else { 5.0 })
+        .collect();
+
+    (v_real, v_bin, v_two)
+}
@@ -0,0 +1,137 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::dist_corr_naive::_dist_cov_sq_u_naive;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn quadratic_relation() {
+    let test_sizes = [4, 5, 2_i32.pow(4), 111, 2_i32.pow(9)];
+
+    for numb in test_sizes {
+        sub_test(numb as usize, 13, |x| x * x);
+    }
+}
+
+#[test]
+fn sin() {
+    let test_sizes = [7, 121, 597];
+
+    for numb in test_sizes {
+        sub_test(numb as usize, 21, |x| x.sin());
+    }
+}
+
+#[test]
+fn ties() {
+    let test_sizes = [6, 100, 333];
+
+    for numb in test_sizes {
+        sub_test(numb as usize, 3, |x| x.round().abs());
+    }
+}
+
+#[test]
+fn independent() {
+    let sample_size = 500;
+    let mut rng_1 = ChaCha8Rng::seed_from_u64(134);
+    let mut rng_2 = ChaCha8Rng::seed_from_u64(11);
+
+    let v1: Vec<f64> = (0..sample_size)
+        .map(move |_x| rng_1.random_range(-10.0..10.0))
+        .collect();
+    let v2: Vec<f64> = (0..sample_size)
+        .map(move |_x| rng_2.random_range(-10.0..10.0))
+        .collect();
+
+    let dist_correlation = DistCorrelation;
+    let dist_corr = dist_correlation.compute(&v1, &v2).unwrap();
+    let bias_corrected = dist_correlation.compute_bias_corrected(&v1, &v2).unwrap();
+
+    println!("Dist corr: {:?}", dist_corr);
+    println!("Bias-corrected: {:?}", bias_corrected);
+
+    assert!(bias_corrected.dist_corr_sq.abs() < 0.01);
+    assert!(bias_corrected.dist_corr_sq < dist_corr.powi(2));
+    assert_eq!(
+        bias_corrected.dist_corr,
+        bias_corrected.dist_corr_sq.clamp(0.0, 1.0).sqrt()
+    );
+}
+
+#[test]
+fn linear_relation() {
+    let v1: Vec<f64> = vec![1.0, -2.0, 3.0, 0.5, 7.0, 2.0];
+    let v2: Vec<f64> = v1.iter().map(|x| -3.0 * x + 1.0).collect();
+
+    let bias_corrected = DistCorrelation.compute_bias_corrected(&v1, &v2).unwrap();
+
+    assert!((bias_corrected.dist_corr_sq - 1.0).abs() < 1e-12);
+    assert!((bias_corrected.dist_corr - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn constant() {
+    let v1: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    let v2: Vec<f64> = vec![1.0; 5];
+
+    let bias_corrected = DistCorrelation.compute_bias_corrected(&v1, &v2).unwrap();
+
+    assert_eq!(bias_corrected.dist_corr_sq, 0.0);
+    assert_eq!(bias_corrected.dist_corr, 0.0);
+}
+
+#[test]
+fn errors() {
+    let dist_correlation = DistCorrelation;
+    let dist_covariance = DistCovariance;
+
+    assert!(dist_correlation
+        .compute_bias_corrected(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0])
+        .is_err());
+    assert!(dist_correlation
+        .compute_bias_corrected(&[1.0, 2.0, 3.0, 4.0], &[1.0, 2.0, 3.0])
+        .is_err());
+    assert!(dist_covariance
+        .compute_bias_corrected(&[0.0; 0], &[0.0; 0])
+        .is_err());
+    assert!(dist_covariance
+        .compute_bias_corrected(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0])
+        .is_err());
+}
+
+fn sub_test(sample_size: usize, seed: u64, func: fn(&f64) -> f64) {
+    println!("------------------------");
+    println!("Sample size: {:?}", sample_size);
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let v1: Vec<f64> = (0..sample_size)
+        .map(move |_x| rng.random_range(-10.0..10.0))
+        .collect();
+
+    let v2: Vec<f64> = v1.iter().map(func).collect();
+
+    let bias_corrected = DistCorrelation.compute_bias_corrected(&v1, &v2).unwrap();
+    let dist_cov_sq = DistCovariance.compute_bias_corrected(&v1, &v2).unwrap();
+
+    let dist_cov_sq_naive = _dist_cov_sq_u_naive(&v1, &v2);
+    let dist_var_sq_v1_naive = _dist_cov_sq_u_naive(&v1, &v1);
+    let dist_var_sq_v2_naive = _dist_cov_sq_u_naive(&v2, &v2);
+    let dist_corr_sq_naive =
+        dist_cov_sq_naive / (dist_var_sq_v1_naive * dist_var_sq_v2_naive).sqrt();
+
+    println!("Bias-corrected: {:?}", bias_corrected);
+    println!("Bias-corrected naive: {:?}", dist_corr_sq_naive);
+
+    let scale = dist_var_sq_v1_naive.max(dist_var_sq_v2_naive).max(1.0);
+    assert!((dist_cov_sq - dist_cov_sq_naive).abs() < 1e-10 * scale);
+    assert!((bias_corrected.dist_cov_sq - dist_cov_sq_naive).abs() < 1e-10 * scale);
+    assert!((bias_corrected.dist_var_sq_v1 - dist_var_sq_v1_naive).abs() < 1e-10 * scale);
+    assert!((bias_corrected.dist_var_sq_v2 - dist_var_sq_v2_naive).abs() < 1e-10 * scale);
+    assert!((bias_corrected.dist_corr_sq - dist_corr_sq_naive).abs() < 1e-8);
+}
@@ -7,6 +7,7 @@ use std::time::Instant;
 
 use crate::api::{DistCorrelation, DistCovariance};
 use crate::dist_corr_naive::_dist_cov_sq_naive;
+use crate::prepared_vector::PreparedVector;
 
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Tests
@@ -206,3 +207,171 @@ fn hard_one_binary() {
 
     assert!((dist_cov - dist_cov_binary).abs() < 1e-10);
 }
+
+#[test]
+fn two_valued_binary() {
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+
+    let v_real: Vec<f64> = (0..500).map(|_x| rng.random_range(-5.0..5.0)).collect();
+    let v_bool: Vec<bool> = v_real.iter().map(|x| x.abs() < 2.0).collect();
+    let v_other: Vec<f64> = (0..500)
+        .map(|i| if v_bool[i] || i % 3 == 0 { 2.0 } else { 1.0 })
+        .collect();
+
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    for (low, high) in [(0.0, 1.0), (-1.0, 1.0), (1.0, 2.0), (3.0, -7.5)] {
+        let v1: Vec<f64> = v_bool.iter().map(|&x| if x { high } else { low }).collect();
+
+        let dist_corr = dist_correlation.compute(&v1, &v_real).unwrap();
+        let dist_corr_one_binary = dist_correlation
+            .compute_binary(&v1, &v_real, true, false)
+            .unwrap();
+        let dist_corr_binary = dist_correlation
+            .compute_binary(&v1, &v_other, true, true)
+            .unwrap();
+
+        assert!((dist_corr - dist_corr_one_binary).abs() < 1e-10);
+        assert!(
+            (dist_corr_binary - dist_correlation.compute(&v1, &v_other).unwrap()).abs() < 1e-10
+        );
+
+        let dist_cov = distance_covariance.compute(&v_real, &v1).unwrap();
+        let dist_cov_one_binary = distance_covariance
+            .compute_binary(&v_real, &v1, false, true)
+            .unwrap();
+        let dist_cov_binary = distance_covariance
+            .compute_binary(&v1, &v_other, true, true)
+            .unwrap();
+
+        println!("{} {}: {} {}", low, high, dist_cov, dist_cov_one_binary);
+
+        assert!((dist_cov - dist_cov_one_binary).abs() < 1e-10);
+        assert!(
+            (dist_cov_binary - distance_covariance.compute(&v1, &v_other).unwrap()).abs() < 1e-10
+        );
+    }
+}
+
+#[test]
+fn two_valued_binary_tests() {
+    let mut rng = ChaCha8Rng::seed_from_u64(17);
+
+    let v_real: Vec<f64> = (0..200).map(|_x| rng.random_range(-5.0..5.0)).collect();
+    let v_bin_1: Vec<f64> = v_real.iter().map(|x| (*x > 0.0) as u8 as f64).collect();
+    let v_bin_2: Vec<f64> = v_real
+        .iter()
+        .map(|x| (x.abs() < 2.0) as u8 as f64)
+        .collect();
+    let v_two_1: Vec<f64> = v_bin_1.iter().map(|x| 2.0 * x - 1.0).collect();
+    let v_two_2: Vec<f64> = v_bin_2.iter().map(|x| 1.0 + x).collect();
+
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    let test = dist_correlation
+        .permutation_test_binary(&v_bin_1, &v_real, true, false, 99, 3)
+        .unwrap();
+    let test_two_valued = dist_correlation
+        .permutation_test_binary(&v_two_1, &v_real, true, false, 99, 3)
+        .unwrap();
+    assert_eq!(test, test_two_valued);
+
+    let test = dist_correlation
+        .permutation_test_binary(&v_bin_1, &v_bin_2, true, true, 99, 3)
+        .unwrap();
+    let test_two_valued = dist_correlation
+        .permutation_test_binary(&v_two_1, &v_two_2, true, true, 99, 3)
+        .unwrap();
+    assert_eq!(test, test_two_valued);
+
+    let exact_test = dist_correlation
+        .exact_test_both_binary(&v_bin_1, &v_bin_2)
+        .unwrap();
+    let exact_test_two_valued = dist_correlation
+        .exact_test_both_binary(&v_two_1, &v_two_2)
+        .unwrap();
+    assert_eq!(exact_test, exact_test_two_valued);
+
+    let prepared_two_1 = PreparedVector::new(&v_two_1).unwrap();
+    let prepared_two_2 = PreparedVector::new(&v_two_2).unwrap();
+    let prepared_real = PreparedVector::new(&v_real).unwrap();
+
+    assert!(prepared_two_1.is_binary());
+
+    for (v1, v2, prepared_1, prepared_2) in [
+        (&v_two_1, &v_real, &prepared_two_1, &prepared_real),
+        (&v_two_1, &v_two_2, &prepared_two_1, &prepared_two_2),
+    ] {
+        let dist_cov = distance_covariance.compute(v1, v2).unwrap();
+        let dist_cov_prepared = distance_covariance
+            .compute_prepared(prepared_1, prepared_2)
+            .unwrap();
+
+        println!("dist_cov: {:?} vs {:?}", dist_cov, dist_cov_prepared);
+
+        assert!((dist_cov - dist_cov_prepared).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn bool_binary() {
+    let v_bool_1 = vec![false, true, true, false, true, true];
+    let v_bool_2 = vec![true, true, false, false, true, false];
+    let v_real = vec![0.5, 2.0, 1.0, -0.3, 4.0, 1.5];
+
+    let v_bin_1: Vec<f64> = v_bool_1.iter().map(|&x| x as u8 as f64).collect();
+    let v_bin_2: Vec<f64> = v_bool_2.iter().map(|&x| x as u8 as f64).collect();
+
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    assert!(
+        (dist_correlation.compute_bool(&v_bool_1, &v_real).unwrap()
+            - dist_correlation.compute(&v_bin_1, &v_real).unwrap())
+        .abs()
+            < 1e-10
+    );
+    assert!(
+        (dist_correlation
+            .compute_both_bool(&v_bool_1, &v_bool_2)
+            .unwrap()
+            - dist_correlation.compute(&v_bin_1, &v_bin_2).unwrap())
+        .abs()
+            < 1e-10
+    );
+    assert!(
+        (distance_covariance
+            .compute_bool(&v_bool_1, &v_real)
+            .unwrap()
+            - distance_covariance.compute(&v_bin_1, &v_real).unwrap())
+        .abs()
+            < 1e-10
+    );
+    assert!(
+        (distance_covariance
+            .compute_both_bool(&v_bool_1, &v_bool_2)
+            .unwrap()
+            - distance_covariance.compute(&v_bin_1, &v_bin_2).unwrap())
+        .abs()
+            < 1e-10
+    );
+}
+
+#[test]
+fn errors_binary() {
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    assert!(dist_correlation
+        .compute_binary(&[0.0, 1.0, 2.0], &[1.0, 2.0, 3.0], true, false)
+        .is_err());
+    assert!(distance_covariance
+        .compute_binary(&[1.0, 2.0, 3.0], &[0.0, 1.0, 2.0], false, true)
+        .is_err());
+    assert!(dist_correlation
+        .compute_bool(&[true, false], &[1.0])
+        .is_err());
+    assert!(distance_covariance.compute_both_bool(&[], &[]).is_err());
+}
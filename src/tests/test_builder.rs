@@ -0,0 +1,479 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{
+    ConfiguredDistCorrelation, ConfiguredDistCovariance, DistCorrelation, DistCovariance,
+};
+use crate::auto_detection::{Algorithm, BinaryDetection};
+use crate::config::Estimator;
+use crate::error::DistCorrError;
+use crate::nan_policy::NanPolicy;
+use crate::screening::ScreeningCutoff;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// the builder without settings reproduces the default settings of the unit structs
+#[test]
+fn default_settings() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let v1: Vec<f64> = (0..500).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x * x + rng.random::<f64>()).collect();
+
+    let dist_corr = DistCorrelation::builder().build();
+    let dist_cov = DistCovariance::builder().build();
+
+    assert_eq!(dist_corr, ConfiguredDistCorrelation::default());
+    assert_eq!(dist_cov, ConfiguredDistCovariance::default());
+    assert_eq!(*DistCorrelation, dist_corr);
+    assert_eq!(*DistCovariance, dist_cov);
+    assert_eq!(dist_corr.estimator(), Estimator::Standard);
+    assert_eq!(dist_corr.nan_policy(), NanPolicy::Reject);
+    assert!(dist_corr.parallel());
+
+    assert_eq!(
+        dist_corr.compute(&v1, &v2).unwrap(),
+        DistCorrelation.compute(&v1, &v2).unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute(&v1, &v2).unwrap(),
+        DistCovariance.compute(&v1, &v2).unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute_var(&v1).unwrap(),
+        DistCovariance.compute_var(&v1).unwrap()
+    );
+}
+
+/// the bias-corrected estimator agrees with the dedicated bias-corrected methods
+#[test]
+fn bias_corrected_estimator() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let v1: Vec<f64> = (0..200).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x.sin() + rng.random::<f64>()).collect();
+    let v_bin: Vec<f64> = (0..200).map(|_| rng.random_range(0..2) as f64).collect();
+
+    let dist_corr = DistCorrelation::builder()
+        .estimator(Estimator::BiasCorrected)
+        .build();
+    let dist_cov = DistCovariance::builder()
+        .estimator(Estimator::BiasCorrected)
+        .build();
+
+    let expected = DistCorrelation.compute_bias_corrected(&v1, &v2).unwrap();
+    assert_eq!(dist_corr.compute(&v1, &v2).unwrap(), expected.dist_corr);
+    assert_eq!(
+        dist_cov.compute(&v1, &v2).unwrap(),
+        expected.dist_cov_sq.max(0.0).sqrt()
+    );
+    assert!((dist_cov.compute_var(&v1).unwrap() - expected.dist_var_sq_v1.sqrt()).abs() < 1e-12);
+
+    // binary flags only validate the inputs
+    let expected_bin = DistCorrelation.compute_bias_corrected(&v_bin, &v2).unwrap();
+    assert_eq!(
+        dist_corr.compute_binary(&v_bin, &v2, true, false).unwrap(),
+        expected_bin.dist_corr
+    );
+    assert!(matches!(
+        dist_corr.compute_binary(&v1, &v2, true, false),
+        Err(DistCorrError::NonBinary { .. })
+    ));
+
+    let auto = dist_corr
+        .compute_auto(&v_bin, &v2, BinaryDetection::TwoValued)
+        .unwrap();
+    assert_eq!(auto.algorithm, Algorithm::General);
+    assert_eq!(auto.value, expected_bin.dist_corr);
+
+    let too_few = Err(DistCorrError::TooFewObservations {
+        required: 4,
+        found: 3,
+    });
+    assert_eq!(dist_corr.compute(&v1[..3], &v2[..3]), too_few);
+    assert_eq!(dist_cov.compute_var(&v1[..3]), too_few);
+}
+
+/// the configured nan policy equals the explicit one
+#[test]
+fn nan_policy_setting() {
+    let v1 = vec![1.0, 2.0, f64::NAN, 4.0, 3.0, 6.0, f64::INFINITY];
+    let v2 = vec![0.5, 1.0, 2.0, f64::NAN, 2.5, 3.0, 1.0];
+
+    for nan_policy in [NanPolicy::PairwiseDeletion, NanPolicy::Propagate] {
+        let dist_corr = DistCorrelation::builder().nan_policy(nan_policy).build();
+        let dist_cov = DistCovariance::builder().nan_policy(nan_policy).build();
+
+        let expected = DistCorrelation
+            .compute_with_nan_policy(&v1, &v2, nan_policy)
+            .unwrap();
+        let result = dist_corr.compute(&v1, &v2).unwrap();
+        assert!(result == expected || (result.is_nan() && expected.is_nan()));
+
+        let expected = DistCovariance
+            .compute_with_nan_policy(&v1, &v2, nan_policy)
+            .unwrap();
+        let result = dist_cov.compute(&v1, &v2).unwrap();
+        assert!(result == expected || (result.is_nan() && expected.is_nan()));
+    }
+
+    let dist_cov = DistCovariance::builder()
+        .nan_policy(NanPolicy::PairwiseDeletion)
+        .build();
+    assert_eq!(
+        dist_cov.compute_var(&v1).unwrap(),
+        DistCovariance
+            .compute_var(&[1.0, 2.0, 4.0, 3.0, 6.0])
+            .unwrap()
+    );
+
+    let dist_cov = DistCovariance::builder()
+        .nan_policy(NanPolicy::Propagate)
+        .build();
+    assert!(dist_cov.compute_var(&v1).unwrap().is_nan());
+
+    // the explicit policy overrides the configured one
+    assert!(matches!(
+        dist_cov.compute_with_nan_policy(&v1, &v2, NanPolicy::Reject),
+        Err(DistCorrError::NonFinite { index: 2, .. })
+    ));
+}
+
+/// sequential computations agree with parallel ones
+#[test]
+fn sequential() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2);
+    let v1: Vec<f64> = (0..20_000).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x.cos() + rng.random::<f64>()).collect();
+    let columns = [v1.clone(), v2.clone(), v1.iter().map(|x| -x).collect()];
+
+    let dist_corr = DistCorrelation::builder().parallel(false).build();
+    assert!(!dist_corr.parallel());
+
+    let sequential = dist_corr.compute(&v1, &v2).unwrap();
+    assert!((sequential - DistCorrelation.compute(&v1, &v2).unwrap()).abs() < 1e-12);
+    assert_eq!(sequential, dist_corr.compute(&v1, &v2).unwrap());
+
+    let sequential = dist_corr.correlation_matrix(&columns).unwrap();
+    let parallel = DistCorrelation.correlation_matrix(&columns).unwrap();
+    for (s, p) in sequential.packed().iter().zip(parallel.packed()) {
+        assert!((s - p).abs() < 1e-12);
+    }
+}
+
+/// batch computations evaluate every pair with compute for non-default settings
+#[test]
+fn batch_settings() {
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let len = 50;
+    let mut columns: Vec<Vec<f64>> = (0..4)
+        .map(|_| (0..len).map(|_| rng.random::<f64>()).collect())
+        .collect();
+    columns[1][7] = f64::NAN;
+    columns[3][11] = f64::NEG_INFINITY;
+    let target: Vec<f64> = columns[0].iter().map(|x| x * x).collect();
+
+    for (estimator, nan_policy) in [
+        (Estimator::Standard, NanPolicy::PairwiseDeletion),
+        (Estimator::BiasCorrected, NanPolicy::PairwiseDeletion),
+        (Estimator::Standard, NanPolicy::Propagate),
+    ] {
+        let dist_corr = DistCorrelation::builder()
+            .estimator(estimator)
+            .nan_policy(nan_policy)
+            .build();
+
+        let same = |a: f64, b: f64| (a - b).abs() < 1e-12 || (a.is_nan() && b.is_nan());
+
+        let matrix = dist_corr.correlation_matrix(&columns).unwrap();
+        let cross = dist_corr
+            .cross_correlation_matrix(&columns, &columns)
+            .unwrap();
+        for i in 0..columns.len() {
+            for j in 0..columns.len() {
+                let expected = dist_corr.compute(&columns[i], &columns[j]).unwrap();
+                assert!(same(matrix.get(i, j), expected));
+                assert!(same(cross[i][j], expected));
+            }
+        }
+
+        let results = dist_corr.compute_one_vs_many(&target, &columns).unwrap();
+        for (result, column) in results.iter().zip(&columns) {
+            let expected = dist_corr.compute(&target, column).unwrap();
+            assert!(same(*result.as_ref().unwrap(), expected));
+        }
+
+        let screened = dist_corr
+            .screen(&target, &columns, ScreeningCutoff::All)
+            .unwrap();
+        assert_eq!(screened[0].index, 0);
+        for feature in &screened {
+            let expected = dist_corr.compute(&columns[feature.index], &target).unwrap();
+            assert!(same(feature.dist_corr, expected));
+        }
+    }
+
+    // features with a NaN distance correlation are ranked last and get a NaN p-value
+    let dist_corr = DistCorrelation::builder()
+        .nan_policy(NanPolicy::Propagate)
+        .build();
+    let screened = dist_corr
+        .screen_with_p_values(&target, &columns, ScreeningCutoff::All, 19, 0)
+        .unwrap();
+    let ranked: Vec<usize> = screened.iter().map(|feature| feature.index).collect();
+    assert_eq!(&ranked[2..], &[1, 3]);
+    assert!(screened[3].p_value.unwrap().is_nan());
+    assert!(!screened[0].p_value.unwrap().is_nan());
+
+    // the default policy still rejects non-finite columns
+    assert!(matches!(
+        DistCorrelation.correlation_matrix(&columns),
+        Err(DistCorrError::NonFinite { index: 7, .. })
+    ));
+}
+
+/// the tests, bias-corrected, partial and multivariate computations apply the configured nan policy
+#[test]
+fn nan_policy_of_other_methods() {
+    let mut rng = ChaCha8Rng::seed_from_u64(4);
+    let mut v1: Vec<f64> = (0..60).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x * x + rng.random::<f64>()).collect();
+    let v3: Vec<f64> = v1.iter().map(|x| x.sin() + rng.random::<f64>()).collect();
+    let v_bin: Vec<f64> = (0..60).map(|i| (i % 2) as f64).collect();
+    let labels: Vec<usize> = (0..60).map(|i| i % 3).collect();
+    v1[5] = f64::NAN;
+    v1[17] = f64::INFINITY;
+
+    let complete = |v: &[f64]| -> Vec<f64> {
+        v.iter()
+            .enumerate()
+            .filter(|&(i, _)| i != 5 && i != 17)
+            .map(|(_, &x)| x)
+            .collect()
+    };
+    let (c1, c2, c3, c_bin) = (
+        complete(&v1),
+        complete(&v2),
+        complete(&v3),
+        complete(&v_bin),
+    );
+    let c_labels: Vec<usize> = (0..60)
+        .filter(|&i| i != 5 && i != 17)
+        .map(|i| i % 3)
+        .collect();
+
+    let dist_corr = DistCorrelation::builder()
+        .nan_policy(NanPolicy::PairwiseDeletion)
+        .build();
+    let dist_cov = DistCovariance::builder()
+        .nan_policy(NanPolicy::PairwiseDeletion)
+        .build();
+
+    assert_eq!(
+        dist_corr.permutation_test(&v1, &v2, 99, 1).unwrap(),
+        DistCorrelation.permutation_test(&c1, &c2, 99, 1).unwrap()
+    );
+    assert_eq!(
+        dist_corr
+            .permutation_test_binary(&v_bin, &v1, true, false, 99, 1)
+            .unwrap(),
+        DistCorrelation
+            .permutation_test_binary(&c_bin, &c1, true, false, 99, 1)
+            .unwrap()
+    );
+    assert_eq!(
+        dist_corr.t_test(&v1, &v2).unwrap(),
+        DistCorrelation.t_test(&c1, &c2).unwrap()
+    );
+    assert_eq!(
+        dist_corr.compute_bias_corrected(&v1, &v2).unwrap(),
+        DistCorrelation.compute_bias_corrected(&c1, &c2).unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute_bias_corrected(&v2, &v1).unwrap(),
+        DistCovariance.compute_bias_corrected(&c2, &c1).unwrap()
+    );
+    assert_eq!(
+        dist_corr.compute_partial(&v2, &v3, &v1).unwrap(),
+        DistCorrelation.compute_partial(&c2, &c3, &c1).unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute_partial(&v1, &v2, &v3).unwrap(),
+        DistCovariance.compute_partial(&c1, &c2, &c3).unwrap()
+    );
+    assert_eq!(
+        dist_corr.compute_categorical(&labels, &v1).unwrap(),
+        DistCorrelation.compute_categorical(&c_labels, &c1).unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute_categorical(&labels, &v1).unwrap(),
+        DistCovariance.compute_categorical(&c_labels, &c1).unwrap()
+    );
+
+    // the rows of row-major samples are dropped as a whole
+    let x: Vec<f64> = v1.iter().zip(&v3).flat_map(|(&a, &b)| [b, a]).collect();
+    let x_complete: Vec<f64> = c1.iter().zip(&c3).flat_map(|(&a, &b)| [b, a]).collect();
+    assert_eq!(
+        dist_corr.compute_multi(&x, 2, &v2, 1).unwrap(),
+        DistCorrelation
+            .compute_multi(&x_complete, 2, &c2, 1)
+            .unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute_multi(&v2, 1, &x, 2).unwrap(),
+        DistCovariance
+            .compute_multi(&c2, 1, &x_complete, 2)
+            .unwrap()
+    );
+    assert_eq!(
+        dist_corr
+            .compute_multi_projection(&x, 2, &v2, 1, 20, 3)
+            .unwrap(),
+        DistCorrelation
+            .compute_multi_projection(&x_complete, 2, &c2, 1, 20, 3)
+            .unwrap()
+    );
+    assert_eq!(
+        dist_cov
+            .compute_partial_multi(&x, 2, &v2, 1, &v3, 1)
+            .unwrap(),
+        DistCovariance
+            .compute_partial_multi(&x_complete, 2, &c2, 1, &c3, 1)
+            .unwrap()
+    );
+
+    // propagation returns NaN results
+    let dist_corr = DistCorrelation::builder()
+        .nan_policy(NanPolicy::Propagate)
+        .build();
+    let dist_cov = DistCovariance::builder()
+        .nan_policy(NanPolicy::Propagate)
+        .build();
+
+    let test = dist_corr.permutation_test(&v1, &v2, 99, 1).unwrap();
+    assert!(test.statistic.is_nan() && test.p_value.is_nan());
+    assert_eq!(test.n_perm, 99);
+    let test = dist_corr.t_test(&v2, &v1).unwrap();
+    assert!(test.statistic.is_nan() && test.p_value.is_nan());
+    assert_eq!(test.df, DistCorrelation.t_test(&v2, &v3).unwrap().df);
+    assert!(dist_corr
+        .compute_bias_corrected(&v1, &v2)
+        .unwrap()
+        .dist_corr
+        .is_nan());
+    assert!(dist_corr
+        .compute_partial(&v2, &v3, &v1)
+        .unwrap()
+        .partial_dist_corr
+        .is_nan());
+    assert!(dist_cov.compute_multi(&x, 2, &v2, 1).unwrap().is_nan());
+    assert!(dist_cov.compute_bool(&[true; 60], &v1).unwrap().is_nan());
+    assert!(dist_corr
+        .compute_multi_projection(&x, 2, &v2, 1, 20, 3)
+        .unwrap()
+        .estimate
+        .is_nan());
+
+    let test = dist_corr
+        .exact_test_both_binary(&v_bin, &[f64::NAN; 60])
+        .unwrap();
+    assert!(test.p_value_exact.is_nan() && test.p_value_chi_square.is_nan());
+    assert_eq!(test.table.total(), 0);
+}
+
+/// the configured estimator is honored by the multivariate computations and rejected by the
+/// computations without a bias-corrected version
+#[test]
+fn estimator_of_other_methods() {
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+    let v1: Vec<f64> = (0..100).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x.cos() + rng.random::<f64>()).collect();
+
+    let dist_corr = DistCorrelation::builder()
+        .estimator(Estimator::BiasCorrected)
+        .build();
+    let dist_cov = DistCovariance::builder()
+        .estimator(Estimator::BiasCorrected)
+        .build();
+
+    let expected = DistCorrelation.compute_bias_corrected(&v1, &v2).unwrap();
+    assert!((dist_corr.compute_multi(&v1, 1, &v2, 1).unwrap() - expected.dist_corr).abs() < 1e-12);
+    assert!(
+        (dist_cov.compute_multi(&v1, 1, &v2, 1).unwrap() - expected.dist_cov_sq.max(0.0).sqrt())
+            .abs()
+            < 1e-12
+    );
+    assert_eq!(
+        dist_corr.compute_multi(&v1[..3], 1, &v2[..3], 1),
+        Err(DistCorrError::TooFewObservations {
+            required: 4,
+            found: 3
+        })
+    );
+
+    let unsupported = |setting| DistCorrError::UnsupportedSetting { setting };
+    assert_eq!(
+        dist_corr.permutation_test(&v1, &v2, 99, 1).unwrap_err(),
+        unsupported("estimator")
+    );
+    assert_eq!(
+        dist_cov
+            .compute_multi_projection(&v1, 1, &v2, 1, 10, 1)
+            .unwrap_err(),
+        unsupported("estimator")
+    );
+    assert_eq!(
+        dist_corr
+            .compute_categorical(&[0, 1, 0, 1], &v1[..4])
+            .unwrap_err(),
+        unsupported("estimator")
+    );
+
+    let accurate = DistCorrelation::builder().accurate(true).build();
+    assert_eq!(
+        accurate.compute_multi(&v1, 1, &v2, 1).unwrap_err(),
+        unsupported("accurate")
+    );
+    assert_eq!(
+        accurate
+            .exact_test_both_binary(&[0.0, 1.0], &[1.0, 0.0])
+            .unwrap_err(),
+        unsupported("accurate")
+    );
+
+    // the dedicated bias-corrected methods honor the accuracy mode
+    let result = accurate.compute_bias_corrected(&v1, &v2).unwrap();
+    assert!((result.dist_corr_sq - expected.dist_corr_sq).abs() < 1e-12);
+}
+
+/// the tests and multivariate computations run on the configured thread pool
+#[test]
+fn sequential_other_methods() {
+    let mut rng = ChaCha8Rng::seed_from_u64(6);
+    let v1: Vec<f64> = (0..5_000).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x.cos() + rng.random::<f64>()).collect();
+
+    let dist_corr = DistCorrelation::builder()
+        .parallel(false)
+        .deterministic(true)
+        .build();
+    let test = dist_corr.permutation_test(&v1, &v2, 49, 7).unwrap();
+    let expected = DistCorrelation::builder()
+        .deterministic(true)
+        .build()
+        .permutation_test(&v1, &v2, 49, 7)
+        .unwrap();
+    assert_eq!(test, expected);
+
+    let multi = dist_corr
+        .compute_multi(&v1[..500], 1, &v2[..500], 1)
+        .unwrap();
+    assert_eq!(
+        multi,
+        DistCorrelation
+            .compute_multi(&v1[..500], 1, &v2[..500], 1)
+            .unwrap()
+    );
+}
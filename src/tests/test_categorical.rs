@@ -0,0 +1,221 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::dist_corr_naive::_dist_cov_sq_multi_naive;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn categorical_naive() {
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    for (sample_size, num_levels) in [(5, 2), (17, 3), (128, 5), (333, 12)] {
+        let (labels, values) = sample(sample_size, num_levels, sample_size as u64);
+
+        let values_rows: Vec<Vec<f64>> = values.iter().map(|&x| vec![x]).collect();
+        let labels_rows = one_hot(&labels, num_levels);
+
+        let dist_cov_sq_naive = _dist_cov_sq_multi_naive(&labels_rows, &values_rows);
+        let dist_var_sq_labels = _dist_cov_sq_multi_naive(&labels_rows, &labels_rows);
+        let dist_var_sq_values = _dist_cov_sq_multi_naive(&values_rows, &values_rows);
+        let dist_corr_naive =
+            (dist_cov_sq_naive / (dist_var_sq_labels * dist_var_sq_values).sqrt()).sqrt();
+
+        let dist_corr = dist_correlation
+            .compute_categorical(&labels, &values)
+            .unwrap();
+        let dist_cov = distance_covariance
+            .compute_categorical(&labels, &values)
+            .unwrap();
+
+        println!("dist_corr: {:?}, naive: {:?}", dist_corr, dist_corr_naive);
+
+        assert!((dist_corr - dist_corr_naive).abs() < 1e-10);
+        assert!((dist_cov - dist_cov_sq_naive.sqrt()).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn both_categorical_naive() {
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    for (sample_size, num_levels_1, num_levels_2) in [(12, 2, 3), (40, 4, 2), (250, 7, 9)] {
+        let mut rng = ChaCha8Rng::seed_from_u64(sample_size as u64);
+
+        let labels_1: Vec<usize> = (0..sample_size)
+            .map(|_i| rng.random_range(0..num_levels_1))
+            .collect();
+        let labels_2: Vec<usize> = labels_1
+            .iter()
+            .map(|&k| {
+                if rng.random::<f64>() < 0.5 {
+                    k % num_levels_2
+                } else {
+                    rng.random_range(0..num_levels_2)
+                }
+            })
+            .collect();
+
+        let rows_1 = one_hot(&labels_1, num_levels_1);
+        let rows_2 = one_hot(&labels_2, num_levels_2);
+
+        let dist_cov_sq_naive = _dist_cov_sq_multi_naive(&rows_1, &rows_2);
+        let dist_corr_naive = (dist_cov_sq_naive
+            / (_dist_cov_sq_multi_naive(&rows_1, &rows_1)
+                * _dist_cov_sq_multi_naive(&rows_2, &rows_2))
+            .sqrt())
+        .sqrt();
+
+        let dist_corr = dist_correlation
+            .compute_both_categorical(&labels_1, &labels_2)
+            .unwrap();
+        let dist_cov = distance_covariance
+            .compute_both_categorical(&labels_1, &labels_2)
+            .unwrap();
+
+        println!("dist_corr: {:?}, naive: {:?}", dist_corr, dist_corr_naive);
+
+        assert!((dist_corr - dist_corr_naive).abs() < 1e-10);
+        assert!((dist_cov - dist_cov_sq_naive.sqrt()).abs() < 1e-10);
+    }
+}
+
+/// two levels reproduce the binary algorithms, independent of the label values
+#[test]
+fn categorical_binary() {
+    let (labels, values) = sample(300, 2, 3);
+    let labels_sparse: Vec<usize> = labels.iter().map(|&k| 1000 * k + 7).collect();
+    let v_bin: Vec<f64> = labels.iter().map(|&k| k as f64).collect();
+    let v_bin_2: Vec<f64> = values
+        .iter()
+        .map(|&x| if x > 0.5 { 1.0 } else { 0.0 })
+        .collect();
+    let labels_2: Vec<usize> = v_bin_2.iter().map(|&x| x as usize).collect();
+
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    assert!(
+        (dist_correlation
+            .compute_categorical(&labels_sparse, &values)
+            .unwrap()
+            - dist_correlation
+                .compute_binary(&v_bin, &values, true, false)
+                .unwrap())
+        .abs()
+            < 1e-10
+    );
+    assert!(
+        (distance_covariance
+            .compute_categorical(&labels_sparse, &values)
+            .unwrap()
+            - distance_covariance
+                .compute_binary(&v_bin, &values, true, false)
+                .unwrap())
+        .abs()
+            < 1e-10
+    );
+    assert!(
+        (dist_correlation
+            .compute_both_categorical(&labels_sparse, &labels_2)
+            .unwrap()
+            - dist_correlation
+                .compute_binary(&v_bin, &v_bin_2, true, true)
+                .unwrap())
+        .abs()
+            < 1e-10
+    );
+    assert!(
+        (distance_covariance
+            .compute_both_categorical(&labels_sparse, &labels_2)
+            .unwrap()
+            - distance_covariance
+                .compute_binary(&v_bin, &v_bin_2, true, true)
+                .unwrap())
+        .abs()
+            < 1e-10
+    );
+}
+
+#[test]
+fn categorical_degenerate() {
+    let dist_correlation = DistCorrelation;
+
+    let labels = vec![3; 10];
+    let labels_distinct: Vec<usize> = (0..10).collect();
+    let values: Vec<f64> = (0..10).map(|i| (i as f64).sin()).collect();
+
+    assert_eq!(
+        dist_correlation
+            .compute_categorical(&labels, &values)
+            .unwrap(),
+        0.0
+    );
+    assert_eq!(
+        dist_correlation
+            .compute_both_categorical(&labels, &labels_distinct)
+            .unwrap(),
+        0.0
+    );
+    assert!(
+        (dist_correlation
+            .compute_both_categorical(&labels_distinct, &labels_distinct)
+            .unwrap()
+            - 1.0)
+            .abs()
+            < 1e-10
+    );
+}
+
+#[test]
+fn categorical_errors() {
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    assert!(dist_correlation
+        .compute_categorical(&[0, 1], &[1.0])
+        .is_err());
+    assert!(dist_correlation
+        .compute_categorical(&[], &[0.0; 0])
+        .is_err());
+    assert!(distance_covariance
+        .compute_both_categorical(&[0, 1], &[1])
+        .is_err());
+    assert!(distance_covariance
+        .compute_both_categorical(&[], &[])
+        .is_err());
+}
+
+/// labels with num_levels levels and values depending on the labels
+fn sample(sample_size: usize, num_levels: usize, seed: u64) -> (Vec<usize>, Vec<f64>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let labels: Vec<usize> = (0..sample_size)
+        .map(|_i| rng.random_range(0..num_levels))
+        .collect();
+    let values: Vec<f64> = labels
+        .iter()
+        .map(|&k| (k as f64).sqrt() + rng.random_range(-1.0..1.0))
+        .collect();
+
+    (labels, values)
+}
+
+/// one-hot encoding scaled such that the euclidean distance is the discrete metric
+fn one_hot(labels: &[usize], num_levels: usize) -> Vec<Vec<f64>> {
+    labels
+        .iter()
+        .map(|&k| {
+            let mut row = vec![0.0; num_levels];
+            row[k] = std::f64::consts::FRAC_1_SQRT_2;
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,153 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::auto_detection::Algorithm;
+use crate::config::Estimator;
+use crate::nan_policy::NanPolicy;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// the details agree with the results of compute_binary, DistCovariance and compute_var
+#[test]
+fn consistency() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let len = 300;
+
+    let v_real_1: Vec<f64> = (0..len).map(|_| rng.random::<f64>()).collect();
+    let v_real_2: Vec<f64> = v_real_1
+        .iter()
+        .map(|x| (3.0 * x).sin() + 0.5 * rng.random::<f64>())
+        .collect();
+    // binary vectors with levels other than 0 and 1
+    let v_bin_1: Vec<f64> = v_real_1
+        .iter()
+        .map(|&x| if x > 0.4 { 3.0 } else { -1.0 })
+        .collect();
+    let v_bin_2: Vec<f64> = (0..len)
+        .map(|_| if rng.random::<f64>() > 0.7 { 2.5 } else { 2.0 })
+        .collect();
+
+    for (v1, v2, v1_binary, v2_binary, algorithm) in [
+        (&v_real_1, &v_real_2, false, false, Algorithm::General),
+        (&v_bin_1, &v_real_2, true, false, Algorithm::OneBinary),
+        (&v_real_1, &v_bin_2, false, true, Algorithm::OneBinary),
+        (&v_bin_1, &v_bin_2, true, true, Algorithm::BothBinary),
+    ] {
+        let details = DistCorrelation
+            .compute_detailed(v1, v2, v1_binary, v2_binary)
+            .unwrap();
+
+        assert_eq!(
+            details.dist_corr,
+            DistCorrelation
+                .compute_binary(v1, v2, v1_binary, v2_binary)
+                .unwrap()
+        );
+        assert_eq!(details.algorithm, algorithm);
+        assert_eq!(details.len, len);
+        assert!(!details.degenerate);
+        assert!(!details.clamped);
+
+        let dist_cov = DistCovariance.compute(v1, v2).unwrap();
+        assert!((details.dist_cov_sq - dist_cov.powi(2)).abs() < 1e-10);
+
+        let dist_var_v1 = DistCovariance.compute_var(v1).unwrap();
+        let dist_var_v2 = DistCovariance.compute_var(v2).unwrap();
+        assert!((details.dist_var_sq_v1 - dist_var_v1.powi(2)).abs() < 1e-10);
+        assert!((details.dist_var_sq_v2 - dist_var_v2.powi(2)).abs() < 1e-10);
+    }
+}
+
+/// a constant vector is reported as degenerate instead of independent
+#[test]
+fn degenerate() {
+    let v1 = vec![1.0, 4.0, 2.0, 8.0, 5.0];
+    let v_const = vec![3.0; 5];
+    let v_bin = vec![0.0, 1.0, 1.0, 0.0, 1.0];
+
+    for (v_a, v_b, a_binary, b_binary) in [
+        (&v1, &v_const, false, false),
+        (&v1, &v_const, false, true),
+        (&v_bin, &v_const, true, false),
+        (&v_bin, &v_const, true, true),
+    ] {
+        let details = DistCorrelation
+            .compute_detailed(v_a, v_b, a_binary, b_binary)
+            .unwrap();
+
+        assert_eq!(details.dist_corr, 0.0);
+        assert!(details.degenerate);
+        assert!(!details.clamped);
+    }
+
+    let details = DistCorrelation
+        .compute_detailed(&v_bin, &v_const, true, true)
+        .unwrap();
+    assert_eq!(details.algorithm, Algorithm::BothBinary);
+    assert!(details.degenerate);
+    assert_eq!(details.dist_var_sq_v2, 0.0);
+}
+
+/// cancellation for data with a large offset is flagged as clamping
+#[test]
+fn clamped() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let v1: Vec<f64> = (0..1000).map(|_| 1e9 + rng.random::<f64>()).collect();
+    let v2: Vec<f64> = (0..1000).map(|_| rng.random::<f64>()).collect();
+
+    let details = DistCorrelation
+        .compute_detailed(&v1, &v2, false, false)
+        .unwrap();
+
+    // the exact dVar^2 is positive, but the rounding errors dominate
+    assert!(details.dist_var_sq_v1 < 0.0);
+    assert!(details.clamped);
+    assert!(details.degenerate);
+    assert_eq!(
+        details.dist_corr,
+        DistCorrelation.compute(&v1, &v2).unwrap()
+    );
+}
+
+/// the configured settings are applied
+#[test]
+fn settings() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2);
+    let mut v1: Vec<f64> = (0..100).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x * x).collect();
+    v1[10] = f64::NAN;
+
+    let dist_corr = DistCorrelation::builder()
+        .nan_policy(NanPolicy::PairwiseDeletion)
+        .build();
+    let details = dist_corr.compute_detailed(&v1, &v2, false, false).unwrap();
+    assert_eq!(details.len, 99);
+    assert_eq!(details.dist_corr, dist_corr.compute(&v1, &v2).unwrap());
+
+    let dist_corr = DistCorrelation::builder()
+        .nan_policy(NanPolicy::Propagate)
+        .build();
+    let details = dist_corr.compute_detailed(&v1, &v2, false, false).unwrap();
+    assert_eq!(details.len, 100);
+    assert!(details.dist_corr.is_nan() && details.dist_cov_sq.is_nan());
+
+    let dist_corr = DistCorrelation::builder()
+        .estimator(Estimator::BiasCorrected)
+        .nan_policy(NanPolicy::PairwiseDeletion)
+        .build();
+    let details = dist_corr.compute_detailed(&v1, &v2, false, false).unwrap();
+    let (v1_complete, v2_complete): (Vec<f64>, Vec<f64>) =
+        v1.iter().zip(&v2).filter(|(x, _)| !x.is_nan()).unzip();
+    let expected = DistCorrelation
+        .compute_bias_corrected(&v1_complete, &v2_complete)
+        .unwrap();
+    assert_eq!(details.len, 99);
+    assert_eq!(details.dist_corr, expected.dist_corr);
+    assert_eq!(details.dist_cov_sq, expected.dist_cov_sq);
+    assert_eq!(details.dist_var_sq_v1, expected.dist_var_sq_v1);
+}
@@ -3,8 +3,10 @@
 
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
+use rayon::ThreadPoolBuilder;
 
 use crate::api::{DistCorrelation, DistCovariance};
+use crate::config::deterministic_pool_builder;
 
 // +++++++++++++++++++++++++++++++++++++++++++++++++++
 // Tests
@@ -40,3 +42,95 @@ fn sin_determinism() {
         }
     }
 }
+
+/// all results of the deterministic mode, compared bitwise
+fn deterministic_results(v1: &[f64], v2: &[f64]) -> Vec<u64> {
+    let details = DistCorrelation
+        .compute_detailed(v1, v2, false, false)
+        .unwrap();
+    let bias_corrected = DistCorrelation.compute_bias_corrected(v1, v2).unwrap();
+    let columns = [v1.to_vec(), v2.to_vec(), v1.iter().map(|x| x * x).collect()];
+    let matrix = DistCorrelation.correlation_matrix(&columns).unwrap();
+
+    [
+        DistCorrelation.compute(v1, v2).unwrap(),
+        DistCovariance.compute(v1, v2).unwrap(),
+        details.dist_cov_sq,
+        details.dist_var_sq_v1,
+        bias_corrected.dist_corr_sq,
+        bias_corrected.dist_cov_sq,
+        matrix.get(0, 1),
+        matrix.get(1, 2),
+    ]
+    .iter()
+    .map(|x| x.to_bits())
+    .collect()
+}
+
+/// the deterministic mode gives bit-identical results in pools with different numbers of threads
+#[test]
+fn thread_count_independence() {
+    let mut rng = ChaCha8Rng::seed_from_u64(22);
+    // values rounded to produce ties
+    let v1: Vec<f64> = (0..30_000)
+        .map(|_| (rng.random_range(-10.0..10.0_f64) * 100.0).round() / 100.0)
+        .collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x.sin() + rng.random::<f64>()).collect();
+
+    let results: Vec<Vec<u64>> = [1, 2, 3, 8]
+        .iter()
+        .map(|&num_threads| {
+            deterministic_pool_builder()
+                .num_threads(num_threads)
+                .build()
+                .unwrap()
+                .install(|| deterministic_results(&v1, &v2))
+        })
+        .collect();
+
+    for result in &results[1..] {
+        assert_eq!(result, &results[0]);
+    }
+
+    // the configured mode runs on the dedicated pools
+    for parallel in [true, false] {
+        let dist_corr = DistCorrelation::builder()
+            .deterministic(true)
+            .parallel(parallel)
+            .build();
+        let dist_cov = DistCovariance::builder()
+            .deterministic(true)
+            .parallel(parallel)
+            .build();
+        assert!(dist_corr.deterministic() && dist_cov.deterministic());
+
+        assert_eq!(
+            dist_corr.compute(&v1, &v2).unwrap().to_bits(),
+            results[0][0]
+        );
+        assert_eq!(dist_cov.compute(&v1, &v2).unwrap().to_bits(), results[0][1]);
+    }
+}
+
+/// the O(n^2) computations for a general exponent sum in a fixed order in every pool
+#[test]
+fn exponent_thread_count_independence() {
+    let mut rng = ChaCha8Rng::seed_from_u64(23);
+    let v1: Vec<f64> = (0..3_000).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x * x + rng.random::<f64>()).collect();
+
+    let dist_corr = DistCorrelation::builder().exponent(0.5).build();
+
+    let results: Vec<u64> = [1, 2, 5, 16]
+        .iter()
+        .map(|&num_threads| {
+            ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap()
+                .install(|| dist_corr.compute(&v1, &v2).unwrap().to_bits())
+        })
+        .collect();
+
+    assert!(results.iter().all(|&result| result == results[0]));
+}
@@ -0,0 +1,187 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::auto_detection::BinaryDetection;
+use crate::error::DistCorrError;
+use crate::prepared_vector::PreparedVector;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync + std::error::Error + 'static>() {}
+    assert_send_sync::<DistCorrError>();
+
+    // errors can be returned from other threads
+    let handle = std::thread::spawn(|| DistCorrelation.compute(&[1.0], &[]));
+    assert!(handle.join().unwrap().is_err());
+}
+
+#[test]
+fn length_and_empty() {
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    assert_eq!(
+        dist_correlation.compute(&[1.0, 2.0, 3.0], &[1.0, 2.0]),
+        Err(DistCorrError::LengthMismatch {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        distance_covariance.compute(&[], &[]),
+        Err(DistCorrError::EmptyInput)
+    );
+    assert_eq!(
+        distance_covariance.compute_var(&[]),
+        Err(DistCorrError::EmptyInput)
+    );
+    assert_eq!(
+        PreparedVector::new(&[0.0; 0]),
+        Err(DistCorrError::EmptyInput)
+    );
+    assert_eq!(
+        dist_correlation.compute_bias_corrected(&[1.0, 2.0, 3.0], &[3.0, 1.0, 2.0]),
+        Err(DistCorrError::TooFewObservations {
+            required: 4,
+            found: 3
+        })
+    );
+    assert_eq!(
+        dist_correlation.compute_partial(&[1.0; 5], &[1.0; 5], &[1.0; 4]),
+        Err(DistCorrError::LengthMismatch {
+            expected: 5,
+            found: 4
+        })
+    );
+
+    let results = dist_correlation
+        .compute_one_vs_many(&[1.0, 2.0, 3.0], &[vec![1.0, 2.0, 3.0], vec![1.0]])
+        .unwrap();
+    assert_eq!(
+        results[1],
+        Err(DistCorrError::LengthMismatch {
+            expected: 3,
+            found: 1
+        })
+    );
+}
+
+#[test]
+fn non_binary() {
+    let dist_correlation = DistCorrelation;
+
+    assert_eq!(
+        dist_correlation.compute_binary(&[1.0, 2.0, 1.0, 3.0], &[0.0; 4], true, false),
+        Err(DistCorrError::NonBinary {
+            index: 3,
+            value: 3.0
+        })
+    );
+    assert_eq!(
+        DistCovariance.compute_binary(&[0.0; 3], &[0.0, f64::INFINITY, 1.0], false, true),
+        Err(DistCorrError::NonFinite {
+            index: 1,
+            value: f64::INFINITY
+        })
+    );
+    assert_eq!(
+        dist_correlation.permutation_test_binary(&[0.0, 1.0, 0.5], &[1.0; 3], true, false, 10, 0),
+        Err(DistCorrError::NonBinary {
+            index: 2,
+            value: 0.5
+        })
+    );
+    assert_eq!(
+        dist_correlation.exact_test_both_binary(&[0.0, 1.0, 1.0], &[1.0, 0.0, 2.0]),
+        Err(DistCorrError::NonBinary {
+            index: 2,
+            value: 2.0
+        })
+    );
+}
+
+#[test]
+fn parameters() {
+    let dist_correlation = DistCorrelation;
+    let x = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+    assert_eq!(
+        dist_correlation.permutation_test(&x, &x, 0, 0),
+        Err(DistCorrError::NonPositiveParameter { name: "n_perm" })
+    );
+    assert_eq!(
+        dist_correlation.compute_multi(&x, 0, &x, 2),
+        Err(DistCorrError::NonPositiveParameter { name: "dim_x" })
+    );
+    assert_eq!(
+        dist_correlation.compute_multi(&x, 4, &x, 2),
+        Err(DistCorrError::InvalidDimension { len: 6, dim: 4 })
+    );
+    assert_eq!(
+        dist_correlation.compute_multi(&x, 2, &x, 3),
+        Err(DistCorrError::LengthMismatch {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        dist_correlation.compute_multi_projection(&x, 2, &x, 2, 0, 0),
+        Err(DistCorrError::NonPositiveParameter { name: "n_proj" })
+    );
+}
+
+#[test]
+fn degenerate_variance() {
+    let v1: Vec<f64> = (0..20).map(|i| i as f64).collect();
+    let v2 = vec![1.0; 20];
+
+    assert_eq!(
+        DistCorrelation.t_test(&v1, &v2),
+        Err(DistCorrError::DegenerateVariance)
+    );
+    assert_eq!(
+        DistCorrelation
+            .compute_auto(&v1, &v2, BinaryDetection::ZeroOne)
+            .map(|result| result.value),
+        Ok(0.0)
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        DistCorrError::LengthMismatch {
+            expected: 3,
+            found: 2
+        }
+        .to_string(),
+        "Length of inputs must be identical, expected 3 but found 2"
+    );
+    assert_eq!(
+        DistCorrError::NonBinary {
+            index: 4,
+            value: 2.5
+        }
+        .to_string(),
+        "Binary input has a third distinct value 2.5 at index 4"
+    );
+    assert_eq!(
+        DistCorrError::NonPositiveParameter { name: "n_perm" }.to_string(),
+        "n_perm must be positive"
+    );
+    assert_eq!(
+        DistCorrError::UnsupportedSetting {
+            setting: "estimator"
+        }
+        .to_string(),
+        "Setting estimator is not supported by the computation"
+    );
+    assert_eq!(
+        DistCorrError::ThreadPoolUnavailable.to_string(),
+        "Thread pool of the configured computation cannot be built"
+    );
+}
@@ -0,0 +1,179 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::DistCorrelation;
+use crate::dist_corr_binary::ContingencyTable;
+use crate::exact_test::BinaryIndependenceTest;
+use crate::special_functions::{chi_square_sf, ln_gamma};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn special_functions() {
+    assert!((ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-13);
+    assert!((ln_gamma(10.0) - 362_880.0_f64.ln()).abs() < 1e-12);
+    assert!((ln_gamma(1.0)).abs() < 1e-13);
+
+    assert!((chi_square_sf(3.841_458_820_694_124, 1.0) - 0.05).abs() < 1e-12);
+    assert!((chi_square_sf(10.0, 4.0) - 0.040_427_681_994_512_805).abs() < 1e-12);
+    assert!((chi_square_sf(0.5, 3.0) - 0.918_891_411_654_676_5).abs() < 1e-12);
+    assert_eq!(chi_square_sf(0.0, 1.0), 1.0);
+}
+
+/// Fisher's lady tasting tea
+#[test]
+fn tea_tasting() {
+    let v1 = vec![1.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0];
+    let v2 = vec![1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+
+    let test = DistCorrelation.exact_test_both_binary(&v1, &v2).unwrap();
+    println!("Exact test: {:?}", test);
+
+    assert_eq!(
+        test.table,
+        ContingencyTable {
+            n00: 3,
+            n01: 1,
+            n10: 1,
+            n11: 3
+        }
+    );
+    assert!((test.statistic - 0.5).abs() < 1e-12);
+    assert!((test.chi_square - 2.0).abs() < 1e-12);
+    assert!((test.p_value_exact - 34.0 / 70.0).abs() < 1e-12);
+    assert!((test.p_value_chi_square - 0.157_299_207_050_285_1).abs() < 1e-12);
+}
+
+/// compare with a brute force enumeration of all tables with the same margins
+#[test]
+fn exact_enumeration() {
+    let binomial =
+        |n: u64, k: u64| (0..k).fold(1_u128, |acc, i| acc * (n - i) as u128 / (i + 1) as u128);
+
+    let tables = [
+        (7, 2, 4, 9),
+        (0, 5, 6, 1),
+        (12, 1, 0, 3),
+        (3, 3, 3, 3),
+        (20, 4, 9, 1),
+    ];
+
+    for (n00, n01, n10, n11) in tables {
+        let v1: Vec<f64> = [(0.0, n00 + n01), (1.0, n10 + n11)]
+            .iter()
+            .flat_map(|&(x, count)| std::iter::repeat_n(x, count))
+            .collect();
+        let v2: Vec<f64> = [(0.0, n00), (1.0, n01), (0.0, n10), (1.0, n11)]
+            .iter()
+            .flat_map(|&(x, count)| std::iter::repeat_n(x, count))
+            .collect();
+
+        let test = DistCorrelation.exact_test_both_binary(&v1, &v2).unwrap();
+
+        let (n, r1, c1) = (v1.len() as u64, (n10 + n11) as u64, (n01 + n11) as u64);
+        let (mut numerator, denominator) = (0_u128, binomial(n, c1));
+        for k in (r1 + c1).saturating_sub(n)..=r1.min(c1) {
+            let table = ContingencyTable {
+                n00: (n + k - r1 - c1) as usize,
+                n01: (c1 - k) as usize,
+                n10: (r1 - k) as usize,
+                n11: k as usize,
+            };
+            if table.dist_corr() >= test.statistic - 1e-12 {
+                numerator += binomial(r1, k) * binomial(n - r1, c1 - k);
+            }
+        }
+        let p_value = numerator as f64 / denominator as f64;
+
+        println!("Table: {:?}", test.table);
+        println!("Exact p-value: {:?} vs {:?}", test.p_value_exact, p_value);
+
+        assert!((test.p_value_exact - p_value).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn medium_binary() {
+    let sample_size = 2000;
+    let mut rng_1 = ChaCha8Rng::seed_from_u64(134);
+    let mut rng_2 = ChaCha8Rng::seed_from_u64(11);
+
+    let v1: Vec<f64> = (0..sample_size)
+        .map(move |_x| rng_1.random_range(-10.0..10.0))
+        .map(|x| if x < 0.0 { 0.0 } else { 1.0 })
+        .collect();
+    let v2: Vec<f64> = v1
+        .iter()
+        .map(|x| {
+            if rng_2.random_range(0.0..1.0) < 0.47 {
+                1.0 - x
+            } else {
+                *x
+            }
+        })
+        .collect();
+
+    let dist_correlation = DistCorrelation;
+    let test = dist_correlation.exact_test_both_binary(&v1, &v2).unwrap();
+    let dist_corr_binary = dist_correlation
+        .compute_binary(&v1, &v2, true, true)
+        .unwrap();
+    let permutation_test = dist_correlation
+        .permutation_test_binary(&v1, &v2, true, true, 9999, 1)
+        .unwrap();
+
+    println!("Exact test: {:?}", test);
+    println!("Permutation test: {:?}", permutation_test);
+
+    assert!((test.statistic - dist_corr_binary).abs() < 1e-12);
+    assert!((test.p_value_exact - permutation_test.p_value).abs() < 0.01);
+    assert!((test.p_value_exact - test.p_value_chi_square).abs() < 0.01);
+}
+
+#[test]
+fn degenerate() {
+    let v1 = vec![0.0, 1.0, 0.0, 1.0];
+    let v2 = vec![1.0, 1.0, 1.0, 1.0];
+
+    let test = DistCorrelation.exact_test_both_binary(&v1, &v2).unwrap();
+
+    assert_eq!(test.statistic, 0.0);
+    assert_eq!(test.p_value_exact, 1.0);
+    assert_eq!(test.p_value_chi_square, 1.0);
+}
+
+/// margins whose product exceeds the range of usize
+#[test]
+fn large_margins() {
+    let count = 1 << 33;
+    let table = ContingencyTable {
+        n00: count,
+        n01: count,
+        n10: count,
+        n11: count,
+    };
+
+    let test = BinaryIndependenceTest::from_table(table);
+
+    assert_eq!(test.statistic, 0.0);
+    assert_eq!(test.p_value_exact, 1.0);
+}
+
+#[test]
+fn errors() {
+    let dist_correlation = DistCorrelation;
+
+    assert!(dist_correlation
+        .exact_test_both_binary(&[0.0, 1.0, 2.0], &[1.0, 0.0, 1.0])
+        .is_err());
+    assert!(dist_correlation
+        .exact_test_both_binary(&[0.0, 1.0], &[1.0])
+        .is_err());
+    assert!(dist_correlation
+        .exact_test_both_binary(&[0.0; 0], &[0.0; 0])
+        .is_err());
+}
@@ -0,0 +1,182 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::config::Estimator;
+use crate::dist_corr_naive::{_dist_cov_sq_exact_ratio, _dist_cov_sq_u_exact_ratio};
+use crate::error::DistCorrError;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// dependent integer data with ties
+fn integer_data(seed: u64, len: usize, range: i64) -> (Vec<i64>, Vec<i64>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let k1: Vec<i64> = (0..len).map(|_| rng.random_range(-range..range)).collect();
+    let k2: Vec<i64> = k1
+        .iter()
+        .map(|k| k * k / range + rng.random_range(0..range))
+        .collect();
+
+    (k1, k2)
+}
+
+/// checks that x is the f64 closest to numerator / denominator, where 0 <= x < 2^52
+fn is_correctly_rounded(x: f64, numerator: i128, denominator: i128) -> bool {
+    if numerator == 0 {
+        return x == 0.0;
+    }
+
+    // x = mantissa * 2^exponent with a 53-bit mantissa
+    let bits = x.to_bits();
+    let mantissa = ((bits & ((1 << 52) - 1)) | (1 << 52)) as i128;
+    let exponent = ((bits >> 52) & 0x7ff) as i32 - 1075;
+
+    // the midpoints to the neighbours of x are odd multiples of powers of two
+    let (lower, lower_exponent) = if mantissa == 1 << 52 {
+        (4 * mantissa - 1, exponent - 2)
+    } else {
+        (2 * mantissa - 1, exponent - 1)
+    };
+    let (upper, upper_exponent) = (2 * mantissa + 1, exponent - 1);
+
+    // numerator / denominator >= c 2^e <=> numerator 2^-e >= c denominator for e < 0
+    let scaled = |c: i128, e: i32| {
+        let power = 1i128.checked_shl(-e as u32).unwrap();
+        (
+            numerator.checked_mul(power).unwrap(),
+            c.checked_mul(denominator).unwrap(),
+        )
+    };
+    let at_least = |c: i128, e: i32| scaled(c, e).0 >= scaled(c, e).1;
+    let at_most = |c: i128, e: i32| scaled(c, e).0 <= scaled(c, e).1;
+
+    at_least(lower, lower_exponent) && at_most(upper, upper_exponent)
+}
+
+/// the results are the correctly rounded exact rationals of both estimators
+#[test]
+fn correctly_rounded() {
+    for (seed, len, range) in [(0, 4, 3), (1, 50, 20), (2, 300, 1_000), (3, 1_000, 1 << 20)] {
+        let (k1, k2) = integer_data(seed, len, range);
+
+        let result = DistCovariance.compute_exact(&k1, &k2).unwrap();
+        assert_eq!(result.len, len);
+        for (value, (numerator, denominator)) in [
+            (result.dist_cov_sq, _dist_cov_sq_exact_ratio(&k1, &k2)),
+            (result.dist_var_sq_v1, _dist_cov_sq_exact_ratio(&k1, &k1)),
+            (result.dist_var_sq_v2, _dist_cov_sq_exact_ratio(&k2, &k2)),
+        ] {
+            assert!(is_correctly_rounded(value, numerator, denominator));
+            let next = f64::from_bits(value.to_bits() + 1);
+            assert!(!is_correctly_rounded(next, numerator, denominator));
+        }
+
+        let result = DistCovariance::builder()
+            .estimator(Estimator::BiasCorrected)
+            .build()
+            .compute_exact(&k1, &k2)
+            .unwrap();
+        for (value, (numerator, denominator)) in [
+            (result.dist_cov_sq, _dist_cov_sq_u_exact_ratio(&k1, &k2)),
+            (result.dist_var_sq_v1, _dist_cov_sq_u_exact_ratio(&k1, &k1)),
+        ] {
+            if numerator < 0 {
+                assert!(is_correctly_rounded(-value, -numerator, denominator));
+            } else {
+                assert!(is_correctly_rounded(value, numerator, denominator));
+            }
+        }
+    }
+
+    // the exact reference for small integers, where the division of f64 is correctly rounded
+    let (k1, k2) = integer_data(4, 30, 10);
+    let (numerator, denominator) = _dist_cov_sq_exact_ratio(&k1, &k2);
+    let result = DistCovariance.compute_exact(&k1, &k2).unwrap();
+    assert_eq!(result.dist_cov_sq, numerator as f64 / denominator as f64);
+}
+
+/// the results do not depend on the order of the observations, the offset, the integer type or
+/// the thread pool
+#[test]
+fn bit_exact() {
+    let (k1, k2) = integer_data(5, 50_000, 1 << 16);
+    let expected = DistCovariance.compute_exact(&k1, &k2).unwrap();
+
+    let mut rng = ChaCha8Rng::seed_from_u64(6);
+    let mut order: Vec<usize> = (0..k1.len()).collect();
+    order.shuffle(&mut rng);
+    let p1: Vec<i64> = order.iter().map(|&i| k1[i] + (1 << 40)).collect();
+    let p2: Vec<u32> = order.iter().map(|&i| k2[i] as u32).collect();
+
+    for parallel in [true, false] {
+        let dist_cov = DistCovariance::builder().parallel(parallel).build();
+        assert_eq!(dist_cov.compute_exact(&p1, &p2).unwrap(), expected);
+    }
+
+    // the accuracy mode of the floating-point computations agrees to many digits
+    let v1: Vec<f64> = k1.iter().map(|&k| k as f64).collect();
+    let v2: Vec<f64> = k2.iter().map(|&k| k as f64).collect();
+    let details = DistCorrelation::builder()
+        .accurate(true)
+        .build()
+        .compute_detailed(&v1, &v2, false, false)
+        .unwrap();
+    assert!(((details.dist_cov_sq - expected.dist_cov_sq) / expected.dist_cov_sq).abs() < 1e-12);
+}
+
+/// invalid inputs and ranges beyond the 128-bit accumulators are rejected
+#[test]
+fn errors() {
+    let dist_cov = DistCovariance;
+    let bias_corrected = DistCovariance::builder()
+        .estimator(Estimator::BiasCorrected)
+        .build();
+
+    assert_eq!(
+        dist_cov.compute_exact(&[1i64, 2], &[1u32]).unwrap_err(),
+        DistCorrError::LengthMismatch {
+            expected: 2,
+            found: 1
+        }
+    );
+    assert_eq!(
+        dist_cov.compute_exact::<i64, i64>(&[], &[]).unwrap_err(),
+        DistCorrError::EmptyInput
+    );
+    assert_eq!(
+        bias_corrected
+            .compute_exact(&[1, 2, 3], &[1, 2, 3])
+            .unwrap_err(),
+        DistCorrError::TooFewObservations {
+            required: 4,
+            found: 3
+        }
+    );
+    assert_eq!(
+        dist_cov
+            .compute_exact(&[i64::MIN, i64::MAX], &[0, 1])
+            .unwrap_err(),
+        DistCorrError::ExactRangeExceeded
+    );
+    assert_eq!(
+        dist_cov
+            .compute_exact(&[i128::MIN, i128::MAX], &[0, 1])
+            .unwrap_err(),
+        DistCorrError::ExactRangeExceeded
+    );
+
+    // constant vectors and the full range of u32
+    let result = dist_cov
+        .compute_exact(&[7u32; 5], &[0, 1, 2, 3, 4])
+        .unwrap();
+    assert_eq!(result.dist_cov_sq, 0.0);
+    assert_eq!(result.dist_var_sq_v1, 0.0);
+    let result = dist_cov
+        .compute_exact(&[0, u32::MAX], &[0, u32::MAX])
+        .unwrap();
+    assert_eq!(result.dist_cov_sq, (u32::MAX as f64 / 2.0).powi(2));
+}
@@ -0,0 +1,422 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::config::Estimator;
+use crate::dist_corr_exponent::ExponentSums;
+use crate::dist_corr_naive::{_dist_cov_sq_naive_exponent, _dist_cov_sq_u_naive_exponent};
+use crate::error::DistCorrError;
+use crate::prepared_vector::PreparedVector;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+fn random_vectors(seed: u64, len: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let v1: Vec<f64> = (0..len).map(|_| rng.random::<f64>()).collect();
+    let v2: Vec<f64> = v1
+        .iter()
+        .map(|x| (4.0 * x).cos() + 0.3 * rng.random::<f64>())
+        .collect();
+
+    (v1, v2)
+}
+
+/// the exponent one reproduces the default computations exactly
+#[test]
+fn exponent_one() {
+    let (v1, v2) = random_vectors(0, 500);
+
+    let dist_corr = DistCorrelation::builder().exponent(1.0).build();
+    let dist_cov = DistCovariance::builder().exponent(1.0).build();
+    assert_eq!(dist_corr, *DistCorrelation);
+    assert_eq!(dist_corr.exponent(), 1.0);
+    assert_eq!(dist_cov.exponent(), 1.0);
+
+    assert_eq!(
+        dist_corr.compute(&v1, &v2).unwrap(),
+        DistCorrelation.compute(&v1, &v2).unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute(&v1, &v2).unwrap(),
+        DistCovariance.compute(&v1, &v2).unwrap()
+    );
+
+    // the O(n^2) sums agree with the O(n log n) algorithms
+    let sums = ExponentSums::new(&v1, &v2, 1.0);
+    let details = DistCorrelation
+        .compute_detailed(&v1, &v2, false, false)
+        .unwrap();
+    let expected = DistCorrelation.compute_bias_corrected(&v1, &v2).unwrap();
+    assert!((sums.details().dist_corr - details.dist_corr).abs() < 1e-10);
+    assert!((sums.details().dist_cov_sq - details.dist_cov_sq).abs() < 1e-10);
+    assert!((sums.bias_corrected().dist_corr - expected.dist_corr).abs() < 1e-10);
+    assert!((sums.bias_corrected().dist_cov_sq - expected.dist_cov_sq).abs() < 1e-10);
+}
+
+/// other exponents agree with the naive computations
+#[test]
+fn naive() {
+    let (v1, v2) = random_vectors(1, 200);
+
+    for exponent in [0.3, 0.5, 1.5, 1.9] {
+        let dist_cov_sq = _dist_cov_sq_naive_exponent(&v1, &v2, exponent);
+        let dist_var_sq_v1 = _dist_cov_sq_naive_exponent(&v1, &v1, exponent);
+        let dist_var_sq_v2 = _dist_cov_sq_naive_exponent(&v2, &v2, exponent);
+        let dist_corr = (dist_cov_sq / (dist_var_sq_v1 * dist_var_sq_v2).sqrt()).sqrt();
+
+        let details = DistCorrelation::builder()
+            .exponent(exponent)
+            .build()
+            .compute_detailed(&v1, &v2, false, false)
+            .unwrap();
+        assert!((details.dist_cov_sq - dist_cov_sq).abs() < 1e-12);
+        assert!((details.dist_var_sq_v1 - dist_var_sq_v1).abs() < 1e-12);
+        assert!((details.dist_var_sq_v2 - dist_var_sq_v2).abs() < 1e-12);
+        assert!((details.dist_corr - dist_corr).abs() < 1e-10);
+
+        let dist_cov = DistCovariance::builder().exponent(exponent).build();
+        assert!((dist_cov.compute(&v1, &v2).unwrap() - dist_cov_sq.sqrt()).abs() < 1e-10);
+        assert!((dist_cov.compute_var(&v1).unwrap() - dist_var_sq_v1.sqrt()).abs() < 1e-10);
+
+        // the bias-corrected estimator
+        let dist_cov_sq_u = _dist_cov_sq_u_naive_exponent(&v1, &v2, exponent);
+        let dist_var_sq_u_v1 = _dist_cov_sq_u_naive_exponent(&v1, &v1, exponent);
+        let dist_var_sq_u_v2 = _dist_cov_sq_u_naive_exponent(&v2, &v2, exponent);
+
+        let details = DistCorrelation::builder()
+            .exponent(exponent)
+            .estimator(Estimator::BiasCorrected)
+            .build()
+            .compute_detailed(&v1, &v2, false, false)
+            .unwrap();
+        assert!((details.dist_cov_sq - dist_cov_sq_u).abs() < 1e-12);
+        assert!((details.dist_var_sq_v1 - dist_var_sq_u_v1).abs() < 1e-12);
+        let dist_corr_sq_u = dist_cov_sq_u / (dist_var_sq_u_v1 * dist_var_sq_u_v2).sqrt();
+        assert!((details.dist_corr - dist_corr_sq_u.sqrt()).abs() < 1e-10);
+    }
+}
+
+/// for exponents close to two the computations converge to the naive ones at exponent two
+#[test]
+fn exponent_two_limit() {
+    let (v1, v2) = random_vectors(2, 300);
+
+    let naive_dist_corr = |exponent: f64| {
+        let dist_cov_sq = _dist_cov_sq_naive_exponent(&v1, &v2, exponent);
+        let dist_var_sq_v1 = _dist_cov_sq_naive_exponent(&v1, &v1, exponent);
+        let dist_var_sq_v2 = _dist_cov_sq_naive_exponent(&v2, &v2, exponent);
+
+        (dist_cov_sq / (dist_var_sq_v1 * dist_var_sq_v2).sqrt()).sqrt()
+    };
+
+    for step in [1e-3, 1e-6, 1e-9] {
+        let exponent = 2.0 - step;
+        let details = DistCorrelation::builder()
+            .exponent(exponent)
+            .build()
+            .compute_detailed(&v1, &v2, false, false)
+            .unwrap();
+        let dist_cov_sq = _dist_cov_sq_naive_exponent(&v1, &v2, exponent);
+
+        // the sums agree with the naive computation at the same exponent
+        assert!((details.dist_cov_sq - dist_cov_sq).abs() < 1e-14);
+        assert!((details.dist_corr - naive_dist_corr(exponent)).abs() < 1e-12);
+
+        // and approach the naive computation at exponent two linearly in the step
+        let dist_cov_sq_two = _dist_cov_sq_naive_exponent(&v1, &v2, 2.0);
+        assert!((details.dist_cov_sq - dist_cov_sq_two).abs() < step);
+        assert!((details.dist_corr - naive_dist_corr(2.0)).abs() < step);
+    }
+}
+
+/// tied pairs are summed once with their multiplicity, which gives the same results as the
+/// naive computations
+#[test]
+fn ties() {
+    let mut rng = ChaCha8Rng::seed_from_u64(8);
+    let v1: Vec<f64> = (0..1_000).map(|_| rng.random_range(0..10) as f64).collect();
+    let v2: Vec<f64> = v1
+        .iter()
+        .map(|x| (x - 4.5).abs() + rng.random_range(0..3) as f64)
+        .collect();
+
+    for exponent in [0.5, 1.0, 1.5] {
+        let sums = ExponentSums::new(&v1, &v2, exponent);
+        let dist_cov_sq = _dist_cov_sq_naive_exponent(&v1, &v2, exponent);
+        let dist_cov_sq_u = _dist_cov_sq_u_naive_exponent(&v1, &v2, exponent);
+
+        assert!((sums.details().dist_cov_sq - dist_cov_sq).abs() < 1e-10);
+        assert!((sums.bias_corrected().dist_cov_sq - dist_cov_sq_u).abs() < 1e-10);
+        assert!(
+            (sums.details().dist_var_sq_v1 - _dist_cov_sq_naive_exponent(&v1, &v1, exponent)).abs()
+                < 1e-10
+        );
+    }
+}
+
+/// exponents outside of (0, 2) are rejected by every configured computation
+#[test]
+fn invalid_exponent() {
+    let (v1, v2) = random_vectors(3, 20);
+    let columns = [v1.clone(), v2.clone()];
+
+    for exponent in [0.0, 2.0, -1.0, 3.0, f64::NAN, f64::INFINITY] {
+        let dist_corr = DistCorrelation::builder().exponent(exponent).build();
+        let dist_cov = DistCovariance::builder().exponent(exponent).build();
+
+        let is_invalid = |error: DistCorrError| {
+            matches!(error, DistCorrError::InvalidExponent { exponent: e }
+                if e == exponent || (e.is_nan() && exponent.is_nan()))
+        };
+
+        assert!(is_invalid(dist_corr.compute(&v1, &v2).unwrap_err()));
+        assert!(is_invalid(
+            dist_corr
+                .compute_detailed(&v1, &v2, false, false)
+                .unwrap_err()
+        ));
+        assert!(is_invalid(
+            dist_corr.correlation_matrix(&columns).unwrap_err()
+        ));
+        assert!(is_invalid(dist_cov.compute(&v1, &v2).unwrap_err()));
+        assert!(is_invalid(dist_cov.compute_var(&v1).unwrap_err()));
+    }
+}
+
+/// for binary vectors the distances do not depend on the exponent
+#[test]
+fn binary() {
+    let mut rng = ChaCha8Rng::seed_from_u64(4);
+    let v1: Vec<f64> = (0..300).map(|_| rng.random_range(0..2) as f64).collect();
+    let v2: Vec<f64> = v1
+        .iter()
+        .map(|&x| {
+            if rng.random::<f64>() < 0.8 {
+                x
+            } else {
+                1.0 - x
+            }
+        })
+        .collect();
+
+    // the default result for two binary vectors is the absolute value of the phi coefficient
+    let phi = DistCorrelation
+        .compute_binary(&v1, &v2, true, true)
+        .unwrap();
+
+    for exponent in [0.5, 1.5] {
+        let dist_corr = DistCorrelation::builder().exponent(exponent).build();
+        assert!((dist_corr.compute(&v1, &v2).unwrap() - phi).abs() < 1e-12);
+        assert!((dist_corr.compute_binary(&v1, &v2, true, true).unwrap() - phi).abs() < 1e-12);
+    }
+}
+
+/// the batch computations evaluate every pair with the configured exponent
+#[test]
+fn batch() {
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+    let columns: Vec<Vec<f64>> = (0..4)
+        .map(|_| (0..60).map(|_| rng.random::<f64>()).collect())
+        .collect();
+
+    let dist_corr = DistCorrelation::builder().exponent(0.5).build();
+    let matrix = dist_corr.correlation_matrix(&columns).unwrap();
+    let results = dist_corr
+        .compute_one_vs_many(&columns[0], &columns)
+        .unwrap();
+
+    for i in 0..columns.len() {
+        for j in 0..columns.len() {
+            let expected = dist_corr.compute(&columns[i], &columns[j]).unwrap();
+            assert!((matrix.get(i, j) - expected).abs() < 1e-12);
+        }
+        let expected = dist_corr.compute(&columns[0], &columns[i]).unwrap();
+        assert!((results[i].as_ref().unwrap() - expected).abs() < 1e-12);
+    }
+
+    assert_ne!(
+        matrix.get(0, 1),
+        DistCorrelation.compute(&columns[0], &columns[1]).unwrap()
+    );
+}
+
+/// the bias-corrected, t-test and multivariate computations use the configured exponent
+#[test]
+fn other_methods() {
+    let (v1, v2) = random_vectors(6, 100);
+    let (z, _) = random_vectors(7, 100);
+
+    for exponent in [0.5, 1.3] {
+        let dist_corr = DistCorrelation::builder().exponent(exponent).build();
+        let dist_cov = DistCovariance::builder().exponent(exponent).build();
+        let bias_corrected = DistCorrelation::builder()
+            .exponent(exponent)
+            .estimator(Estimator::BiasCorrected);
+
+        let dist_cov_sq_u = _dist_cov_sq_u_naive_exponent(&v1, &v2, exponent);
+        let expected = dist_corr.compute_bias_corrected(&v1, &v2).unwrap();
+        assert!((expected.dist_cov_sq - dist_cov_sq_u).abs() < 1e-12);
+        assert!((dist_cov.compute_bias_corrected(&v1, &v2).unwrap() - dist_cov_sq_u).abs() < 1e-12);
+        assert_eq!(
+            dist_corr.t_test(&v1, &v2).unwrap().dist_corr_sq,
+            expected.dist_corr_sq
+        );
+
+        let partial = dist_corr.compute_partial(&v1, &v2, &z).unwrap();
+        assert_eq!(partial.dist_corr_xy, expected);
+        assert_eq!(
+            dist_cov.compute_partial(&v1, &v2, &z).unwrap(),
+            partial.partial_dist_cov
+        );
+
+        // univariate samples agree with the univariate computations
+        let partial_multi = dist_corr
+            .compute_partial_multi(&v1, 1, &v2, 1, &z, 1)
+            .unwrap();
+        assert!((partial_multi.partial_dist_corr - partial.partial_dist_corr).abs() < 1e-10);
+
+        let multi = dist_corr.compute_multi(&v1, 1, &v2, 1).unwrap();
+        assert!((multi - dist_corr.compute(&v1, &v2).unwrap()).abs() < 1e-12);
+        let multi = dist_cov.compute_multi(&v1, 1, &v2, 1).unwrap();
+        assert!((multi - dist_cov.compute(&v1, &v2).unwrap()).abs() < 1e-12);
+        let multi = bias_corrected
+            .build()
+            .compute_multi(&v1, 1, &v2, 1)
+            .unwrap();
+        assert!((multi - expected.dist_corr).abs() < 1e-12);
+
+        assert_ne!(
+            multi,
+            DistCorrelation
+                .compute_bias_corrected(&v1, &v2)
+                .unwrap()
+                .dist_corr
+        );
+    }
+}
+
+/// the binary and categorical computations without values do not depend on the exponent, the
+/// ones tied to the exponent one reject other exponents
+#[test]
+fn unsupported_exponent() {
+    let (v1, v2) = random_vectors(8, 60);
+    let flags: Vec<bool> = v1.iter().map(|&x| x > 0.5).collect();
+    let bits: Vec<f64> = v2.iter().map(|&x| f64::from(x > 0.8)).collect();
+    let bits_2: Vec<f64> = flags.iter().map(|&b| f64::from(b)).collect();
+    let labels: Vec<usize> = v1.iter().map(|&x| (3.0 * x) as usize).collect();
+    let labels_2: Vec<usize> = v2.iter().map(|&x| (2.0 * x) as usize).collect();
+    let counts: Vec<i64> = v1.iter().map(|&x| (100.0 * x) as i64).collect();
+    let prepared_1 = PreparedVector::new(&v1).unwrap();
+    let prepared_2 = PreparedVector::new(&v2).unwrap();
+
+    let dist_corr = DistCorrelation::builder().exponent(0.5).build();
+    let dist_cov = DistCovariance::builder().exponent(0.5).build();
+
+    assert_eq!(
+        dist_corr.compute_both_categorical(&labels, &labels_2),
+        DistCorrelation.compute_both_categorical(&labels, &labels_2)
+    );
+    assert_eq!(
+        dist_cov.compute_both_categorical(&labels, &labels_2),
+        DistCovariance.compute_both_categorical(&labels, &labels_2)
+    );
+    assert_eq!(
+        dist_corr.exact_test_both_binary(&bits, &bits_2),
+        DistCorrelation.exact_test_both_binary(&bits, &bits_2)
+    );
+    let flags_2: Vec<bool> = bits.iter().map(|&b| b == 1.0).collect();
+    let phi = DistCorrelation.compute_both_bool(&flags, &flags_2).unwrap();
+    assert!((dist_corr.compute_both_bool(&flags, &flags_2).unwrap() - phi).abs() < 1e-12);
+
+    // a boolean and a data vector use the exponent as the numeric computation
+    let expected = dist_corr.compute(&bits_2, &v2).unwrap();
+    assert!((dist_corr.compute_bool(&flags, &v2).unwrap() - expected).abs() < 1e-12);
+
+    let unsupported = DistCorrError::UnsupportedSetting {
+        setting: "exponent",
+    };
+    assert_eq!(
+        dist_corr.compute_categorical(&labels, &v2).unwrap_err(),
+        unsupported
+    );
+    assert_eq!(
+        dist_cov.compute_categorical(&labels, &v2).unwrap_err(),
+        unsupported
+    );
+    assert_eq!(
+        dist_corr
+            .compute_prepared(&prepared_1, &prepared_2)
+            .unwrap_err(),
+        unsupported
+    );
+    assert_eq!(
+        dist_cov
+            .compute_prepared(&prepared_1, &prepared_2)
+            .unwrap_err(),
+        unsupported
+    );
+    assert_eq!(
+        dist_corr
+            .compute_multi_projection(&v1, 1, &v2, 1, 10, 0)
+            .unwrap_err(),
+        unsupported
+    );
+    assert_eq!(
+        dist_cov
+            .compute_multi_projection(&v1, 1, &v2, 1, 10, 0)
+            .unwrap_err(),
+        unsupported
+    );
+    assert_eq!(
+        dist_corr.permutation_test(&v1, &v2, 10, 0).unwrap_err(),
+        unsupported
+    );
+    assert_eq!(
+        dist_corr
+            .permutation_test_binary(&bits, &v2, true, false, 10, 0)
+            .unwrap_err(),
+        unsupported
+    );
+    assert_eq!(
+        dist_cov.compute_exact(&counts, &counts).unwrap_err(),
+        unsupported
+    );
+
+    // invalid exponents are reported as such
+    let dist_corr = DistCorrelation::builder().exponent(2.0).build();
+    let dist_cov = DistCovariance::builder().exponent(2.0).build();
+    let invalid = DistCorrError::InvalidExponent { exponent: 2.0 };
+    assert_eq!(
+        dist_corr.compute_categorical(&labels, &v2).unwrap_err(),
+        invalid
+    );
+    assert_eq!(
+        dist_corr
+            .compute_both_categorical(&labels, &labels_2)
+            .unwrap_err(),
+        invalid
+    );
+    assert_eq!(
+        dist_corr.compute_multi(&v1, 1, &v2, 1).unwrap_err(),
+        invalid
+    );
+    assert_eq!(
+        dist_corr
+            .compute_partial_multi(&v1, 1, &v2, 1, &v1, 1)
+            .unwrap_err(),
+        invalid
+    );
+    assert_eq!(dist_corr.t_test(&v1, &v2).unwrap_err(), invalid);
+    assert_eq!(
+        dist_corr.permutation_test(&v1, &v2, 10, 0).unwrap_err(),
+        invalid
+    );
+    assert_eq!(
+        dist_cov.compute_exact(&counts, &counts).unwrap_err(),
+        invalid
+    );
+}
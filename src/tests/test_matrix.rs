@@ -0,0 +1,112 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::DistCorrelation;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn correlation_matrix() {
+    let columns = sample(13, 250, 3);
+    let matrix = DistCorrelation.correlation_matrix(&columns).unwrap();
+
+    assert_eq!(matrix.dim(), 13);
+    assert_eq!(matrix.packed().len(), 13 * 14 / 2);
+
+    for i in 0..13 {
+        for j in 0..13 {
+            let dist_corr = DistCorrelation.compute(&columns[i], &columns[j]).unwrap();
+
+            assert!((matrix.get(i, j) - dist_corr).abs() < 1e-10);
+            assert_eq!(matrix.get(i, j), matrix.get(j, i));
+        }
+        assert_eq!(matrix.row(i), matrix.column(i));
+        assert_eq!(matrix.get(i, i), 1.0);
+    }
+
+    assert_eq!(matrix.to_dense()[4], matrix.row(4));
+}
+
+#[test]
+fn cross_correlation_matrix() {
+    let list_1 = sample(5, 300, 1);
+    let list_2 = sample(7, 300, 2);
+
+    let matrix = DistCorrelation
+        .cross_correlation_matrix(&list_1, &list_2)
+        .unwrap();
+
+    assert_eq!(matrix.len(), 5);
+    for (v_1, row) in list_1.iter().zip(&matrix) {
+        assert_eq!(row.len(), 7);
+        for (v_2, entry) in list_2.iter().zip(row) {
+            let dist_corr = DistCorrelation.compute(v_1, v_2).unwrap();
+            assert!((entry - dist_corr).abs() < 1e-10);
+        }
+    }
+}
+
+#[test]
+fn degenerate() {
+    let columns = [
+        vec![1.0, 2.0, 3.0],
+        vec![5.0, 5.0, 5.0],
+        vec![0.0, 1.0, 0.0],
+    ];
+    let matrix = DistCorrelation.correlation_matrix(&columns).unwrap();
+
+    assert_eq!(matrix.row(1), vec![0.0, 0.0, 0.0]);
+    assert_eq!(matrix.get(2, 2), 1.0);
+
+    let empty: [Vec<f64>; 0] = [];
+    assert_eq!(DistCorrelation.correlation_matrix(&empty).unwrap().dim(), 0);
+    assert!(DistCorrelation
+        .cross_correlation_matrix(&empty, &columns)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let columns = [[1.0, 2.0, 3.0], [2.0, 1.0, 0.0]];
+    let matrix = DistCorrelation.correlation_matrix(&columns).unwrap();
+
+    matrix.get(0, 2);
+}
+
+#[test]
+fn errors() {
+    let dist_correlation = DistCorrelation;
+
+    assert!(dist_correlation
+        .correlation_matrix(&[vec![1.0, 2.0], vec![1.0]])
+        .is_err());
+    assert!(dist_correlation
+        .correlation_matrix(&[Vec::<f64>::new()])
+        .is_err());
+    assert!(dist_correlation
+        .cross_correlation_matrix(&[[1.0, 2.0]], &[[1.0, 2.0, 3.0]])
+        .is_err());
+}
+
+/// random vectors with a common latent component
+fn sample(numb: usize, sample_size: usize, seed: u64) -> Vec<Vec<f64>> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let latent: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-1.0..1.0))
+        .collect();
+
+    (0..numb)
+        .map(|k| {
+            latent
+                .iter()
+                .map(|x| (x * (k + 1) as f64).sin() + rng.random_range(-1.0..1.0))
+                .collect()
+        })
+        .collect()
+}
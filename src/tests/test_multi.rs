@@ -1,4 +1,5 @@
-use crate::dist_corr_multi::{_dist_cov_multi, _dist_cov_multi_exp};
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::dist_corr_naive::{_dist_cov_multi_exp, _dist_cov_sq_multi_naive};
 
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
@@ -22,10 +23,10 @@ fn simple() {
 
     println!("Dist corr exp: {:?}", dist_corr);
 
-    let dist_cov_stand = _dist_cov_multi(&v1, &v2);
+    let dist_cov_stand = _dist_cov_sq_multi_naive(&v1, &v2);
     println!("dist cov stand: {:?}", dist_cov_stand);
-    let dist_cov_stand_v1 = _dist_cov_multi(&v1, &v1);
-    let dist_cov_stand_v2 = _dist_cov_multi(&v2, &v2);
+    let dist_cov_stand_v1 = _dist_cov_sq_multi_naive(&v1, &v1);
+    let dist_cov_stand_v2 = _dist_cov_sq_multi_naive(&v2, &v2);
 
     let dist_corr = (dist_cov_stand / (dist_cov_stand_v1 * dist_cov_stand_v2).sqrt()).sqrt();
 
@@ -64,10 +65,10 @@ fn independent() {
 
     println!("Dist corr exp: {:?}", dist_corr);
 
-    let dist_cov_stand = _dist_cov_multi(&v1, &v2);
+    let dist_cov_stand = _dist_cov_sq_multi_naive(&v1, &v2);
     println!("dist cov stand: {:?}", dist_cov_stand);
-    let dist_cov_stand_v1 = _dist_cov_multi(&v1, &v1);
-    let dist_cov_stand_v2 = _dist_cov_multi(&v2, &v2);
+    let dist_cov_stand_v1 = _dist_cov_sq_multi_naive(&v1, &v1);
+    let dist_cov_stand_v2 = _dist_cov_sq_multi_naive(&v2, &v2);
 
     let dist_corr = (dist_cov_stand / (dist_cov_stand_v1 * dist_cov_stand_v2).sqrt()).sqrt();
 
@@ -95,10 +96,10 @@ fn medium() {
 
     println!("Dist corr exp: {:?}", dist_corr);
 
-    let dist_cov_stand = _dist_cov_multi(&v1, &v2);
+    let dist_cov_stand = _dist_cov_sq_multi_naive(&v1, &v2);
     println!("dist cov stand: {:?}", dist_cov_stand);
-    let dist_cov_stand_v1 = _dist_cov_multi(&v1, &v1);
-    let dist_cov_stand_v2 = _dist_cov_multi(&v2, &v2);
+    let dist_cov_stand_v1 = _dist_cov_sq_multi_naive(&v1, &v1);
+    let dist_cov_stand_v2 = _dist_cov_sq_multi_naive(&v2, &v2);
 
     let dist_corr = (dist_cov_stand / (dist_cov_stand_v1 * dist_cov_stand_v2).sqrt()).sqrt();
 
@@ -129,10 +130,10 @@ fn independent_medium() {
 
     println!("Dist corr exp: {:?}", dist_corr);
 
-    let dist_cov_stand = _dist_cov_multi(&v1, &v2);
+    let dist_cov_stand = _dist_cov_sq_multi_naive(&v1, &v2);
     println!("dist cov stand: {:?}", dist_cov_stand);
-    let dist_cov_stand_v1 = _dist_cov_multi(&v1, &v1);
-    let dist_cov_stand_v2 = _dist_cov_multi(&v2, &v2);
+    let dist_cov_stand_v1 = _dist_cov_sq_multi_naive(&v1, &v1);
+    let dist_cov_stand_v2 = _dist_cov_sq_multi_naive(&v2, &v2);
 
     let dist_corr = (dist_cov_stand / (dist_cov_stand_v1 * dist_cov_stand_v2).sqrt()).sqrt();
 
@@ -162,12 +163,98 @@ fn independent_medium_2() {
 
     println!("Dist corr exp: {:?}", dist_corr);
 
-    let dist_cov_stand = _dist_cov_multi(&v1, &v2);
+    let dist_cov_stand = _dist_cov_sq_multi_naive(&v1, &v2);
     println!("dist cov stand: {:?}", dist_cov_stand);
-    let dist_cov_stand_v1 = _dist_cov_multi(&v1, &v1);
-    let dist_cov_stand_v2 = _dist_cov_multi(&v2, &v2);
+    let dist_cov_stand_v1 = _dist_cov_sq_multi_naive(&v1, &v1);
+    let dist_cov_stand_v2 = _dist_cov_sq_multi_naive(&v2, &v2);
 
     let dist_corr = (dist_cov_stand / (dist_cov_stand_v1 * dist_cov_stand_v2).sqrt()).sqrt();
 
     println!("Dist corr standard: {:?}", dist_corr);
 }
+
+#[test]
+fn compute_multi_naive() {
+    let sample_size = 301;
+    let (dim_x, dim_y) = (3, 2);
+    let mut rng = ChaCha8Rng::seed_from_u64(134);
+
+    let x: Vec<f64> = (0..sample_size * dim_x)
+        .map(|_x| rng.random_range(-1.0..1.0))
+        .collect();
+    let y: Vec<f64> = x
+        .chunks_exact(dim_x)
+        .flat_map(|row| {
+            [
+                row[0] * row[1],
+                (row[2] + rng.random_range(-1.0..1.0)).abs(),
+            ]
+        })
+        .collect();
+
+    let rows_x: Vec<Vec<f64>> = x.chunks_exact(dim_x).map(|row| row.to_vec()).collect();
+    let rows_y: Vec<Vec<f64>> = y.chunks_exact(dim_y).map(|row| row.to_vec()).collect();
+
+    let dist_cov = DistCovariance.compute_multi(&x, dim_x, &y, dim_y).unwrap();
+    let dist_corr = DistCorrelation.compute_multi(&x, dim_x, &y, dim_y).unwrap();
+
+    let dist_cov_naive = _dist_cov_sq_multi_naive(&rows_x, &rows_y).sqrt();
+    let dist_var_x = _dist_cov_sq_multi_naive(&rows_x, &rows_x).sqrt();
+    let dist_var_y = _dist_cov_sq_multi_naive(&rows_y, &rows_y).sqrt();
+    let dist_corr_naive = dist_cov_naive / (dist_var_x * dist_var_y).sqrt();
+
+    println!("Dist corr multi: {:?}", dist_corr);
+    println!("Dist corr multi naive: {:?}", dist_corr_naive);
+
+    assert!((dist_cov - dist_cov_naive).abs() < 1e-10);
+    assert!((dist_corr - dist_corr_naive).abs() < 1e-10);
+}
+
+/// for one dimensional samples the multivariate and univariate algorithms coincide
+#[test]
+fn compute_multi_univariate() {
+    let test_sizes = [2, 17, 64, 597];
+
+    for sample_size in test_sizes {
+        let mut rng = ChaCha8Rng::seed_from_u64(21);
+        let v1: Vec<f64> = (0..sample_size)
+            .map(|_x| rng.random_range(-10.0..10.0))
+            .collect();
+        let v2: Vec<f64> = v1.iter().map(|x| x.sin()).collect();
+
+        let dist_corr = DistCorrelation.compute(&v1, &v2).unwrap();
+        let dist_corr_multi = DistCorrelation.compute_multi(&v1, 1, &v2, 1).unwrap();
+        let dist_cov = DistCovariance.compute(&v1, &v2).unwrap();
+        let dist_cov_multi = DistCovariance.compute_multi(&v1, 1, &v2, 1).unwrap();
+
+        assert!((dist_corr - dist_corr_multi).abs() < 1e-10);
+        assert!((dist_cov - dist_cov_multi).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn compute_multi_degenerate() {
+    let x = vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0];
+    let y = vec![0.0, 1.0, 3.0];
+
+    assert_eq!(DistCorrelation.compute_multi(&x, 2, &y, 1).unwrap(), 0.0);
+    assert_eq!(
+        DistCorrelation.compute_multi(&[1.0], 1, &[2.0], 1).unwrap(),
+        0.0
+    );
+}
+
+#[test]
+fn compute_multi_errors() {
+    let x = vec![1.0, 2.0, 3.0, 4.0];
+    let y = vec![1.0, 2.0];
+
+    assert!(DistCorrelation.compute_multi(&x, 0, &y, 1).is_err());
+    assert!(DistCorrelation.compute_multi(&x, 3, &y, 1).is_err());
+    assert!(DistCorrelation.compute_multi(&x, 2, &y, 2).is_err());
+    assert!(DistCovariance.compute_multi(&x, 1, &y, 1).is_err());
+    assert!(DistCovariance
+        .compute_multi(&[0.0; 0], 1, &[0.0; 0], 1)
+        .is_err());
+    assert!(DistCovariance.compute_multi(&x, 2, &y, 1).is_ok());
+}
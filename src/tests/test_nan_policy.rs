@@ -0,0 +1,222 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::auto_detection::BinaryDetection;
+use crate::error::DistCorrError;
+use crate::nan_policy::NanPolicy;
+use crate::prepared_vector::PreparedVector;
+use crate::screening::ScreeningCutoff;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// non-finite values are rejected on every path instead of panicking
+#[test]
+fn reject() {
+    let dist_correlation = DistCorrelation;
+    let distance_covariance = DistCovariance;
+
+    let v_real = vec![0.5, 2.0, 1.0, -0.3, 4.0];
+    let v_bin = vec![0.0, 1.0, 1.0, 0.0, 1.0];
+
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let mut v_bad = v_real.clone();
+        v_bad[3] = value;
+        let mut v_bin_bad = v_bin.clone();
+        v_bin_bad[3] = value;
+
+        let is_non_finite = |result: Result<f64, DistCorrError>| {
+            matches!(result, Err(DistCorrError::NonFinite { index: 3, .. }))
+        };
+
+        assert!(is_non_finite(dist_correlation.compute(&v_bad, &v_real)));
+        assert!(is_non_finite(dist_correlation.compute(&v_real, &v_bad)));
+        assert!(is_non_finite(distance_covariance.compute(&v_bad, &v_real)));
+        assert!(is_non_finite(distance_covariance.compute_var(&v_bad)));
+
+        // binary and general paths are validated alike
+        assert!(is_non_finite(
+            dist_correlation.compute_binary(&v_bin_bad, &v_real, true, false)
+        ));
+        assert!(is_non_finite(
+            dist_correlation.compute_binary(&v_bin, &v_bad, true, false)
+        ));
+        assert!(is_non_finite(
+            distance_covariance.compute_binary(&v_bin, &v_bin_bad, true, true)
+        ));
+        assert!(is_non_finite(
+            dist_correlation
+                .compute_auto(&v_bin_bad, &v_real, BinaryDetection::TwoValued)
+                .map(|result| result.value)
+        ));
+        assert!(is_non_finite(
+            dist_correlation.compute_bool(&[true, false, true, true, false], &v_bad)
+        ));
+        assert!(is_non_finite(
+            dist_correlation.compute_categorical(&[0, 1, 2, 0, 1], &v_bad)
+        ));
+
+        assert!(is_non_finite(
+            dist_correlation
+                .compute_bias_corrected(&v_bad, &v_real)
+                .map(|result| result.dist_corr)
+        ));
+        assert!(is_non_finite(
+            dist_correlation
+                .permutation_test(&v_bad, &v_real, 10, 0)
+                .map(|test| test.p_value)
+        ));
+        assert!(is_non_finite(
+            dist_correlation
+                .exact_test_both_binary(&v_bin, &v_bin_bad)
+                .map(|test| test.p_value_exact)
+        ));
+        assert!(is_non_finite(
+            PreparedVector::new(&v_bad).map(|prepared| prepared.dist_var())
+        ));
+        assert!(is_non_finite(
+            dist_correlation
+                .correlation_matrix(&[&v_real, &v_bad])
+                .map(|matrix| matrix.get(0, 1))
+        ));
+        assert!(is_non_finite(
+            dist_correlation
+                .screen(&v_real, &[&v_bad], ScreeningCutoff::All)
+                .map(|screened| screened[0].dist_corr)
+        ));
+        assert!(is_non_finite(
+            dist_correlation.compute_multi(&v_bad, 1, &v_real, 1)
+        ));
+
+        let results = dist_correlation
+            .compute_one_vs_many(&v_real, &[&v_real, &v_bad])
+            .unwrap();
+        assert!(results[0].is_ok());
+        assert!(is_non_finite(results[1].clone()));
+    }
+}
+
+#[test]
+fn pairwise_deletion() {
+    let mut rng = ChaCha8Rng::seed_from_u64(11);
+
+    let mut v1: Vec<f64> = (0..200).map(|_i| rng.random_range(-1.0..1.0)).collect();
+    let mut v2: Vec<f64> = v1.iter().map(|x| x * x).collect();
+
+    let (v1_complete, v2_complete): (Vec<f64>, Vec<f64>) = v1
+        .iter()
+        .zip(&v2)
+        .enumerate()
+        .filter(|(i, _)| i % 7 != 0 && i % 11 != 0)
+        .map(|(_, (a, b))| (*a, *b))
+        .unzip();
+
+    for i in 0..200 {
+        if i % 7 == 0 {
+            v1[i] = f64::NAN;
+        }
+        if i % 11 == 0 {
+            v2[i] = if i % 2 == 0 { f64::INFINITY } else { f64::NAN };
+        }
+    }
+
+    let dist_corr = DistCorrelation
+        .compute_with_nan_policy(&v1, &v2, NanPolicy::PairwiseDeletion)
+        .unwrap();
+    let dist_cov = DistCovariance
+        .compute_with_nan_policy(&v1, &v2, NanPolicy::PairwiseDeletion)
+        .unwrap();
+
+    assert_eq!(
+        dist_corr,
+        DistCorrelation.compute(&v1_complete, &v2_complete).unwrap()
+    );
+    assert_eq!(
+        dist_cov,
+        DistCovariance.compute(&v1_complete, &v2_complete).unwrap()
+    );
+
+    // no complete pair is left
+    assert_eq!(
+        DistCorrelation.compute_with_nan_policy(
+            &[f64::NAN, 1.0],
+            &[1.0, f64::NAN],
+            NanPolicy::PairwiseDeletion
+        ),
+        Err(DistCorrError::EmptyInput)
+    );
+}
+
+/// labels of deleted values are dropped together with the values
+#[test]
+fn pairwise_deletion_categorical() {
+    let labels = vec![0, 1, 2, 0, 1, 2, 0, 1];
+    let values = vec![0.5, f64::NAN, 2.0, 1.0, 3.5, f64::INFINITY, -1.0, 4.0];
+
+    let dist_correlation = DistCorrelation::builder()
+        .nan_policy(NanPolicy::PairwiseDeletion)
+        .build();
+
+    assert_eq!(
+        dist_correlation
+            .compute_categorical(&labels, &values)
+            .unwrap(),
+        DistCorrelation
+            .compute_categorical(&[0, 2, 0, 1, 0, 1], &[0.5, 2.0, 1.0, 3.5, -1.0, 4.0])
+            .unwrap()
+    );
+    assert_eq!(
+        dist_correlation.compute_categorical(&[0, 1], &[f64::NAN, f64::INFINITY]),
+        Err(DistCorrError::EmptyInput)
+    );
+}
+
+#[test]
+fn propagate() {
+    let v1 = vec![1.0, 2.0, 3.0, 4.0];
+    let v2 = vec![1.0, 4.0, 9.0, f64::NEG_INFINITY];
+
+    assert!(DistCorrelation
+        .compute_with_nan_policy(&v1, &v2, NanPolicy::Propagate)
+        .unwrap()
+        .is_nan());
+    assert!(DistCovariance
+        .compute_with_nan_policy(&v2, &v1, NanPolicy::Propagate)
+        .unwrap()
+        .is_nan());
+
+    // finite data is unaffected by the policy
+    for nan_policy in [
+        NanPolicy::Reject,
+        NanPolicy::PairwiseDeletion,
+        NanPolicy::Propagate,
+    ] {
+        assert_eq!(
+            DistCorrelation
+                .compute_with_nan_policy(&v1, &v1, nan_policy)
+                .unwrap(),
+            DistCorrelation.compute(&v1, &v1).unwrap()
+        );
+    }
+}
+
+#[test]
+fn reject_policy() {
+    assert_eq!(NanPolicy::default(), NanPolicy::Reject);
+
+    assert!(matches!(
+        DistCovariance.compute_with_nan_policy(
+            &[1.0, 2.0, 3.0],
+            &[1.0, f64::NAN, 3.0],
+            NanPolicy::Reject
+        ),
+        Err(DistCorrError::NonFinite { index: 1, value }) if value.is_nan()
+    ));
+    assert!(DistCorrelation
+        .compute_with_nan_policy(&[1.0, 2.0], &[1.0], NanPolicy::PairwiseDeletion)
+        .is_err());
+}
@@ -0,0 +1,172 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use std::borrow::Cow;
+
+use ndarray::{s, Array2, ShapeBuilder};
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::ndarray_api::{column_views, rows_to_slice, view_to_slice};
+use crate::numeric::Column;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// row-major and column-major arrays with the same random dependent columns
+fn arrays(seed: u64, rows: usize, cols: usize) -> (Array2<f64>, Array2<f64>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let row_major = Array2::from_shape_fn((rows, cols), |(i, j)| {
+        (i as f64 * 0.01 * (j + 1) as f64).sin() + rng.random::<f64>()
+    });
+
+    let mut column_major = Array2::zeros((rows, cols).f());
+    column_major.assign(&row_major);
+
+    (row_major, column_major)
+}
+
+/// contiguous views are borrowed, strided views are copied
+#[test]
+fn borrowing() {
+    let (row_major, column_major) = arrays(0, 50, 3);
+
+    assert!(matches!(
+        view_to_slice(column_major.column(1)),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(view_to_slice(row_major.row(1)), Cow::Borrowed(_)));
+    assert!(matches!(view_to_slice(row_major.column(1)), Cow::Owned(_)));
+    assert_eq!(
+        view_to_slice(row_major.column(1)),
+        view_to_slice(column_major.column(1))
+    );
+
+    let columns = column_views(column_major.view());
+    let strided = column_views(row_major.view());
+    assert!(columns
+        .iter()
+        .all(|c| matches!(c.values(), Cow::Borrowed(_))));
+    assert!(strided.iter().all(|c| matches!(c.values(), Cow::Owned(_))));
+    for (column, strided) in columns.iter().zip(&strided) {
+        assert_eq!((column.len(), column.values()), (50, strided.values()));
+    }
+
+    assert!(matches!(rows_to_slice(row_major.view()), Cow::Borrowed(_)));
+    assert_eq!(
+        rows_to_slice(column_major.view()),
+        rows_to_slice(row_major.view())
+    );
+
+    let values = view_to_slice(row_major.slice(s![..;-2, 0]));
+    let expected: Vec<f64> = (0..25).map(|i| row_major[[49 - 2 * i, 0]]).collect();
+    assert_eq!(values, expected);
+}
+
+/// the pairwise computations on views agree with the ones on slices
+#[test]
+fn pairwise() {
+    let (row_major, column_major) = arrays(1, 500, 2);
+    let v1 = row_major.column(0).to_vec();
+    let v2 = row_major.column(1).to_vec();
+
+    let expected = DistCorrelation.compute(&v1, &v2).unwrap();
+    for data in [&row_major, &column_major] {
+        let result = DistCorrelation
+            .compute_array(data.column(0), data.column(1))
+            .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    let expected = DistCovariance.compute(&v1, &v2).unwrap();
+    let result = DistCovariance
+        .compute_array(row_major.column(0), column_major.column(1))
+        .unwrap();
+    assert_eq!(result, expected);
+
+    let expected = DistCovariance.compute_var(&v1).unwrap();
+    assert_eq!(
+        DistCovariance
+            .compute_var_array(row_major.column(0))
+            .unwrap(),
+        expected
+    );
+
+    // other numeric types
+    let counts = row_major.mapv(|x| (10.0 * x) as i32);
+    let counts_f64 = counts.mapv(f64::from);
+    assert_eq!(
+        DistCorrelation
+            .compute_array(counts.column(0), row_major.column(1))
+            .unwrap(),
+        DistCorrelation
+            .compute_array(counts_f64.column(0), row_major.column(1))
+            .unwrap()
+    );
+
+    // length mismatch
+    assert!(DistCorrelation
+        .compute_array(row_major.column(0), row_major.row(0))
+        .is_err());
+}
+
+/// the matrix, one-vs-many and multivariate computations use the columns of the views
+#[test]
+fn batch() {
+    let (row_major, column_major) = arrays(2, 300, 4);
+    let columns: Vec<Vec<f64>> = row_major
+        .columns()
+        .into_iter()
+        .map(|c| c.to_vec())
+        .collect();
+
+    let expected = DistCorrelation.correlation_matrix(&columns).unwrap();
+    for data in [&row_major, &column_major] {
+        let matrix = DistCorrelation
+            .correlation_matrix_array(data.view())
+            .unwrap();
+        assert_eq!(matrix, expected);
+    }
+
+    let expected = DistCorrelation
+        .cross_correlation_matrix(&columns[..2], &columns[2..])
+        .unwrap();
+    let result = DistCorrelation
+        .cross_correlation_matrix_array(
+            row_major.slice(s![.., ..2]),
+            column_major.slice(s![.., 2..]),
+        )
+        .unwrap();
+    assert_eq!(result, expected);
+
+    let expected = DistCorrelation
+        .compute_one_vs_many(&columns[0], &columns[1..])
+        .unwrap();
+    let result = DistCorrelation
+        .compute_one_vs_many_array(row_major.column(0), row_major.slice(s![.., 1..]))
+        .unwrap();
+    assert_eq!(result, expected);
+
+    let x: Vec<f64> = row_major.slice(s![.., ..2]).iter().copied().collect();
+    let y: Vec<f64> = row_major.slice(s![.., 2..]).iter().copied().collect();
+    let expected = DistCorrelation.compute_multi(&x, 2, &y, 2).unwrap();
+    let result = DistCorrelation
+        .compute_multi_array(
+            column_major.slice(s![.., ..2]),
+            row_major.slice(s![.., 2..]),
+        )
+        .unwrap();
+    assert_eq!(result, expected);
+
+    let expected = DistCovariance.compute_multi(&x, 2, &y, 2).unwrap();
+    let result = DistCovariance
+        .compute_multi_array(row_major.slice(s![.., ..2]), row_major.slice(s![.., 2..]))
+        .unwrap();
+    assert_eq!(result, expected);
+
+    // views without columns
+    assert!(DistCorrelation
+        .compute_multi_array(row_major.slice(s![.., ..0]), row_major.view())
+        .is_err());
+}
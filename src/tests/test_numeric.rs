@@ -0,0 +1,195 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use std::borrow::Cow;
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{
+    ConfiguredDistCorrelation, ConfiguredDistCovariance, DistCorrelation, DistCovariance,
+};
+use crate::error::DistCorrError;
+use crate::nan_policy::NanPolicy;
+use crate::numeric::Numeric;
+use crate::prepared_vector::PreparedVector;
+use crate::screening::ScreeningCutoff;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+fn to_f64<T: Numeric>(v: &[T]) -> Vec<f64> {
+    v.iter().map(|x| x.to_f64()).collect()
+}
+
+/// inputs of other numeric types give the results of their f64 conversions, the latter also
+/// through the f64 methods of the unit structs
+#[test]
+fn pairwise() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let v_f32: Vec<f32> = (0..2_000).map(|_| rng.random::<f32>()).collect();
+    let v_i32: Vec<i32> = v_f32
+        .iter()
+        .map(|x| (100.0 * x.sin()) as i32 + rng.random_range(-20..20))
+        .collect();
+    let v_u8: Vec<u8> = v_f32.iter().map(|&x| (x > 0.4) as u8).collect();
+    let (f_f32, f_i32, f_u8) = (to_f64(&v_f32), to_f64(&v_i32), to_f64(&v_u8));
+
+    let dist_corr = ConfiguredDistCorrelation::default();
+    let dist_cov = ConfiguredDistCovariance::default();
+
+    assert_eq!(
+        dist_corr.compute(&v_f32, &v_i32).unwrap(),
+        DistCorrelation.compute(&f_f32, &f_i32).unwrap()
+    );
+    assert_eq!(
+        dist_corr
+            .compute_binary(&v_u8, &v_f32, true, false)
+            .unwrap(),
+        DistCorrelation
+            .compute_binary(&f_u8, &f_f32, true, false)
+            .unwrap()
+    );
+    assert_eq!(
+        dist_corr
+            .compute_detailed(&v_i32, &v_u8, false, true)
+            .unwrap(),
+        dist_corr
+            .compute_detailed(&f_i32, &f_u8, false, true)
+            .unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute(&v_i32, &v_u8).unwrap(),
+        DistCovariance.compute(&f_i32, &f_u8).unwrap()
+    );
+    assert_eq!(
+        dist_cov.compute_var(&v_i32).unwrap(),
+        DistCovariance.compute_var(&f_i32).unwrap()
+    );
+
+    let x: Vec<i16> = v_i32.iter().map(|&x| x as i16).collect();
+    assert_eq!(
+        dist_cov.compute_multi(&x, 2, &v_f32, 2).unwrap(),
+        dist_cov.compute_multi(&to_f64(&x), 2, &f_f32, 2).unwrap()
+    );
+
+    assert_eq!(
+        dist_corr.compute_bias_corrected(&v_f32, &v_i32).unwrap(),
+        dist_corr.compute_bias_corrected(&f_f32, &f_i32).unwrap()
+    );
+    assert_eq!(
+        dist_corr.compute_partial(&v_f32, &v_i32, &v_u8).unwrap(),
+        dist_corr.compute_partial(&f_f32, &f_i32, &f_u8).unwrap()
+    );
+    assert_eq!(
+        dist_corr.permutation_test(&v_i32, &v_f32, 19, 3).unwrap(),
+        dist_corr.permutation_test(&f_i32, &f_f32, 19, 3).unwrap()
+    );
+    let labels: Vec<usize> = v_u8.iter().map(|&x| x as usize).collect();
+    assert_eq!(
+        dist_corr.compute_categorical(&labels, &v_i32).unwrap(),
+        dist_corr.compute_categorical(&labels, &f_i32).unwrap()
+    );
+
+    assert_eq!(u8::MAX.to_f64(), 255.0);
+    assert_eq!(f32::MIN_POSITIVE.to_f64(), f32::MIN_POSITIVE as f64);
+}
+
+/// integers beyond 2^53 in magnitude are rejected instead of rounded
+#[test]
+fn inexact() {
+    let exact = [1_i64, 2, -(1 << 53), 1 << 53];
+    let inexact = [1_i64, 2, 3, (1 << 53) + 1];
+    let v = [0.5, 1.5, 2.5, 3.5];
+    let error = DistCorrError::InexactConversion { index: 3 };
+
+    assert!((1_u64 << 53).is_exact() && !(1_u64 << 53 | 1).is_exact());
+    assert!(!i64::MIN.is_exact());
+
+    let dist_corr = ConfiguredDistCorrelation::default();
+    let dist_cov = ConfiguredDistCovariance::default();
+
+    assert!(dist_corr.compute(&exact, &v).is_ok());
+    assert_eq!(dist_corr.compute(&inexact, &v).unwrap_err(), error);
+    assert_eq!(dist_corr.compute(&v, &inexact).unwrap_err(), error);
+    for nan_policy in [NanPolicy::PairwiseDeletion, NanPolicy::Propagate] {
+        let result = dist_corr.compute_with_nan_policy(&inexact, &v, nan_policy);
+        assert_eq!(result.unwrap_err(), error);
+    }
+    assert_eq!(dist_cov.compute_var(&inexact).unwrap_err(), error);
+    assert_eq!(
+        dist_corr.compute_multi(&inexact, 1, &v, 1).unwrap_err(),
+        error
+    );
+    assert_eq!(dist_corr.correlation_matrix(&[inexact]).unwrap_err(), error);
+    assert_eq!(PreparedVector::new(&inexact).unwrap_err(), error);
+
+    let results = dist_corr
+        .compute_one_vs_many(&v, &[exact, inexact])
+        .unwrap();
+    assert!(results[0].is_ok());
+    assert_eq!(results[1], Err(error));
+}
+
+/// slices of f64 are borrowed, other types are converted once
+#[test]
+fn conversion() {
+    let v = [0.5, 1.5];
+    assert!(matches!(f64::slice_to_f64(&v), Cow::Borrowed(values) if values == v));
+    assert!(matches!(u16::slice_to_f64(&[1, 2]), Cow::Owned(values) if values == [1.0, 2.0]));
+}
+
+/// the batch computations accept columns of any numeric type
+#[test]
+fn batch() {
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+    let columns: Vec<Vec<f32>> = (0..4)
+        .map(|_| (0..300).map(|_| rng.random::<f32>()).collect())
+        .collect();
+    let columns_f64: Vec<Vec<f64>> = columns.iter().map(|c| to_f64(c)).collect();
+    let target: Vec<i32> = (0..300).map(|_| rng.random_range(0..10)).collect();
+
+    let dist_corr = ConfiguredDistCorrelation::default();
+
+    let matrix = dist_corr.correlation_matrix(&columns).unwrap();
+    assert_eq!(matrix, dist_corr.correlation_matrix(&columns_f64).unwrap());
+
+    assert_eq!(
+        dist_corr
+            .cross_correlation_matrix(&columns, &columns_f64[..2])
+            .unwrap(),
+        dist_corr
+            .cross_correlation_matrix(&columns_f64, &columns_f64[..2])
+            .unwrap()
+    );
+
+    let results = dist_corr.compute_one_vs_many(&target, &columns).unwrap();
+    for (result, column) in results.iter().zip(&columns) {
+        let expected = dist_corr.compute(&target, column).unwrap();
+        assert!((result.as_ref().unwrap() - expected).abs() < 1e-12);
+    }
+
+    assert_eq!(
+        dist_corr
+            .screen(&target, &columns, ScreeningCutoff::TopK(2))
+            .unwrap(),
+        dist_corr
+            .screen(&to_f64(&target), &columns_f64, ScreeningCutoff::TopK(2))
+            .unwrap()
+    );
+
+    // the pairwise evaluation without prepared vectors
+    let dist_corr = DistCorrelation::builder().exponent(0.5).build();
+    assert_eq!(
+        dist_corr.correlation_matrix(&columns).unwrap(),
+        dist_corr.correlation_matrix(&columns_f64).unwrap()
+    );
+    assert_eq!(
+        dist_corr
+            .cross_correlation_matrix(&[&target], &columns)
+            .unwrap(),
+        dist_corr
+            .cross_correlation_matrix(&[to_f64(&target)], &columns_f64)
+            .unwrap()
+    );
+}
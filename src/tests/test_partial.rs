@@ -0,0 +1,206 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::dist_corr_naive::_partial_dist_corr_naive;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn confounder() {
+    let test_sizes = [5, 6, 17, 2_i32.pow(7), 333];
+
+    for numb in test_sizes {
+        sub_test(numb as usize, 13);
+    }
+}
+
+/// removing an independent z hardly changes the bias-corrected distance correlation
+#[test]
+fn independent_confounder() {
+    let sample_size = 1000;
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+
+    let x: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-1.0..1.0))
+        .collect();
+    let y: Vec<f64> = x.iter().map(|v| v * v).collect();
+    let z: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-1.0..1.0))
+        .collect();
+
+    let partial = DistCorrelation.compute_partial(&x, &y, &z).unwrap();
+    println!("Partial: {:?}", partial);
+
+    assert!((partial.partial_dist_corr - partial.dist_corr_xy.dist_corr_sq).abs() < 0.01);
+    assert!(partial.partial_dist_corr > 0.2);
+}
+
+/// x and y depend on each other only through z
+#[test]
+fn conditionally_independent() {
+    let sample_size = 1000;
+    let mut rng = ChaCha8Rng::seed_from_u64(8);
+
+    let z: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-1.0..1.0))
+        .collect();
+    let x: Vec<f64> = z
+        .iter()
+        .map(|v| v + 0.1 * rng.random_range(-1.0..1.0))
+        .collect();
+    let y: Vec<f64> = z
+        .iter()
+        .map(|v| 2.0 * v + 0.1 * rng.random_range(-1.0..1.0))
+        .collect();
+
+    let partial = DistCorrelation.compute_partial(&x, &y, &z).unwrap();
+    println!("Partial: {:?}", partial);
+
+    assert!(partial.dist_corr_xy.dist_corr_sq > 0.9);
+    assert!(partial.partial_dist_corr.abs() < 0.1);
+}
+
+/// vanishing denominators
+#[test]
+fn degenerate() {
+    let x = vec![1.0, -2.0, 3.0, 0.5, 7.0, 2.0];
+    let y = vec![0.3, 1.0, -1.0, 2.0, 0.0, 4.0];
+
+    // x is fully explained by z
+    let z: Vec<f64> = x.iter().map(|v| 3.0 * v - 1.0).collect();
+    let partial = DistCorrelation.compute_partial(&x, &y, &z).unwrap();
+    assert_eq!(partial.partial_dist_corr, 0.0);
+    assert!(partial.partial_dist_cov.abs() < 1e-10);
+
+    // constant z does not change anything
+    let z = vec![2.0; 6];
+    let partial = DistCorrelation.compute_partial(&x, &y, &z).unwrap();
+    assert_eq!(partial.partial_dist_corr, partial.dist_corr_xy.dist_corr_sq);
+    assert_eq!(
+        DistCovariance.compute_partial(&x, &y, &z).unwrap(),
+        DistCovariance.compute_bias_corrected(&x, &y).unwrap()
+    );
+
+    // constant x
+    let partial = DistCorrelation.compute_partial(&[1.0; 6], &y, &x).unwrap();
+    assert_eq!(partial.partial_dist_corr, 0.0);
+}
+
+#[test]
+fn multi_naive() {
+    let sample_size = 150;
+    let (dim_x, dim_y, dim_z) = (2, 1, 3);
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+
+    let z: Vec<f64> = (0..sample_size * dim_z)
+        .map(|_x| rng.random_range(-1.0..1.0))
+        .collect();
+    let x: Vec<f64> = z
+        .chunks_exact(dim_z)
+        .flat_map(|row| [row[0] * row[1], rng.random_range(-1.0..1.0)])
+        .collect();
+    let y: Vec<f64> = z
+        .chunks_exact(dim_z)
+        .zip(x.chunks_exact(dim_x))
+        .map(|(row_z, row_x)| row_z[2].abs() + row_x[1])
+        .collect();
+
+    let partial = DistCorrelation
+        .compute_partial_multi(&x, dim_x, &y, dim_y, &z, dim_z)
+        .unwrap();
+    let partial_dist_cov = DistCovariance
+        .compute_partial_multi(&x, dim_x, &y, dim_y, &z, dim_z)
+        .unwrap();
+
+    let rows = |data: &[f64], dim: usize| -> Vec<Vec<f64>> {
+        data.chunks_exact(dim).map(|row| row.to_vec()).collect()
+    };
+    let (partial_dist_corr_naive, partial_dist_cov_naive) =
+        _partial_dist_corr_naive(&rows(&x, dim_x), &rows(&y, dim_y), &rows(&z, dim_z));
+
+    println!("Partial: {:?}", partial);
+    println!("Partial naive: {:?}", partial_dist_corr_naive);
+
+    assert!((partial.partial_dist_corr - partial_dist_corr_naive).abs() < 1e-10);
+    assert!((partial_dist_cov - partial_dist_cov_naive).abs() < 1e-10);
+    assert!(partial.partial_dist_corr > 0.1);
+
+    // one dimensional samples coincide with the univariate algorithm
+    let z_1: Vec<f64> = z.iter().step_by(dim_z).copied().collect();
+    let x_1: Vec<f64> = x.iter().step_by(dim_x).copied().collect();
+    let partial_multi = DistCorrelation
+        .compute_partial_multi(&x_1, 1, &y, 1, &z_1, 1)
+        .unwrap();
+    let partial_uni = DistCorrelation.compute_partial(&x_1, &y, &z_1).unwrap();
+    assert!((partial_multi.partial_dist_corr - partial_uni.partial_dist_corr).abs() < 1e-10);
+}
+
+#[test]
+fn errors() {
+    let dist_correlation = DistCorrelation;
+    let dist_covariance = DistCovariance;
+    let v = vec![1.0, 2.0, 3.0, 4.0];
+
+    assert!(dist_correlation
+        .compute_partial(&v, &v, &[1.0, 2.0, 3.0])
+        .is_err());
+    assert!(dist_correlation
+        .compute_partial(&v[..3], &v[..3], &v[..3])
+        .is_err());
+    assert!(dist_covariance
+        .compute_partial(&[0.0; 0], &[0.0; 0], &[0.0; 0])
+        .is_err());
+    assert!(dist_correlation
+        .compute_partial_multi(&v, 1, &v, 1, &v, 0)
+        .is_err());
+    assert!(dist_correlation
+        .compute_partial_multi(&v, 1, &v, 1, &v, 3)
+        .is_err());
+    assert!(dist_covariance
+        .compute_partial_multi(&v, 1, &v, 1, &v, 2)
+        .is_err());
+    assert!(dist_covariance
+        .compute_partial_multi(&v, 2, &v, 2, &v, 2)
+        .is_err());
+    assert!(dist_covariance
+        .compute_partial_multi(&v, 1, &v, 1, &v, 1)
+        .is_ok());
+}
+
+fn sub_test(sample_size: usize, seed: u64) {
+    println!("------------------------");
+    println!("Sample size: {:?}", sample_size);
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let z: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let x: Vec<f64> = z
+        .iter()
+        .map(|v| v.sin() + rng.random_range(-1.0..1.0))
+        .collect();
+    let y: Vec<f64> = z
+        .iter()
+        .zip(&x)
+        .map(|(v, w)| v.abs() + 0.5 * w * w)
+        .collect();
+
+    let partial = DistCorrelation.compute_partial(&x, &y, &z).unwrap();
+    let partial_dist_cov = DistCovariance.compute_partial(&x, &y, &z).unwrap();
+
+    let rows = |data: &[f64]| -> Vec<Vec<f64>> { data.iter().map(|v| vec![*v]).collect() };
+    let (partial_dist_corr_naive, partial_dist_cov_naive) =
+        _partial_dist_corr_naive(&rows(&x), &rows(&y), &rows(&z));
+
+    println!("Partial: {:?}", partial);
+    println!("Partial naive: {:?}", partial_dist_corr_naive);
+
+    assert!((partial.partial_dist_corr - partial_dist_corr_naive).abs() < 1e-8);
+    assert!((partial_dist_cov - partial_dist_cov_naive).abs() < 1e-8);
+    assert_eq!(partial.partial_dist_cov, partial_dist_cov);
+}
@@ -0,0 +1,223 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::DistCorrelation;
+use crate::ordering::Ordering;
+use crate::permutation_test::permutation;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn dependent() {
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let v1: Vec<f64> = (0..500)
+        .map(move |_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x * x).collect();
+
+    let dist_correlation = DistCorrelation;
+    let test = dist_correlation
+        .permutation_test(&v1, &v2, 199, 42)
+        .unwrap();
+    let dist_corr = dist_correlation.compute(&v1, &v2).unwrap();
+
+    println!("Permutation test: {:?}", test);
+
+    assert!((test.statistic - dist_corr).abs() < 1e-12);
+    assert!((test.p_value - 1.0 / 200.0).abs() < f64::EPSILON);
+    assert_eq!(test.n_perm, 199);
+    assert!(test.null_max < test.statistic);
+}
+
+#[test]
+fn independent() {
+    let mut rng_1 = ChaCha8Rng::seed_from_u64(134);
+    let mut rng_2 = ChaCha8Rng::seed_from_u64(11);
+
+    let v1: Vec<f64> = (0..300)
+        .map(move |_x| rng_1.random_range(-10.0..10.0))
+        .collect();
+    let v2: Vec<f64> = (0..300)
+        .map(move |_x| rng_2.random_range(-10.0..10.0))
+        .collect();
+
+    let dist_correlation = DistCorrelation;
+    let test = dist_correlation.permutation_test(&v1, &v2, 499, 1).unwrap();
+
+    println!("Permutation test: {:?}", test);
+
+    assert!(test.p_value > 0.05);
+    assert!(test.null_mean > 0.0 && test.null_std_dev > 0.0);
+}
+
+/// the summary of the permutation distribution coincides with
+/// directly computed distance correlations of the permuted data
+#[test]
+fn null_distribution() {
+    let (n_perm, seed) = (50, 17);
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+    let v1: Vec<f64> = (0..97)
+        .map(move |_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x.sin()).collect();
+
+    let dist_correlation = DistCorrelation;
+    let test = dist_correlation
+        .permutation_test(&v1, &v2, n_perm, seed)
+        .unwrap();
+
+    let Ordering { v1_per, v2_ord, .. } = Ordering::order_wrt_v2(&v1, &v2, false);
+    let null: Vec<f64> = (0..n_perm)
+        .map(|b| {
+            let v1_p: Vec<f64> = permutation(v1.len(), seed, b)
+                .iter()
+                .map(|&i| v1_per[i])
+                .collect();
+            dist_correlation.compute(&v1_p, &v2_ord).unwrap()
+        })
+        .collect();
+
+    let null_mean = null.iter().sum::<f64>() / n_perm as f64;
+    let null_max = null.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    assert!((test.null_mean - null_mean).abs() < 1e-10);
+    assert!((test.null_max - null_max).abs() < 1e-10);
+}
+
+#[test]
+fn reproducible() {
+    let mut rng = ChaCha8Rng::seed_from_u64(21);
+    let v1: Vec<f64> = (0..200)
+        .map(move |_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let v2: Vec<f64> = v1
+        .iter()
+        .enumerate()
+        .map(|(i, x)| x.cos() + i as f64 * 0.01)
+        .collect();
+
+    let dist_correlation = DistCorrelation;
+    let test_1 = dist_correlation.permutation_test(&v1, &v2, 99, 8).unwrap();
+    let test_2 = dist_correlation.permutation_test(&v1, &v2, 99, 8).unwrap();
+    let test_3 = dist_correlation.permutation_test(&v1, &v2, 99, 9).unwrap();
+
+    assert_eq!(test_1, test_2);
+    assert_ne!(test_1.null_mean, test_3.null_mean);
+}
+
+#[test]
+fn constant() {
+    let v1: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+    let v2: Vec<f64> = vec![1.0, 1.0, 1.0, 1.0];
+
+    let test = DistCorrelation.permutation_test(&v1, &v2, 10, 0).unwrap();
+
+    assert_eq!(test.statistic, 0.0);
+    assert_eq!(test.p_value, 1.0);
+}
+
+#[test]
+fn errors() {
+    let dist_correlation = DistCorrelation;
+
+    assert!(dist_correlation
+        .permutation_test(&[1.0, 2.0], &[1.0], 10, 0)
+        .is_err());
+    assert!(dist_correlation
+        .permutation_test(&[0.0; 0], &[0.0; 0], 10, 0)
+        .is_err());
+    assert!(dist_correlation
+        .permutation_test(&[1.0, 2.0], &[1.0, 3.0], 0, 0)
+        .is_err());
+}
+
+#[test]
+fn one_binary() {
+    let mut rng_1 = ChaCha8Rng::seed_from_u64(134);
+    let mut rng_2 = ChaCha8Rng::seed_from_u64(11);
+
+    let v1: Vec<f64> = (0..1000)
+        .map(move |_x| rng_1.random_range(-10.0..10.0))
+        .map(|x| if x < 0.0 { 0.0 } else { 1.0 })
+        .collect();
+    let v2: Vec<f64> = v1
+        .iter()
+        .map(|x| 0.2 * x + rng_2.random_range(-1.0..1.0))
+        .collect();
+
+    let dist_correlation = DistCorrelation;
+    let test = dist_correlation.permutation_test(&v1, &v2, 199, 3).unwrap();
+    let test_binary = dist_correlation
+        .permutation_test_binary(&v1, &v2, true, false, 199, 3)
+        .unwrap();
+    let test_binary_flipped = dist_correlation
+        .permutation_test_binary(&v2, &v1, false, true, 199, 3)
+        .unwrap();
+    let dist_corr_binary = dist_correlation
+        .compute_binary(&v1, &v2, true, false)
+        .unwrap();
+
+    println!("Permutation test: {:?}", test);
+    println!("Permutation test binary: {:?}", test_binary);
+
+    assert!((test_binary.statistic - dist_corr_binary).abs() < 1e-12);
+    assert!((test_binary.statistic - test.statistic).abs() < 1e-10);
+    assert!((test_binary.null_mean - test.null_mean).abs() < 1e-10);
+    assert!((test_binary.null_max - test.null_max).abs() < 1e-10);
+    assert_eq!(test_binary.p_value, test.p_value);
+    assert_eq!(test_binary, test_binary_flipped);
+}
+
+#[test]
+fn both_binary() {
+    let mut rng_1 = ChaCha8Rng::seed_from_u64(134);
+    let mut rng_2 = ChaCha8Rng::seed_from_u64(11);
+
+    let v1: Vec<f64> = (0..1000)
+        .map(move |_x| rng_1.random_range(-10.0..10.0))
+        .map(|x| if x < 0.0 { 0.0 } else { 1.0 })
+        .collect();
+    let v2: Vec<f64> = v1
+        .iter()
+        .map(|x| {
+            if rng_2.random_range(0.0..1.0) < 0.1 {
+                1.0 - x
+            } else {
+                *x
+            }
+        })
+        .collect();
+
+    let dist_correlation = DistCorrelation;
+    let test = dist_correlation.permutation_test(&v1, &v2, 99, 5).unwrap();
+    let test_binary = dist_correlation
+        .permutation_test_binary(&v1, &v2, true, true, 99, 5)
+        .unwrap();
+
+    println!("Permutation test: {:?}", test);
+    println!("Permutation test binary: {:?}", test_binary);
+
+    assert!((test_binary.statistic - test.statistic).abs() < 1e-10);
+    assert!((test_binary.null_mean - test.null_mean).abs() < 1e-10);
+    assert!((test_binary.null_std_dev - test.null_std_dev).abs() < 1e-10);
+    assert!((test_binary.p_value - 0.01).abs() < f64::EPSILON);
+}
+
+#[test]
+fn errors_binary() {
+    let dist_correlation = DistCorrelation;
+
+    assert!(dist_correlation
+        .permutation_test_binary(&[0.0, 1.0, 2.0], &[1.0, 3.0, 2.0], true, false, 10, 0)
+        .is_err());
+    assert!(dist_correlation
+        .permutation_test_binary(&[0.0, 1.0, 0.0], &[1.0, 3.0, 2.0], true, true, 10, 0)
+        .is_err());
+    assert!(dist_correlation
+        .permutation_test_binary(&[0.0, 1.0], &[1.0, 0.0], true, true, 0, 0)
+        .is_err());
+}
@@ -0,0 +1,201 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+use crate::prepared_vector::PreparedVector;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+#[test]
+fn one_vs_many() {
+    let sample_size = 1000;
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+
+    let target: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let prepared_target = PreparedVector::new(&target).unwrap();
+
+    let features: Vec<Vec<f64>> = vec![
+        target.iter().map(|x| x * x).collect(),
+        target.iter().map(|x| x.sin()).collect(),
+        target.iter().map(|x| x.round().abs()).collect(),
+        (0..sample_size)
+            .map(|_x| rng.random_range(-10.0..10.0))
+            .collect(),
+    ];
+
+    for feature in features {
+        let prepared_feature = PreparedVector::new(&feature).unwrap();
+
+        let dist_corr = DistCorrelation.compute(&feature, &target).unwrap();
+        let dist_corr_prepared = DistCorrelation
+            .compute_prepared(&prepared_feature, &prepared_target)
+            .unwrap();
+        let dist_cov = DistCovariance.compute(&feature, &target).unwrap();
+        let dist_cov_prepared = DistCovariance
+            .compute_prepared(&prepared_feature, &prepared_target)
+            .unwrap();
+
+        println!("Dist corr: {:?} vs {:?}", dist_corr, dist_corr_prepared);
+
+        assert!((dist_corr - dist_corr_prepared).abs() < 1e-10);
+        assert!((dist_cov - dist_cov_prepared).abs() < 1e-10);
+        assert!(
+            (DistCorrelation
+                .compute_prepared(&prepared_target, &prepared_feature)
+                .unwrap()
+                - dist_corr)
+                .abs()
+                < 1e-10
+        );
+    }
+}
+
+#[test]
+fn binary() {
+    let sample_size = 500;
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+
+    let v_real: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let v_bin_1: Vec<f64> = v_real
+        .iter()
+        .map(|x| if *x < 0.0 { 0.0 } else { 1.0 })
+        .collect();
+    let v_bin_2: Vec<f64> = v_real
+        .iter()
+        .map(|x| if x.abs() < 5.0 { 0.0 } else { 1.0 })
+        .collect();
+
+    let prepared_real = PreparedVector::new(&v_real).unwrap();
+    let prepared_bin_1 = PreparedVector::new(&v_bin_1).unwrap();
+    let prepared_bin_2 = PreparedVector::new(&v_bin_2).unwrap();
+
+    assert!(!prepared_real.is_binary());
+    assert!(prepared_bin_1.is_binary());
+
+    let pairs = [
+        (&v_bin_1, &v_real, &prepared_bin_1, &prepared_real),
+        (&v_real, &v_bin_2, &prepared_real, &prepared_bin_2),
+        (&v_bin_1, &v_bin_2, &prepared_bin_1, &prepared_bin_2),
+    ];
+
+    for (v1, v2, prepared_1, prepared_2) in pairs {
+        let dist_corr = DistCorrelation.compute(v1, v2).unwrap();
+        let dist_corr_prepared = DistCorrelation
+            .compute_prepared(prepared_1, prepared_2)
+            .unwrap();
+        let dist_cov = DistCovariance.compute(v1, v2).unwrap();
+        let dist_cov_prepared = DistCovariance
+            .compute_prepared(prepared_1, prepared_2)
+            .unwrap();
+
+        println!("Dist corr: {:?} vs {:?}", dist_corr, dist_corr_prepared);
+
+        assert!((dist_corr - dist_corr_prepared).abs() < 1e-10);
+        assert!((dist_cov - dist_cov_prepared).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn cached_quantities() {
+    let v = vec![3.0, -1.0, 2.0, 2.0, 7.0];
+    let prepared = PreparedVector::new(&v).unwrap();
+
+    assert_eq!(prepared.len(), 5);
+    assert_eq!(prepared.values(), &v[..]);
+
+    for (rank, &i) in prepared.order().iter().enumerate() {
+        assert_eq!(prepared.ranks()[i], rank);
+    }
+    assert!(prepared.order().windows(2).all(|w| v[w[0]] <= v[w[1]]));
+
+    let grand_means: Vec<f64> = v
+        .iter()
+        .map(|a| v.iter().map(|b| (a - b).abs()).sum::<f64>() / 5.0)
+        .collect();
+    for (a, b) in prepared.grand_means().iter().zip(grand_means) {
+        assert!((a - b).abs() < 1e-12);
+    }
+
+    let dist_var = DistCovariance.compute_var(&v).unwrap();
+    assert!((prepared.dist_var() - dist_var).abs() < 1e-12);
+}
+
+#[test]
+fn constant() {
+    let v1 = PreparedVector::new(&[1.0, 2.0, 3.0]).unwrap();
+    let v2 = PreparedVector::new(&[4.0, 4.0, 4.0]).unwrap();
+
+    assert_eq!(DistCorrelation.compute_prepared(&v1, &v2).unwrap(), 0.0);
+    assert_eq!(DistCovariance.compute_prepared(&v1, &v2).unwrap(), 0.0);
+}
+
+#[test]
+fn errors() {
+    assert!(PreparedVector::new(&[0.0; 0]).is_err());
+
+    let v1 = PreparedVector::new(&[1.0, 2.0, 3.0]).unwrap();
+    let v2 = PreparedVector::new(&[1.0, 2.0]).unwrap();
+
+    assert!(DistCorrelation.compute_prepared(&v1, &v2).is_err());
+    assert!(DistCovariance.compute_prepared(&v1, &v2).is_err());
+}
+
+#[test]
+fn compute_one_vs_many() {
+    let sample_size = 300;
+    let mut rng = ChaCha8Rng::seed_from_u64(17);
+
+    let target: Vec<f64> = (0..sample_size)
+        .map(|_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let mut features: Vec<Vec<f64>> = (0..200)
+        .map(|k| {
+            target
+                .iter()
+                .map(|x| (x * k as f64 / 50.0).sin() + rng.random_range(-1.0..1.0))
+                .collect()
+        })
+        .collect();
+    features[7] = target
+        .iter()
+        .map(|x| if *x < 0.0 { 0.0 } else { 1.0 })
+        .collect();
+    features[11] = vec![1.0; sample_size - 1];
+
+    let results = DistCorrelation
+        .compute_one_vs_many(&target, &features)
+        .unwrap();
+
+    assert_eq!(results.len(), features.len());
+    for (k, (feature, result)) in features.iter().zip(results).enumerate() {
+        if k == 11 {
+            assert!(result.is_err());
+        } else {
+            let dist_corr = DistCorrelation.compute(feature, &target).unwrap();
+            assert!((result.unwrap() - dist_corr).abs() < 1e-10);
+        }
+    }
+
+    // slices of features
+    let feature_slices: Vec<&[f64]> = features[..3].iter().map(|v| v.as_slice()).collect();
+    let results = DistCorrelation
+        .compute_one_vs_many(&target, &feature_slices)
+        .unwrap();
+    assert_eq!(results.len(), 3);
+
+    assert!(DistCorrelation
+        .compute_one_vs_many(&[0.0; 0], &feature_slices)
+        .is_err());
+    assert!(DistCorrelation
+        .compute_one_vs_many(&target, &[] as &[Vec<f64>])
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,127 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::{DistCorrelation, DistCovariance};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// the estimate is unbiased for dCov^2, hence close to the exact value for many projections
+#[test]
+fn exact_multi() {
+    let sample_size = 300;
+    let (dim_x, dim_y) = (3, 2);
+    let (x, y) = sample(sample_size, dim_x, dim_y, 5);
+
+    let dist_cov = DistCovariance.compute_multi(&x, dim_x, &y, dim_y).unwrap();
+    let dist_corr = DistCorrelation.compute_multi(&x, dim_x, &y, dim_y).unwrap();
+
+    let estimate_cov = DistCovariance
+        .compute_multi_projection(&x, dim_x, &y, dim_y, 2000, 1)
+        .unwrap();
+    let estimate_corr = DistCorrelation
+        .compute_multi_projection(&x, dim_x, &y, dim_y, 2000, 1)
+        .unwrap();
+
+    println!("Dist cov: {:?} vs {:?}", dist_cov, estimate_cov);
+    println!("Dist corr: {:?} vs {:?}", dist_corr, estimate_corr);
+
+    assert_eq!(estimate_cov.n_proj, 2000);
+    assert!((estimate_cov.estimate - dist_cov).abs() < 4.0 * estimate_cov.std_error);
+    assert!((estimate_corr.estimate - dist_corr).abs() < 4.0 * estimate_corr.std_error);
+    assert!(estimate_corr.std_error < 0.01);
+}
+
+/// in one dimension every projection is a reflection which leaves dCov invariant
+#[test]
+fn univariate() {
+    let mut rng = ChaCha8Rng::seed_from_u64(21);
+    let v1: Vec<f64> = (0..500).map(|_x| rng.random_range(-10.0..10.0)).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x.sin()).collect();
+
+    let dist_cov = DistCovariance.compute(&v1, &v2).unwrap();
+    let dist_corr = DistCorrelation.compute(&v1, &v2).unwrap();
+
+    let estimate_cov = DistCovariance
+        .compute_multi_projection(&v1, 1, &v2, 1, 10, 3)
+        .unwrap();
+    let estimate_corr = DistCorrelation
+        .compute_multi_projection(&v1, 1, &v2, 1, 10, 3)
+        .unwrap();
+
+    assert!((estimate_cov.estimate - dist_cov).abs() < 1e-10);
+    assert!((estimate_corr.estimate - dist_corr).abs() < 1e-10);
+    assert!(estimate_cov.std_error < 1e-10);
+}
+
+#[test]
+fn reproducible() {
+    let (x, y) = sample(200, 4, 3, 9);
+
+    let estimate_1 = DistCorrelation
+        .compute_multi_projection(&x, 4, &y, 3, 50, 11)
+        .unwrap();
+    let estimate_2 = DistCorrelation
+        .compute_multi_projection(&x, 4, &y, 3, 50, 11)
+        .unwrap();
+    let estimate_3 = DistCorrelation
+        .compute_multi_projection(&x, 4, &y, 3, 50, 12)
+        .unwrap();
+
+    assert_eq!(estimate_1, estimate_2);
+    assert_ne!(estimate_1, estimate_3);
+}
+
+#[test]
+fn single_projection() {
+    let (x, y) = sample(50, 2, 2, 1);
+
+    let estimate = DistCovariance
+        .compute_multi_projection(&x, 2, &y, 2, 1, 0)
+        .unwrap();
+
+    assert!(estimate.estimate >= 0.0);
+    assert!(estimate.std_error.is_nan());
+}
+
+#[test]
+fn errors() {
+    let x = vec![1.0, 2.0, 3.0, 4.0];
+    let y = vec![1.0, 2.0];
+
+    assert!(DistCorrelation
+        .compute_multi_projection(&x, 2, &y, 1, 0, 1)
+        .is_err());
+    assert!(DistCorrelation
+        .compute_multi_projection(&x, 3, &y, 1, 10, 1)
+        .is_err());
+    assert!(DistCovariance
+        .compute_multi_projection(&x, 1, &y, 1, 10, 1)
+        .is_err());
+    assert!(DistCovariance
+        .compute_multi_projection(&[0.0; 0], 1, &[0.0; 0], 1, 10, 1)
+        .is_err());
+}
+
+/// row-major samples with y depending non-linearly on x
+fn sample(sample_size: usize, dim_x: usize, dim_y: usize, seed: u64) -> (Vec<f64>, Vec<f64>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let x: Vec<f64> = (0..sample_size * dim_x)
+        .map(|_x| rng.random_range(-1.0..1.0))
+        .collect();
+    let y: Vec<f64> = x
+        .chunks_exact(dim_x)
+        .flat_map(|row| {
+            let norm_sq = row.iter().map(|a| a * a).sum::<f64>();
+            (0..dim_y)
+                .map(|j| norm_sq * (j + 1) as f64 + rng.random_range(-0.5..0.5))
+                .collect::<Vec<f64>>()
+        })
+        .collect();
+
+    (x, y)
+}
@@ -0,0 +1,158 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::DistCorrelation;
+use crate::screening::ScreeningCutoff;
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// only the first features are active
+#[test]
+fn ranking() {
+    let (target, features) = sample(400, 50, 3);
+
+    let screened = DistCorrelation
+        .screen(&target, &features, ScreeningCutoff::All)
+        .unwrap();
+
+    assert_eq!(screened.len(), 50);
+    for pair in screened.windows(2) {
+        assert!(pair[0].dist_corr >= pair[1].dist_corr);
+    }
+    for feature in &screened {
+        let dist_corr = DistCorrelation
+            .compute(&features[feature.index], &target)
+            .unwrap();
+        assert!((feature.dist_corr - dist_corr).abs() < 1e-10);
+        assert_eq!(feature.p_value, None);
+    }
+
+    let mut top_3: Vec<usize> = screened[..3].iter().map(|f| f.index).collect();
+    top_3.sort();
+    assert_eq!(top_3, vec![0, 1, 2]);
+}
+
+#[test]
+fn cutoffs() {
+    let (target, features) = sample(400, 100, 5);
+
+    let all = DistCorrelation
+        .screen(&target, &features, ScreeningCutoff::All)
+        .unwrap();
+    let top_k = DistCorrelation
+        .screen(&target, &features, ScreeningCutoff::TopK(10))
+        .unwrap();
+    let n_over_log_n = DistCorrelation
+        .screen(&target, &features, ScreeningCutoff::NOverLogN)
+        .unwrap();
+
+    // [400 / ln(400)] = 66
+    assert_eq!(top_k.len(), 10);
+    assert_eq!(n_over_log_n.len(), 66);
+    assert_eq!(top_k[..], all[..10]);
+    assert_eq!(n_over_log_n[..], all[..66]);
+
+    let top_k = DistCorrelation
+        .screen(&target, &features, ScreeningCutoff::TopK(1000))
+        .unwrap();
+    assert_eq!(top_k.len(), 100);
+}
+
+#[test]
+fn ties() {
+    let target = vec![1.0, 2.0, 3.0, 4.0];
+    let features = vec![vec![0.0; 4], vec![2.0, 4.0, 6.0, 8.0], vec![1.0; 4]];
+
+    let screened = DistCorrelation
+        .screen(&target, &features, ScreeningCutoff::All)
+        .unwrap();
+    let indices: Vec<usize> = screened.iter().map(|f| f.index).collect();
+
+    assert_eq!(indices, vec![1, 0, 2]);
+}
+
+#[test]
+fn p_values() {
+    let (target, mut features) = sample(200, 6, 7);
+    features[1] = target
+        .iter()
+        .map(|x| if x.abs() < 0.5 { 1.0 } else { 0.0 })
+        .collect();
+
+    let screened = DistCorrelation
+        .screen_with_p_values(&target, &features, ScreeningCutoff::TopK(4), 499, 3)
+        .unwrap();
+
+    assert_eq!(screened.len(), 4);
+    for feature in &screened {
+        let test = DistCorrelation
+            .permutation_test_binary(
+                &features[feature.index],
+                &target,
+                feature.index == 1,
+                false,
+                499,
+                3,
+            )
+            .unwrap();
+        assert_eq!(feature.p_value, Some(test.p_value));
+    }
+    assert!(screened[0].p_value.unwrap() < 0.01);
+
+    // binary target
+    let target_binary: Vec<f64> = target
+        .iter()
+        .map(|x| if *x < 0.0 { 0.0 } else { 1.0 })
+        .collect();
+    let screened = DistCorrelation
+        .screen_with_p_values(&target_binary, &features, ScreeningCutoff::All, 99, 3)
+        .unwrap();
+    assert!(screened.iter().all(|f| f.p_value.is_some()));
+}
+
+#[test]
+fn errors() {
+    let dist_correlation = DistCorrelation;
+    let target = vec![1.0, 2.0, 3.0];
+
+    assert!(dist_correlation
+        .screen(&target, &[vec![1.0, 2.0]], ScreeningCutoff::All)
+        .is_err());
+    assert!(dist_correlation
+        .screen(&[0.0; 0], &[Vec::<f64>::new()], ScreeningCutoff::All)
+        .is_err());
+    assert!(dist_correlation
+        .screen_with_p_values(&target, &[vec![3.0, 1.0, 2.0]], ScreeningCutoff::All, 0, 1)
+        .is_err());
+    assert!(dist_correlation
+        .screen(&target, &Vec::<Vec<f64>>::new(), ScreeningCutoff::NOverLogN)
+        .unwrap()
+        .is_empty());
+}
+
+/// target depending on the first three of num_features features
+fn sample(sample_size: usize, num_features: usize, seed: u64) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let features: Vec<Vec<f64>> = (0..num_features)
+        .map(|_k| {
+            (0..sample_size)
+                .map(|_x| rng.random_range(-1.0..1.0))
+                .collect()
+        })
+        .collect();
+    let target: Vec<f64> = (0..sample_size)
+        .map(|i| {
+            features[0][i].powi(2)
+                + features[1][i].abs()
+                + features[2][i]
+                + 0.1 * rng.random_range(-1.0..1.0)
+        })
+        .collect();
+
+    (target, features)
+}
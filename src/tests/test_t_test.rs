@@ -0,0 +1,151 @@
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Using
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::api::DistCorrelation;
+use crate::error::DistCorrError;
+use crate::special_functions::{incomplete_beta, normal_sf, student_t_sf};
+
+// +++++++++++++++++++++++++++++++++++++++++++++++++++
+// Tests
+
+/// reference values computed with 40 digit precision
+#[test]
+fn special_functions() {
+    assert!((student_t_sf(2.0, 10.0) - 0.036_694_017_385_370_18).abs() < 1e-14);
+    assert!((student_t_sf(0.5, 3.0) - 0.325_723_982_424_075_5).abs() < 1e-14);
+    assert!((student_t_sf(-1.3, 7.0) - 0.882_616_082_303_811_4).abs() < 1e-14);
+    assert!((student_t_sf(4.0, 1.0) - 0.077_979_130_377_369_33).abs() < 1e-14);
+    assert!((student_t_sf(3.0, 1e5) - 0.001_350_230_442_032_359_6).abs() < 1e-12);
+    assert!((student_t_sf(3.0, 999_999.0) - 0.001_349_931_270_744_138).abs() < 1e-12);
+    assert!((student_t_sf(1.7, 2e6) - 0.044_565_540_501_860_17).abs() < 1e-12);
+    assert!((student_t_sf(-0.8, 3e8) - 0.788_144_601_099_873_9).abs() < 1e-12);
+    assert!((student_t_sf(2.5, 5e7) - 0.006_209_666_914_278_445).abs() < 1e-12);
+    assert_eq!(student_t_sf(f64::INFINITY, 2.0), 0.0);
+    assert_eq!(student_t_sf(f64::NEG_INFINITY, 2e7), 1.0);
+
+    assert!((normal_sf(2.5) - 0.006_209_665_325_776_135).abs() < 1e-14);
+    assert!((incomplete_beta(2.0, 3.0, 0.4) - 0.5248).abs() < 1e-14);
+}
+
+#[test]
+fn dependent() {
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let v1: Vec<f64> = (0..500)
+        .map(move |_x| rng.random_range(-10.0..10.0))
+        .collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x * x).collect();
+
+    let dist_correlation = DistCorrelation;
+    let test = dist_correlation.t_test(&v1, &v2).unwrap();
+    let bias_corrected = dist_correlation.compute_bias_corrected(&v1, &v2).unwrap();
+
+    println!("t-test: {:?}", test);
+
+    let df = 500.0 * 497.0 / 2.0 - 1.0;
+    let r = bias_corrected.dist_corr_sq;
+    assert_eq!(test.df, df);
+    assert_eq!(test.dist_corr_sq, r);
+    assert!((test.statistic - df.sqrt() * r / (1.0 - r * r).sqrt()).abs() < 1e-10);
+    assert!(test.p_value < 1e-100);
+}
+
+/// the rejection rate under independence is close to the level
+#[test]
+fn independent() {
+    let (sample_size, repetitions) = (50, 400);
+    let mut rng = ChaCha8Rng::seed_from_u64(17);
+
+    let rejections = (0..repetitions)
+        .filter(|_i| {
+            let v1: Vec<f64> = (0..sample_size)
+                .map(|_x| rng.random_range(-10.0..10.0))
+                .collect();
+            let v2: Vec<f64> = (0..sample_size)
+                .map(|_x| rng.random_range(-10.0..10.0))
+                .collect();
+
+            DistCorrelation.t_test(&v1, &v2).unwrap().p_value < 0.05
+        })
+        .count();
+
+    let rejection_rate = rejections as f64 / repetitions as f64;
+    println!("Rejection rate: {:?}", rejection_rate);
+
+    assert!(rejection_rate > 0.02 && rejection_rate < 0.09);
+}
+
+/// the rejection rate of high-dimensional independent samples is close to the level, which the
+/// t approximation only guarantees as the dimension grows
+#[test]
+fn independent_high_dimension() {
+    let (sample_size, dim, repetitions) = (50, 50, 1_000);
+    let mut rng = ChaCha8Rng::seed_from_u64(19);
+
+    let rejections = (0..repetitions)
+        .filter(|_i| {
+            let x: Vec<f64> = (0..sample_size * dim).map(|_x| rng.random()).collect();
+            let y: Vec<f64> = (0..sample_size * dim).map(|_x| rng.random()).collect();
+
+            let test = DistCorrelation.t_test_multi(&x, dim, &y, dim).unwrap();
+            test.p_value < 0.05
+        })
+        .count();
+
+    let rejection_rate = rejections as f64 / repetitions as f64;
+    println!("Rejection rate: {:?}", rejection_rate);
+
+    assert!(rejection_rate > 0.03 && rejection_rate < 0.07);
+}
+
+/// the multivariate test agrees with the univariate one for dimension one
+#[test]
+fn multi() {
+    let mut rng = ChaCha8Rng::seed_from_u64(23);
+    let v1: Vec<f64> = (0..300).map(|_x| rng.random_range(-1.0..1.0)).collect();
+    let v2: Vec<f64> = v1.iter().map(|x| x.abs() + rng.random::<f64>()).collect();
+
+    let dist_correlation = DistCorrelation;
+    let test = dist_correlation.t_test(&v1, &v2).unwrap();
+    let test_multi = dist_correlation.t_test_multi(&v1, 1, &v2, 1).unwrap();
+
+    assert_eq!(test.df, test_multi.df);
+    assert!((test.statistic - test_multi.statistic).abs() < 1e-8);
+    assert!((test.p_value - test_multi.p_value).abs() < 1e-10);
+}
+
+#[test]
+fn linear_relation() {
+    let v1: Vec<f64> = vec![1.0, -2.0, 3.0, 0.5, 7.0, 2.0];
+    let v2: Vec<f64> = v1.iter().map(|x| 2.0 * x).collect();
+
+    let test = DistCorrelation.t_test(&v1, &v2).unwrap();
+
+    assert_eq!(test.statistic, f64::INFINITY);
+    assert_eq!(test.p_value, 0.0);
+}
+
+#[test]
+fn errors() {
+    let dist_correlation = DistCorrelation;
+
+    assert!(dist_correlation
+        .t_test(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0])
+        .is_err());
+    assert!(dist_correlation
+        .t_test(&[1.0, 2.0, 3.0, 4.0], &[1.0, 2.0, 3.0])
+        .is_err());
+    assert_eq!(
+        dist_correlation.t_test_multi(&[1.0; 6], 2, &[1.0, 2.0, 3.0], 1),
+        Err(DistCorrError::TooFewObservations {
+            required: 4,
+            found: 3
+        })
+    );
+    assert_eq!(
+        dist_correlation.t_test_multi(&[1.0; 8], 2, &[1.0, 2.0, 3.0, 4.0], 1),
+        Err(DistCorrError::DegenerateVariance)
+    );
+}